@@ -5,9 +5,32 @@
 //! This crate provides flexible primitives for building card games, including:
 //! - [`Card`], [`Suit`], [`Rank`], and [`Color`] types
 //! - A [`Deck`] collection with shuffling, dealing, and manipulation methods
-//! - Customizable card ordering via the [`CardComparator`] trait
+//! - A compact one-byte-per-card [`PackedDeck`] for simulation
+//! - Dealing to multiple seats via [`DealPattern`]
+//! - Composition checks and enforcement via [`Composition`] and [`EnforcedDeck`]
+//! - Per-player visibility and redacted views via [`TrackedDeck`]
+//! - Trick-taking with led suits, trumps and follow-suit rules via [`Trick`] and [`TrickRules`]
+//! - Euchre bowers and the 24-card deck via [`EuchreComparator`] and [`Euchre24`]
+//! - Skat, Schafkopf and Doppelkopf trumps via [`SkatComparator`],
+//!   [`SchafkopfComparator`] and [`DoppelkopfComparator`]
+//! - Customizable card ordering via the [`CardComparator`] trait and composable
+//!   adapters such as [`Reversed`], [`ThenBy`] and [`SuitFirst`]
+//! - Rank and suit orders loaded from JSON or YAML via [`CustomComparator`]
+//! - Comparator-ordered cards for `BTreeSet` and `BinaryHeap` via [`OrderedCard`]
 //! - Deck generation via the [`DeckFactory`] trait
-//! - Serialization support (JSON, YAML, CSV)
+//! - Card point schemes for scoring via the [`CardPoints`] trait and [`CustomPoints`]
+//! - Text deck specifications such as `"2x standard52 + 4 jokers"` via [`DeckSpec`]
+//! - Exact draw probabilities via [`DrawOdds`]
+//! - Combination enumeration and indexing via [`Combinations`] and [`CombinationIndexer`]
+//! - Compact shuffle IDs via [`PermutationIndex`]
+//! - Suit-isomorphic canonical hands via [`CanonicalHand`] and [`IsomorphismClasses`]
+//! - Serialization support (JSON, YAML, CSV, and a compact binary format)
+//! - JSON Schemas and checksummed deck envelopes via [`DeckEnvelope`]
+//! - Optional TOML, MessagePack, CBOR and bincode helpers behind Cargo features
+//! - Configurable RFC 4180 CSV tables via [`CsvFormat`]
+//! - Bridge deal import and export in PBN via [`PbnBoard`] and [`read_pbn`]
+//! - Poker hand-history parsing via [`HandHistory`]
+//! - Diffable text game records via [`GameRecord`]
 //!
 //! ## Quick Start
 //!
@@ -62,20 +85,107 @@ pub mod objects;
 
 pub use objects::card::Card;
 pub use objects::color::Color;
+pub use objects::composition::Composition;
+pub use objects::composition::CompositionReport;
+pub use objects::composition::EnforcedDeck;
+pub use objects::deal::Deal;
+pub use objects::deal::DealPattern;
+pub use objects::deal::DealStep;
+pub use objects::deal::DealTarget;
 pub use objects::deck::Deck;
+pub use objects::ordered_card::OrderedCard;
+pub use objects::packed_deck::PackedDeck;
 pub use objects::rank::Rank;
 pub use objects::suit::Suit;
+pub use objects::trick::Trick;
+pub use objects::trick::TrickRules;
+pub use objects::visibility::CardView;
+pub use objects::visibility::RedactedDeck;
+pub use objects::visibility::TrackedCard;
+pub use objects::visibility::TrackedDeck;
+pub use objects::visibility::Viewer;
+pub use objects::visibility::Visibility;
 
 pub mod traits;
 
+pub use traits::combinator::ColorGrouped;
+pub use traits::combinator::FnComparator;
+pub use traits::combinator::JokersHigh;
+pub use traits::combinator::JokersLow;
+pub use traits::combinator::Reversed;
+pub use traits::combinator::SuitFirst;
+pub use traits::combinator::ThenBy;
 pub use traits::comparator::AceLowComparator;
 pub use traits::comparator::BridgeComparator;
 pub use traits::comparator::CardComparator;
+pub use traits::comparator::DoppelkopfComparator;
+pub use traits::comparator::DoppelkopfGame;
+pub use traits::comparator::EuchreComparator;
+pub use traits::comparator::SchafkopfComparator;
+pub use traits::comparator::SchafkopfGame;
+pub use traits::comparator::SkatComparator;
+pub use traits::comparator::SkatGame;
 pub use traits::comparator::StandardComparator;
 pub use traits::comparator::TrumpComparator;
+pub use traits::custom_comparator::CustomComparator;
+pub use traits::custom_points::CustomPoints;
 pub use traits::factory::DeckFactory;
+pub use traits::points::BlackjackPoints;
+pub use traits::points::CardPoints;
+pub use traits::points::CribbagePoints;
+pub use traits::points::HeartsPoints;
+pub use traits::points::SkatPoints;
 
 pub mod utils;
 
+pub use utils::binary::DeckReader;
+pub use utils::binary::DeckRecord;
+pub use utils::binary::DeckWriter;
+pub use utils::binary::BINARY_MAGIC;
+pub use utils::binary::BINARY_VERSION;
+pub use utils::combinations::binomial;
+pub use utils::combinations::CombinationIndexer;
+pub use utils::combinations::Combinations;
+pub use utils::csv::CsvColumn;
+pub use utils::csv::CsvError;
+pub use utils::csv::CsvFormat;
+pub use utils::csv::RankStyle;
+pub use utils::csv::SuitStyle;
+pub use utils::envelope::DeckEnvelope;
+pub use utils::envelope::ENVELOPE_VERSION;
+pub use utils::game_record::GameRecord;
+pub use utils::game_record::Move;
+pub use utils::game_record::MoveKind;
+pub use utils::game_record::DECK_ZONE;
+pub use utils::hand_history::read_hand_histories;
+pub use utils::hand_history::Action;
+pub use utils::hand_history::ActionKind;
+pub use utils::hand_history::HandHistory;
+pub use utils::hand_history::Seat;
+pub use utils::hand_history::Street;
+pub use utils::isomorphism::is_isomorphic;
+pub use utils::isomorphism::CanonicalHand;
+pub use utils::isomorphism::IsomorphismClasses;
+pub use utils::isomorphism::SuitPermutation;
+pub use utils::pbn::read_pbn;
+pub use utils::pbn::write_pbn;
+pub use utils::pbn::Direction;
+pub use utils::pbn::PbnBoard;
+pub use utils::pbn::PbnDeal;
+pub use utils::pbn::Vulnerability;
+pub use utils::permutation::PermutationIndex;
+pub use utils::probability::DrawOdds;
+pub use utils::probability::DrawRequirement;
+pub use utils::schema::card_schema;
+pub use utils::schema::deck_envelope_schema;
+pub use utils::schema::deck_schema;
+pub use utils::schema::packed_deck_schema;
+pub use utils::schema::permutation_index_schema;
+pub use utils::spec::DeckSpec;
+pub use utils::stacked::StackedDeckBuilder;
+pub use utils::standard::Doppelkopf48;
+pub use utils::standard::Euchre24;
+pub use utils::standard::Schafkopf24;
+pub use utils::standard::Skat32;
 pub use utils::standard::Standard52;
 pub use utils::standard::Standard54;
@@ -262,14 +262,19 @@ impl Card {
 
     /// Returns a CSV representation of the card as "Rank,Suit".
     ///
+    /// The row uses the suit symbol and `T` for ten, and is not quoted. Use
+    /// [`CsvFormat`](crate::CsvFormat) to write cards as CSV instead.
+    ///
     /// # Examples
     ///
     /// ```rust
+    /// # #![allow(deprecated)]
     /// use crusty_cards::{Card, Suit, Rank};
     ///
     /// let card = Card::new(Suit::Hearts, Rank::Queen);
     /// assert_eq!(card.as_csv_row(), "Q,♥");
     /// ```
+    #[deprecated(note = "use `CsvFormat`")]
     pub fn as_csv_row(&self) -> String {
         format!("{},{}", self.rank, self.suit)
     }
@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+
+use crate::{Card, Deck, DeckFactory};
+
+/// A multiset of cards: how many copies of each card a deck holds.
+///
+/// Order-insensitive, so two decks with the same cards in a different order
+/// have equal compositions.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, Composition, Deck, Rank, Standard52, Suit};
+///
+/// let composition = Composition::from_factory(Standard52) * 2;
+/// assert_eq!(composition.len(), 104);
+/// assert_eq!(composition.count(&Card::new(Suit::Spades, Rank::Ace)), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Composition {
+    counts: HashMap<Card, usize>,
+}
+
+impl Composition {
+    /// Counts the cards yielded by an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Composition, Deck};
+    ///
+    /// let deck: Deck = "A♠ A♠ K♥".parse().unwrap();
+    /// let composition = Composition::from_cards(&deck);
+    /// assert_eq!(composition.distinct(), 2);
+    /// ```
+    pub fn from_cards<'a, I>(cards: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Card>,
+    {
+        let mut counts = HashMap::new();
+        for card in cards {
+            *counts.entry(*card).or_insert(0) += 1;
+        }
+        Composition { counts }
+    }
+
+    /// Counts the cards generated by a [`DeckFactory`].
+    pub fn from_factory<F>(factory: F) -> Self
+    where
+        F: DeckFactory,
+    {
+        Self::from_cards(&factory.generate())
+    }
+
+    /// Returns how many copies of `card` this composition holds.
+    pub fn count(&self, card: &Card) -> usize {
+        self.counts.get(card).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of cards.
+    pub fn len(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Returns `true` if the composition holds no cards.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns the number of distinct cards.
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns an iterator over each distinct card and its count, in no particular order.
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, Card, usize> {
+        self.counts.iter()
+    }
+
+    /// Returns `true` if every card of `other` appears here at least as many times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Composition, Deck, Standard52};
+    ///
+    /// let full = Composition::from_factory(Standard52);
+    /// let hand: Deck = "A♠ K♠".parse().unwrap();
+    /// assert!(full.contains_all(&hand.composition()));
+    /// ```
+    pub fn contains_all(&self, other: &Composition) -> bool {
+        other
+            .counts
+            .iter()
+            .all(|(card, &count)| self.count(card) >= count)
+    }
+
+    /// Compares `deck` against this composition.
+    ///
+    /// The report lists cards the deck is missing, cards it holds too many
+    /// copies of, and cards that do not belong to the composition at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, Composition, Deck, Rank, Standard52, Suit};
+    ///
+    /// let expected = Composition::from_factory(Standard52);
+    /// let mut deck = Deck::from_factory(Standard52);
+    /// let ace = Card::new(Suit::Spades, Rank::Ace);
+    /// deck -= ace;
+    /// deck.add_card(Card::new(Suit::Hearts, Rank::Two));
+    ///
+    /// let report = expected.validate(&deck);
+    /// assert!(!report.is_valid());
+    /// assert_eq!(report.missing(), &[(ace, 1)]);
+    /// assert_eq!(report.duplicated(), &[(Card::new(Suit::Hearts, Rank::Two), 1)]);
+    /// ```
+    pub fn validate(&self, deck: &Deck) -> CompositionReport {
+        let actual = deck.composition();
+        let mut report = CompositionReport::default();
+        for (card, &expected) in &self.counts {
+            let found = actual.count(card);
+            if found < expected {
+                report.missing.push((*card, expected - found));
+            } else if found > expected {
+                report.duplicated.push((*card, found - expected));
+            }
+        }
+        for (card, &found) in &actual.counts {
+            if !self.counts.contains_key(card) {
+                report.foreign.push((*card, found));
+            }
+        }
+        report.sort();
+        report
+    }
+}
+
+impl std::ops::Mul<usize> for Composition {
+    type Output = Composition;
+
+    /// Multiplies every count by `rhs`, like multiplying a [`Deck`].
+    fn mul(self, rhs: usize) -> Composition {
+        Composition {
+            counts: self
+                .counts
+                .into_iter()
+                .filter(|_| rhs > 0)
+                .map(|(card, count)| (card, count * rhs))
+                .collect(),
+        }
+    }
+}
+
+/// The result of validating a [`Deck`] against a [`Composition`].
+///
+/// Each list holds `(card, copies)` pairs sorted by the card's numeric encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CompositionReport {
+    missing: Vec<(Card, usize)>,
+    duplicated: Vec<(Card, usize)>,
+    foreign: Vec<(Card, usize)>,
+}
+
+impl CompositionReport {
+    /// Returns `true` if the deck matched the composition exactly.
+    pub fn is_valid(&self) -> bool {
+        self.missing.is_empty() && self.duplicated.is_empty() && self.foreign.is_empty()
+    }
+
+    /// Cards the deck holds fewer copies of than expected, with how many are missing.
+    pub fn missing(&self) -> &[(Card, usize)] {
+        &self.missing
+    }
+
+    /// Cards the deck holds more copies of than expected, with how many are extra.
+    pub fn duplicated(&self) -> &[(Card, usize)] {
+        &self.duplicated
+    }
+
+    /// Cards that do not belong to the composition, with how many were found.
+    pub fn foreign(&self) -> &[(Card, usize)] {
+        &self.foreign
+    }
+
+    fn sort(&mut self) {
+        for list in [&mut self.missing, &mut self.duplicated, &mut self.foreign] {
+            list.sort_by_key(|(card, _)| u8::from(*card));
+        }
+    }
+}
+
+impl fmt::Display for CompositionReport {
+    /// Formats the report as a single line, e.g. "missing: A♠; duplicated: 2♥x2".
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::{Composition, Deck, Standard52};
+    ///
+    /// let report = Composition::from_factory(Standard52).validate(&Deck::from_factory(Standard52));
+    /// assert_eq!(report.to_string(), "valid");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            return write!(f, "valid");
+        }
+        let format_list = |list: &[(Card, usize)]| {
+            list.iter()
+                .map(|(card, n)| match n {
+                    1 => card.to_string(),
+                    _ => format!("{}x{}", card, n),
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        let sections: Vec<String> = [
+            ("missing", &self.missing),
+            ("duplicated", &self.duplicated),
+            ("foreign", &self.foreign),
+        ]
+        .iter()
+        .filter(|(_, list)| !list.is_empty())
+        .map(|(name, list)| format!("{}: {}", name, format_list(list)))
+        .collect();
+        write!(f, "{}", sections.join("; "))
+    }
+}
+
+/// A [`Deck`] that refuses to hold more cards than a [`Composition`] allows.
+///
+/// The deck may hold fewer cards than the composition (cards that are out in
+/// players' hands), but adding a card fails if it would exceed the allowed
+/// number of copies or if the card does not belong to the composition.
+///
+/// Read-only `Deck` methods are available through `Deref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, Composition, Deck, EnforcedDeck, Rank, Standard52, Suit};
+///
+/// let mut shoe = EnforcedDeck::new(
+///     Deck::from_factory(Standard52),
+///     Composition::from_factory(Standard52),
+/// ).unwrap();
+///
+/// let card = shoe.deal().unwrap();
+/// assert!(shoe.add_card(card).is_ok());
+/// assert!(shoe.add_card(card).is_err());
+/// assert!(shoe.add_card(Card::new(Suit::Hearts, Rank::Joker)).is_err());
+/// assert_eq!(shoe.len(), 52);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnforcedDeck {
+    deck: Deck,
+    composition: Composition,
+}
+
+impl EnforcedDeck {
+    /// Wraps `deck`, enforcing `composition` from now on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `deck` already holds cards the composition does not allow.
+    pub fn new(deck: Deck, composition: Composition) -> Result<Self, String> {
+        if !composition.contains_all(&deck.composition()) {
+            return Err(format!(
+                "Deck does not fit composition: {}",
+                composition.validate(&deck)
+            ));
+        }
+        Ok(EnforcedDeck { deck, composition })
+    }
+
+    /// Returns the enforced composition.
+    pub fn composition(&self) -> &Composition {
+        &self.composition
+    }
+
+    /// Returns the wrapped deck, dropping enforcement.
+    pub fn into_inner(self) -> Deck {
+        self.deck
+    }
+
+    fn check(&self, card: &Card, adding: usize) -> Result<(), String> {
+        let allowed = self.composition.count(card);
+        if allowed == 0 {
+            return Err(format!("Card {} does not belong to this deck", card));
+        }
+        if self.deck.count(card) + adding > allowed {
+            return Err(format!(
+                "Adding {} would exceed the {} allowed copies",
+                card, allowed
+            ));
+        }
+        Ok(())
+    }
+
+    /// Adds a card to the top of the deck.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the deck unchanged, if the card is not part of
+    /// the composition or all its copies are already in the deck.
+    pub fn add_card(&mut self, card: Card) -> Result<(), String> {
+        self.check(&card, 1)?;
+        self.deck.add_card(card);
+        Ok(())
+    }
+
+    /// Adds a card to the bottom of the deck.
+    ///
+    /// # Errors
+    ///
+    /// See [`EnforcedDeck::add_card`].
+    pub fn add_card_bottom(&mut self, card: Card) -> Result<(), String> {
+        self.check(&card, 1)?;
+        self.deck.add_card_bottom(card);
+        Ok(())
+    }
+
+    /// Adds cards to the top of the deck, all or nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the deck unchanged, if any card would violate
+    /// the composition.
+    pub fn add_cards(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        let adding = Composition::from_cards(&cards);
+        for (card, &count) in adding.iter() {
+            self.check(card, count)?;
+        }
+        self.deck.add_cards(cards);
+        Ok(())
+    }
+
+    /// Deals the top card. See [`Deck::deal`].
+    pub fn deal(&mut self) -> Option<Card> {
+        self.deck.deal()
+    }
+
+    /// Deals `n` cards from the top. See [`Deck::deal_n`].
+    pub fn deal_n(&mut self, n: usize) -> Option<Vec<Card>> {
+        self.deck.deal_n(n)
+    }
+
+    /// Deals the bottom card. See [`Deck::deal_bottom`].
+    pub fn deal_bottom(&mut self) -> Option<Card> {
+        self.deck.deal_bottom()
+    }
+
+    /// Shuffles the deck. See [`Deck::shuffle`].
+    pub fn shuffle(&mut self) {
+        self.deck.shuffle();
+    }
+
+    /// Cuts the deck. See [`Deck::cut`].
+    pub fn cut(&mut self, index: usize) -> bool {
+        self.deck.cut(index)
+    }
+}
+
+impl Deref for EnforcedDeck {
+    type Target = Deck;
+
+    fn deref(&self) -> &Deck {
+        &self.deck
+    }
+}
@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Card;
+
+/// A single step of a [`DealPattern`].
+///
+/// Steps are executed in order. `Round` and `Packets` go around the table
+/// starting at the pattern's first seat; `Kitty` sets cards aside.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::DealStep;
+///
+/// // Skat: 3 each, 2 to the skat, 4 each, 3 each
+/// let steps = vec![
+///     DealStep::Round(3),
+///     DealStep::Kitty(2),
+///     DealStep::Round(4),
+///     DealStep::Round(3),
+/// ];
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DealStep {
+    /// Every seat receives a packet of `n` cards, one seat after another.
+    Round(usize),
+    /// Seats receive packets of varying size, one seat after another.
+    /// The seat at position `i` in dealing order receives `sizes[i % sizes.len()]`
+    /// cards, so `Packets(vec![3, 2])` deals 3-2-3-2 around a four-player table.
+    Packets(Vec<usize>),
+    /// `n` cards are set aside into the kitty (widow, skat, crib...).
+    Kitty(usize),
+}
+
+/// Where a single card goes when a [`DealPattern`] is executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DealTarget {
+    /// The card goes to the hand at the given seat.
+    Seat(usize),
+    /// The card goes to the kitty.
+    Kitty,
+}
+
+/// Describes how cards are dealt from a [`Deck`](crate::Deck) to a table of players.
+///
+/// A pattern is a list of [`DealStep`]s plus the seat that receives the first card.
+/// By default dealing starts at seat 0; use [`DealPattern::dealer`] to start at the
+/// seat to the dealer's left, as most games do.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, DealPattern, Standard52};
+///
+/// let mut deck = Deck::from_factory(Standard52);
+/// let deal = deck.deal_to(4, &DealPattern::round_robin(13)).unwrap();
+/// assert_eq!(deal.hands().len(), 4);
+/// assert!(deal.hands().iter().all(|hand| hand.len() == 13));
+/// assert!(deck.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct DealPattern {
+    steps: Vec<DealStep>,
+    start: usize,
+}
+
+impl DealPattern {
+    /// Creates a pattern from a list of steps, starting at seat 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{DealPattern, DealStep};
+    ///
+    /// let pattern = DealPattern::new(vec![DealStep::Round(2), DealStep::Kitty(1)]);
+    /// assert_eq!(pattern.cards_needed(3), 7);
+    /// ```
+    pub fn new(steps: Vec<DealStep>) -> Self {
+        DealPattern { steps, start: 0 }
+    }
+
+    /// Deals `cards_per_seat` cards to every seat, one card at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::DealPattern;
+    ///
+    /// let pattern = DealPattern::round_robin(13);
+    /// assert_eq!(pattern.cards_needed(4), 52);
+    /// ```
+    pub fn round_robin(cards_per_seat: usize) -> Self {
+        Self::new(vec![DealStep::Round(1); cards_per_seat])
+    }
+
+    /// Euchre: packets of 3-2 on the first pass and 2-3 on the second,
+    /// so every seat ends with 5 cards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::DealPattern;
+    ///
+    /// let pattern = DealPattern::euchre();
+    /// assert_eq!(pattern.cards_needed(4), 20);
+    /// ```
+    pub fn euchre() -> Self {
+        Self::new(vec![
+            DealStep::Packets(vec![3, 2]),
+            DealStep::Packets(vec![2, 3]),
+        ])
+    }
+
+    /// Skat: 3 cards each, 2 to the skat, 4 cards each, then 3 cards each.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::DealPattern;
+    ///
+    /// let pattern = DealPattern::skat();
+    /// assert_eq!(pattern.cards_needed(3), 32);
+    /// ```
+    pub fn skat() -> Self {
+        Self::new(vec![
+            DealStep::Round(3),
+            DealStep::Kitty(2),
+            DealStep::Round(4),
+            DealStep::Round(3),
+        ])
+    }
+
+    /// Sets the seat that receives the first card.
+    ///
+    /// The seat is taken modulo the number of players when dealing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{DealPattern, DealTarget};
+    ///
+    /// let pattern = DealPattern::round_robin(1).start_at(2);
+    /// assert_eq!(pattern.schedule(3), vec![
+    ///     DealTarget::Seat(2),
+    ///     DealTarget::Seat(0),
+    ///     DealTarget::Seat(1),
+    /// ]);
+    /// ```
+    pub fn start_at(mut self, seat: usize) -> Self {
+        self.start = seat;
+        self
+    }
+
+    /// Sets the dealer; the first card goes to the seat on the dealer's left.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{DealPattern, DealTarget};
+    ///
+    /// let pattern = DealPattern::round_robin(1).dealer(3);
+    /// assert_eq!(pattern.schedule(4)[0], DealTarget::Seat(0));
+    /// ```
+    pub fn dealer(self, seat: usize) -> Self {
+        self.start_at(seat.wrapping_add(1))
+    }
+
+    /// Returns the steps of this pattern.
+    pub fn steps(&self) -> &[DealStep] {
+        &self.steps
+    }
+
+    /// Returns the total number of cards this pattern deals to `players` seats.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::DealPattern;
+    ///
+    /// assert_eq!(DealPattern::round_robin(5).cards_needed(6), 30);
+    /// ```
+    pub fn cards_needed(&self, players: usize) -> usize {
+        self.steps
+            .iter()
+            .map(|step| match step {
+                DealStep::Round(n) => n * players,
+                DealStep::Packets(sizes) if sizes.is_empty() => 0,
+                DealStep::Packets(sizes) => (0..players).map(|i| sizes[i % sizes.len()]).sum(),
+                DealStep::Kitty(n) => *n,
+            })
+            .sum()
+    }
+
+    /// Returns where each card goes, in the order cards leave the top of the deck.
+    ///
+    /// Returns an empty schedule when `players` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{DealPattern, DealStep, DealTarget};
+    ///
+    /// let pattern = DealPattern::new(vec![DealStep::Packets(vec![2, 1]), DealStep::Kitty(1)]);
+    /// assert_eq!(pattern.schedule(2), vec![
+    ///     DealTarget::Seat(0),
+    ///     DealTarget::Seat(0),
+    ///     DealTarget::Seat(1),
+    ///     DealTarget::Kitty,
+    /// ]);
+    /// ```
+    pub fn schedule(&self, players: usize) -> Vec<DealTarget> {
+        let mut targets = Vec::with_capacity(self.cards_needed(players));
+        if players == 0 {
+            return targets;
+        }
+        let seat = |i: usize| DealTarget::Seat((self.start % players + i) % players);
+        for step in &self.steps {
+            match step {
+                DealStep::Round(n) => {
+                    for i in 0..players {
+                        targets.extend((0..*n).map(|_| seat(i)));
+                    }
+                }
+                DealStep::Packets(sizes) if sizes.is_empty() => {}
+                DealStep::Packets(sizes) => {
+                    for i in 0..players {
+                        targets.extend((0..sizes[i % sizes.len()]).map(|_| seat(i)));
+                    }
+                }
+                DealStep::Kitty(n) => targets.extend((0..*n).map(|_| DealTarget::Kitty)),
+            }
+        }
+        targets
+    }
+}
+
+/// The result of dealing a [`Deck`](crate::Deck) with a [`DealPattern`].
+///
+/// Holds one hand per seat (indexed by absolute seat number) and the kitty.
+/// Cards appear in each hand in the order they were dealt.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, DealPattern, Standard52};
+///
+/// let mut deck = Deck::from_factory(Standard52);
+/// let deal = deck.deal_to(3, &DealPattern::skat()).unwrap();
+/// assert_eq!(deal.hand(0).unwrap().len(), 10);
+/// assert_eq!(deal.kitty().len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Deal {
+    hands: Vec<Vec<Card>>,
+    kitty: Vec<Card>,
+}
+
+impl Deal {
+    /// Creates a deal from hands and a kitty.
+    pub fn new(hands: Vec<Vec<Card>>, kitty: Vec<Card>) -> Self {
+        Deal { hands, kitty }
+    }
+
+    /// Returns all hands, indexed by seat.
+    pub fn hands(&self) -> &[Vec<Card>] {
+        &self.hands
+    }
+
+    /// Returns the hand at `seat`, or `None` if there is no such seat.
+    pub fn hand(&self, seat: usize) -> Option<&[Card]> {
+        self.hands.get(seat).map(Vec::as_slice)
+    }
+
+    /// Returns the cards set aside in the kitty.
+    pub fn kitty(&self) -> &[Card] {
+        &self.kitty
+    }
+
+    /// Consumes the deal, returning the hands and the kitty.
+    pub fn into_parts(self) -> (Vec<Vec<Card>>, Vec<Card>) {
+        (self.hands, self.kitty)
+    }
+}
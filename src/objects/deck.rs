@@ -2,15 +2,29 @@ use rand::rng;
 use rand::seq::SliceRandom;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use std::convert::{From, TryFrom};
 use std::fmt;
 use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
 use std::str::FromStr;
 
+use crate::CanonicalHand;
 use crate::Card;
 use crate::CardComparator;
+use crate::CardPoints;
+use crate::Combinations;
+use crate::Composition;
+use crate::CompositionReport;
+use crate::CsvFormat;
+use crate::Deal;
+use crate::DealPattern;
+use crate::DealTarget;
+use crate::DeckEnvelope;
 use crate::DeckFactory;
+use crate::DeckReader;
+use crate::DeckSpec;
+use crate::DeckWriter;
+use crate::PermutationIndex;
 
 /// A collection of playing cards with deck manipulation operations.
 ///
@@ -136,6 +150,27 @@ impl Deck {
         }
     }
 
+    /// Creates a deck from a text specification such as
+    /// `"2x standard52 + 4 jokers - 2C"`.
+    ///
+    /// See [`DeckSpec`] for the grammar. To use factories other than
+    /// `standard52` and `standard54`, register them on a [`DeckSpec`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::Deck;
+    ///
+    /// let shoe = Deck::from_spec("standard52 * 6").unwrap();
+    /// assert_eq!(shoe.len(), 312);
+    ///
+    /// let deck = Deck::from_spec("standard52 + joker red + joker black").unwrap();
+    /// assert_eq!(deck.len(), 54);
+    /// ```
+    pub fn from_spec(spec: &str) -> Result<Self, String> {
+        DeckSpec::new().build(spec)
+    }
+
     /// Returns an iterator over references to the cards.
     ///
     /// # Examples
@@ -282,7 +317,7 @@ impl Deck {
     /// assert_eq!(deck.to_string(), "J♠ Q♠ K♠ A♠");
     /// ```
     pub fn reverse(&mut self) {
-        self.cards = self.cards.iter().cloned().rev().collect();
+        self.cards.make_contiguous().reverse();
     }
 
     /// Counts occurrences of a specific card in the deck.
@@ -303,6 +338,161 @@ impl Deck {
         self.cards.iter().filter(|&&c| c == *card).count()
     }
 
+    /// Returns how many copies of each card the deck holds.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::{Card, Deck, Rank, Suit};
+    ///
+    /// let deck: Deck = "A♠ K♥ A♠".parse().unwrap();
+    /// let composition = deck.composition();
+    /// assert_eq!(composition.count(&Card::new(Suit::Spades, Rank::Ace)), 2);
+    /// assert_eq!(composition.distinct(), 2);
+    /// ```
+    pub fn composition(&self) -> Composition {
+        Composition::from_cards(&self.cards)
+    }
+
+    /// Checks the deck against the cards generated by a [`DeckFactory`].
+    ///
+    /// Reports missing, duplicated and foreign cards. Order is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::{Deck, Standard52, Standard54};
+    ///
+    /// let mut deck = Deck::from_factory(Standard52);
+    /// deck.shuffle();
+    /// assert!(deck.validate_against(Standard52).is_valid());
+    /// assert_eq!(deck.validate_against(Standard54).missing().len(), 2);
+    /// ```
+    pub fn validate_against<F>(&self, factory: F) -> CompositionReport
+    where
+        F: DeckFactory,
+    {
+        Composition::from_factory(factory).validate(self)
+    }
+
+    /// Returns the permutation index of this deck's order relative to the
+    /// order `factory` generates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deck or the factory hold duplicate cards, or if
+    /// the deck does not hold exactly the factory's cards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Deck, PermutationIndex, Standard52};
+    ///
+    /// let deck = Deck::from_factory(Standard52);
+    /// assert_eq!(deck.permutation_index(Standard52).unwrap(), PermutationIndex::default());
+    ///
+    /// let doubled = deck.clone() * 2;
+    /// assert!(doubled.permutation_index(Standard52).is_err());
+    /// ```
+    pub fn permutation_index<F>(&self, factory: F) -> Result<PermutationIndex, String>
+    where
+        F: DeckFactory,
+    {
+        let reference: Vec<Card> = factory.generate().into_iter().collect();
+        let cards: Vec<Card> = self.iter().copied().collect();
+        let mut seen = HashSet::with_capacity(cards.len());
+        if let Some(card) = cards.iter().find(|card| !seen.insert(**card)) {
+            return Err(format!("Deck contains duplicate card {}", card));
+        }
+        PermutationIndex::rank(&cards, &reference)
+    }
+
+    /// Rebuilds the deck order described by `index` over the cards of `factory`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the factory generates duplicate cards or if the index
+    /// is out of range for the factory's number of cards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Deck, PermutationIndex, Standard52};
+    ///
+    /// let index: PermutationIndex = "1".parse().unwrap();
+    /// let deck = Deck::from_permutation_index(&index, Standard52).unwrap();
+    /// assert_eq!(deck.permutation_index(Standard52).unwrap(), index);
+    /// ```
+    pub fn from_permutation_index<F>(index: &PermutationIndex, factory: F) -> Result<Deck, String>
+    where
+        F: DeckFactory,
+    {
+        let reference: Vec<Card> = factory.generate().into_iter().collect();
+        Ok(index.unrank(&reference)?.into_iter().collect())
+    }
+
+    /// Returns a lazy iterator over every `k`-card combination of the deck.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::{Deck, Standard52};
+    ///
+    /// let deck = Deck::from_factory(Standard52);
+    /// assert_eq!(deck.combinations(5).remaining(), 2_598_960);
+    /// ```
+    pub fn combinations(&self, k: usize) -> Combinations {
+        let cards: Vec<Card> = self.cards.iter().copied().collect();
+        Combinations::new(&cards, k)
+    }
+
+    /// Returns a lazy iterator over every `k`-card combination of the deck
+    /// that contains none of the `dead` cards.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::{Deck, Standard52};
+    ///
+    /// let deck = Deck::from_factory(Standard52);
+    /// let dead: Vec<_> = "A♠ K♠".parse::<Deck>().unwrap().into_iter().collect();
+    /// assert_eq!(deck.combinations_excluding(5, &dead).remaining(), 2_118_760);
+    /// ```
+    pub fn combinations_excluding(&self, k: usize, dead: &[Card]) -> Combinations {
+        let cards: Vec<Card> = self.cards.iter().copied().collect();
+        Combinations::excluding(&cards, k, dead)
+    }
+
+    /// Returns the canonical form of the deck's cards under suit permutation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::Deck;
+    ///
+    /// let hand: Deck = "7♠ A♣".parse().unwrap();
+    /// assert_eq!(hand.canonical_form().to_string(), "A♥ 7♦");
+    /// ```
+    pub fn canonical_form(&self) -> CanonicalHand {
+        let cards: Vec<Card> = self.iter().copied().collect();
+        CanonicalHand::of(&cards)
+    }
+
+    /// Returns `true` if both decks hold the same cards, ignoring order.
+    ///
+    /// Unlike `==`, which also compares order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::Deck;
+    ///
+    /// let a: Deck = "A♠ K♥ K♥".parse().unwrap();
+    /// let b: Deck = "K♥ A♠ K♥".parse().unwrap();
+    /// let c: Deck = "K♥ A♠ A♠".parse().unwrap();
+    /// assert!(a.same_cards_as(&b));
+    /// assert!(!a.same_cards_as(&c));
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn same_cards_as(&self, other: &Deck) -> bool {
+        self.len() == other.len() && self.composition() == other.composition()
+    }
+
     /// Returns the number of cards in the deck.
     ///
     /// # Examples
@@ -362,10 +552,7 @@ impl Deck {
     /// deck.shuffle();
     /// ```
     pub fn shuffle(&mut self) {
-        let mut rng = rng();
-        let mut cards_vec: Vec<Card> = self.cards.drain(..).collect();
-        cards_vec.shuffle(&mut rng);
-        self.cards = VecDeque::from(cards_vec);
+        self.cards.make_contiguous().shuffle(&mut rng());
     }
 
     /// Shuffles the deck multiple times.
@@ -461,6 +648,153 @@ impl Deck {
         }
     }
 
+    /// Randomly shuffles only the top `k` cards' positions, using the
+    /// thread-local random number generator.
+    ///
+    /// See [`Deck::partial_shuffle_with_rng`].
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let mut deck = Deck::from_factory(Standard52);
+    /// deck.partial_shuffle(5);
+    /// assert_eq!(deck.len(), 52);
+    /// ```
+    pub fn partial_shuffle(&mut self, k: usize) {
+        self.partial_shuffle_with_rng(k, &mut rng());
+    }
+
+    /// Randomly shuffles only the top `k` cards' positions using `rng`.
+    ///
+    /// Runs the first `k` steps of Fisher-Yates: afterwards the top `k` cards
+    /// are a uniformly random selection from the whole deck in random order,
+    /// which is all that is needed before dealing `k` cards. The rest of the
+    /// deck is not randomized. `k` is capped at the deck's length.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut a = Deck::from_factory(Standard52);
+    /// let mut b = Deck::from_factory(Standard52);
+    /// a.partial_shuffle_with_rng(5, &mut StdRng::seed_from_u64(7));
+    /// b.partial_shuffle_with_rng(5, &mut StdRng::seed_from_u64(7));
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn partial_shuffle_with_rng<R>(&mut self, k: usize, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        shuffle_prefix(self.cards.make_contiguous(), k, rng);
+    }
+
+    /// Removes and returns a uniformly random card, using the thread-local
+    /// random number generator.
+    ///
+    /// Returns `None` if the deck is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let mut deck = Deck::from_factory(Standard52);
+    /// let card = deck.draw_random().unwrap();
+    /// assert!(!deck.contains(&card));
+    /// ```
+    pub fn draw_random(&mut self) -> Option<Card> {
+        self.draw_random_with_rng(&mut rng())
+    }
+
+    /// Removes and returns a uniformly random card using `rng`.
+    ///
+    /// The remaining cards keep their order. Returns `None` if the deck is empty.
+    pub fn draw_random_with_rng<R>(&mut self, rng: &mut R) -> Option<Card>
+    where
+        R: Rng + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        let index = rng.random_range(0..self.len());
+        self.cards.remove(index)
+    }
+
+    /// Returns `k` distinct random cards without removing them, using the
+    /// thread-local random number generator.
+    ///
+    /// Returns `None` if the deck has fewer than `k` cards.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let deck = Deck::from_factory(Standard52);
+    /// let sample = deck.sample(5).unwrap();
+    /// assert_eq!(sample.len(), 5);
+    /// assert_eq!(deck.len(), 52);
+    /// ```
+    pub fn sample(&self, k: usize) -> Option<Vec<Card>> {
+        self.sample_with_rng(k, &mut rng())
+    }
+
+    /// Returns `k` cards from distinct random positions, in random order,
+    /// without removing them, using `rng`.
+    ///
+    /// Returns `None` if the deck has fewer than `k` cards.
+    pub fn sample_with_rng<R>(&self, k: usize, rng: &mut R) -> Option<Vec<Card>>
+    where
+        R: Rng + ?Sized,
+    {
+        if k > self.len() {
+            return None;
+        }
+        Some(
+            rand::seq::index::sample(rng, self.len(), k)
+                .into_iter()
+                .map(|index| self.cards[index])
+                .collect(),
+        )
+    }
+
+    /// Removes and returns `k` random cards, using the thread-local random
+    /// number generator.
+    ///
+    /// Returns `None`, removing nothing, if the deck has fewer than `k` cards.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let mut deck = Deck::from_factory(Standard52);
+    /// let hand = deck.take_random(5).unwrap();
+    /// assert_eq!(hand.len(), 5);
+    /// assert_eq!(deck.len(), 47);
+    /// ```
+    pub fn take_random(&mut self, k: usize) -> Option<Vec<Card>> {
+        self.take_random_with_rng(k, &mut rng())
+    }
+
+    /// Removes and returns `k` cards from distinct random positions, in random
+    /// order, using `rng`.
+    ///
+    /// The remaining cards keep their order. Returns `None`, removing nothing,
+    /// if the deck has fewer than `k` cards.
+    pub fn take_random_with_rng<R>(&mut self, k: usize, rng: &mut R) -> Option<Vec<Card>>
+    where
+        R: Rng + ?Sized,
+    {
+        if k > self.len() {
+            return None;
+        }
+        let indices = rand::seq::index::sample(rng, self.len(), k).into_vec();
+        let taken = indices.iter().map(|&index| self.cards[index]).collect();
+        let mut removing = indices;
+        removing.sort_unstable_by(|a, b| b.cmp(a));
+        for index in removing {
+            self.cards.remove(index);
+        }
+        Some(taken)
+    }
+
     /// Deals (removes and returns) the top card.
     ///
     /// Returns `None` if the deck is empty.
@@ -576,6 +910,39 @@ impl Deck {
         Some(cards)
     }
 
+    /// Deals cards to `players` seats following a [`DealPattern`].
+    ///
+    /// Cards leave the top of the deck in the order given by
+    /// [`DealPattern::schedule`]. Returns one hand per seat plus the kitty.
+    ///
+    /// Returns `None` (leaving the deck untouched) if `players` is 0 or if
+    /// fewer cards remain than the pattern needs.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::{Deck, DealPattern, Standard52};
+    /// let mut deck = Deck::from_factory(Standard52);
+    /// let top = *deck.peek().unwrap();
+    /// let deal = deck.deal_to(4, &DealPattern::round_robin(5).dealer(0)).unwrap();
+    /// assert_eq!(deal.hand(1).unwrap()[0], top);
+    /// assert_eq!(deck.len(), 32);
+    /// ```
+    pub fn deal_to(&mut self, players: usize, pattern: &DealPattern) -> Option<Deal> {
+        if players == 0 || pattern.cards_needed(players) > self.len() {
+            return None;
+        }
+        let mut hands = vec![Vec::new(); players];
+        let mut kitty = Vec::new();
+        for target in pattern.schedule(players) {
+            let card = self.deal()?;
+            match target {
+                DealTarget::Seat(seat) => hands[seat].push(card),
+                DealTarget::Kitty => kitty.push(card),
+            }
+        }
+        Some(Deal::new(hands, kitty))
+    }
+
     /// Adds a card to the top of the deck.
     ///
     /// # Examples
@@ -761,6 +1128,10 @@ impl Deck {
 
     /// Creates a Deck from a JSON string.
     ///
+    /// Accepts either a plain deck or a [`DeckEnvelope`]. An envelope is
+    /// recognized by its `format_version` field and must pass
+    /// [`DeckEnvelope::validate`].
+    ///
     /// # Examples
     /// ```
     /// use crusty_cards::{Deck, Card, Rank, Suit};
@@ -771,7 +1142,11 @@ impl Deck {
     /// assert_eq!(deck, deck2);
     /// ```
     pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(s)
+        let value: serde_json::Value = serde_json::from_str(s)?;
+        if value.get("format_version").is_some() {
+            return DeckEnvelope::from_value(value).map(DeckEnvelope::into_deck);
+        }
+        serde_json::from_value(value)
     }
 
     /// Serializes the deck to a YAML string.
@@ -802,6 +1177,160 @@ impl Deck {
         serde_yaml::from_str(s)
     }
 
+    /// Serializes the deck to a TOML string.
+    ///
+    /// Requires the `toml` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Card, Rank, Suit};
+    /// let mut deck = Deck::default();
+    /// deck.add_card(Card::new(Suit::Hearts, Rank::Ace));
+    /// let toml = deck.to_toml().unwrap();
+    /// assert!(toml.contains("[[cards]]"));
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(self)
+    }
+
+    /// Creates a Deck from a TOML string.
+    ///
+    /// Requires the `toml` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::Deck;
+    /// let toml = "[[cards]]\nsuit = \"Spades\"\nrank = \"Ace\"\n";
+    /// let deck = Deck::from_toml(toml).unwrap();
+    /// assert_eq!(deck.to_string(), "A♠");
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+
+    /// Serializes the deck to MessagePack, with field names.
+    ///
+    /// Requires the `msgpack` feature.
+    #[cfg(feature = "msgpack")]
+    pub fn to_msgpack(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        rmp_serde::to_vec_named(self)
+    }
+
+    /// Creates a Deck from MessagePack.
+    ///
+    /// Requires the `msgpack` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let deck = Deck::from_factory(Standard52);
+    /// let bytes = deck.to_msgpack().unwrap();
+    /// assert_eq!(Deck::from_msgpack(&bytes).unwrap(), deck);
+    /// ```
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack(bytes: &[u8]) -> Result<Self, rmp_serde::decode::Error> {
+        rmp_serde::from_slice(bytes)
+    }
+
+    /// Serializes the deck to CBOR.
+    ///
+    /// Requires the `cbor` feature.
+    #[cfg(feature = "cbor")]
+    pub fn to_cbor(&self) -> Result<Vec<u8>, ciborium::ser::Error<std::io::Error>> {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(self, &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Creates a Deck from CBOR.
+    ///
+    /// Requires the `cbor` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let deck = Deck::from_factory(Standard52);
+    /// let bytes = deck.to_cbor().unwrap();
+    /// assert_eq!(Deck::from_cbor(&bytes).unwrap(), deck);
+    /// ```
+    #[cfg(feature = "cbor")]
+    pub fn from_cbor(bytes: &[u8]) -> Result<Self, ciborium::de::Error<std::io::Error>> {
+        ciborium::from_reader(bytes)
+    }
+
+    /// Serializes the deck with bincode.
+    ///
+    /// Requires the `bincode` feature.
+    #[cfg(feature = "bincode")]
+    pub fn to_bincode(&self) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(self)
+    }
+
+    /// Creates a Deck from bincode.
+    ///
+    /// Requires the `bincode` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let deck = Deck::from_factory(Standard52);
+    /// let bytes = deck.to_bincode().unwrap();
+    /// assert_eq!(Deck::from_bincode(&bytes).unwrap(), deck);
+    /// ```
+    #[cfg(feature = "bincode")]
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
+
+    /// Serializes the deck to the compact binary format described on [`DeckWriter`].
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let deck = Deck::from_factory(Standard52);
+    /// let bytes = deck.to_binary();
+    /// assert_eq!(bytes.len(), 66);
+    /// assert_eq!(&bytes[..4], b"CCDK");
+    /// ```
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut writer = DeckWriter::new(Vec::with_capacity(self.len() + 14));
+        writer
+            .write_deck(self)
+            .expect("writing to a Vec cannot fail for decks under 4 GiB cards");
+        writer.into_inner()
+    }
+
+    /// Creates a Deck from a single record in the binary format.
+    ///
+    /// Any factory identifier in the record is ignored; use [`DeckReader`] to read it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not exactly one valid record.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{Deck, Standard52};
+    /// let deck = Deck::from_factory(Standard52);
+    /// let restored = Deck::from_binary(&deck.to_binary()).unwrap();
+    /// assert_eq!(restored, deck);
+    /// ```
+    pub fn from_binary(bytes: &[u8]) -> std::io::Result<Self> {
+        let mut reader = DeckReader::new(bytes);
+        let record = reader
+            .read_record()?
+            .ok_or(std::io::ErrorKind::UnexpectedEof)?;
+        if !reader.into_inner().is_empty() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Trailing bytes after deck record",
+            ));
+        }
+        Ok(record.into_deck())
+    }
+
     /// Sorts the deck using a custom comparator.
     ///
     /// # Example
@@ -816,11 +1345,30 @@ impl Deck {
     ///
     /// // Or sort with Ace low
     /// deck.sort_by_comparator(&AceLowComparator);
+    ///
+    /// // Adapters compose, and trait objects work too
+    /// use crusty_cards::CardComparator;
+    /// deck.sort_by_comparator(&AceLowComparator.suit_first().reversed());
+    /// let boxed: Box<dyn CardComparator> = Box::new(StandardComparator);
+    /// deck.sort_by_comparator(boxed.as_ref());
+    /// ```
+    pub fn sort_by_comparator<C: CardComparator + ?Sized>(&mut self, comparator: &C) {
+        self.cards
+            .make_contiguous()
+            .sort_by(|a, b| comparator.compare(a, b));
+    }
+
+    /// Returns the total points of the cards in the deck under `scheme`.
+    ///
+    /// # Example
     /// ```
-    pub fn sort_by_comparator<C: CardComparator>(&mut self, comparator: &C) {
-        let mut cards_vec: Vec<Card> = self.cards.drain(..).collect();
-        cards_vec.sort_by(|a, b| comparator.compare(a, b));
-        self.cards = VecDeque::from(cards_vec);
+    /// use crusty_cards::{Deck, Skat32, SkatPoints};
+    ///
+    /// let deck = Deck::from_factory(Skat32);
+    /// assert_eq!(deck.points(&SkatPoints), 120);
+    /// ```
+    pub fn points<P: CardPoints + ?Sized>(&self, scheme: &P) -> i32 {
+        self.cards.iter().map(|card| scheme.points(card)).sum()
     }
 
     /// Sorts the deck using a custom comparison function.
@@ -839,24 +1387,28 @@ impl Deck {
     where
         F: FnMut(&Card, &Card) -> std::cmp::Ordering,
     {
-        let mut cards_vec: Vec<Card> = self.cards.drain(..).collect();
-        cards_vec.sort_by(compare);
-        self.cards = VecDeque::from(cards_vec);
+        self.cards.make_contiguous().sort_by(compare);
     }
 
     /// Returns a CSV representation of the deck.
     ///
+    /// Rows are bare `Rank,Suit` pairs with suit symbols and `T` for ten.
+    /// Use [`CsvFormat::write_deck`] instead for a quoted, configurable table
+    /// that spreadsheets read.
+    ///
     /// # Examples
     /// ```
+    /// # #![allow(deprecated)]
     /// use crusty_cards::{Deck, Card, Rank, Suit};
     /// let mut deck = Deck::default();
     /// deck.add_card(Card::new(Suit::Hearts, Rank::Ace));
-    /// let csv = deck.to_csv();
+    /// assert_eq!(deck.to_csv(), "Rank,Suit\nA,♥\n");
     /// ```
+    #[deprecated(note = "use `CsvFormat::write_deck`")]
     pub fn to_csv(&self) -> String {
         let mut csv = "Rank,Suit\n".to_string();
         for card in &self.cards {
-            csv.push_str(&card.as_csv_row());
+            csv.push_str(&format!("{},{}", card.rank(), card.suit()));
             csv.push('\n');
         }
         csv
@@ -864,30 +1416,33 @@ impl Deck {
 
     /// Creates a Deck from a CSV string.
     ///
+    /// Accepts any table [`CsvFormat`] reads: the header row is optional,
+    /// fields may be quoted and lines may end in CRLF.
+    ///
     /// # Examples
     /// ```
-    /// use crusty_cards::{Deck, Card, Rank, Suit};
+    /// use crusty_cards::{CsvFormat, Deck, Card, Rank, Suit};
     /// let mut deck = Deck::default();
     /// deck.add_card(Card::new(Suit::Hearts, Rank::Ace));
-    /// let csv = deck.to_csv();
+    /// let csv = CsvFormat::new().write_deck(&deck);
     /// let deck2 = Deck::from_csv(&csv).unwrap();
     /// assert_eq!(deck, deck2);
+    /// assert_eq!(Deck::from_csv("\"A\",\"H\"\r\n").unwrap(), deck);
     /// ```
     pub fn from_csv(s: &str) -> Result<Self, String> {
-        let mut cards = VecDeque::new();
-        for (i, line) in s.lines().enumerate() {
-            if i == 0 {
-                continue;
-            }
-            if line.trim().is_empty() {
-                continue;
-            }
-            match Card::from_csv_row(line) {
-                Ok(card) => cards.push_back(card),
-                Err(e) => return Err(format!("Failed to parse line {}: {}", i + 1, e)),
-            }
-        }
-        Ok(Deck::new(cards))
+        CsvFormat::new().read_deck(s).map_err(|e| e.to_string())
+    }
+}
+
+/// Runs the first `k` steps of Fisher-Yates over `items`, leaving a uniformly
+/// random selection in random order at the front.
+pub(crate) fn shuffle_prefix<T, R>(items: &mut [T], k: usize, rng: &mut R)
+where
+    R: Rng + ?Sized,
+{
+    let len = items.len();
+    for i in 0..k.min(len) {
+        items.swap(i, rng.random_range(i..len));
     }
 }
 
@@ -903,10 +1458,9 @@ impl Add<Card> for Deck {
     /// let new_deck = deck + Card::new(Suit::Hearts, Rank::Ace);
     /// assert_eq!(new_deck.len(), 1);
     /// ```
-    fn add(self, rhs: Card) -> Deck {
-        let mut new_deck = self.clone();
-        new_deck.add_card(rhs);
-        new_deck
+    fn add(mut self, rhs: Card) -> Deck {
+        self.add_card(rhs);
+        self
     }
 }
 
@@ -937,10 +1491,9 @@ impl Sub<Card> for Deck {
     /// let new_deck = deck - Card::new(Suit::Hearts, Rank::Ace);
     /// assert_eq!(new_deck.len(), 0);
     /// ```
-    fn sub(self, rhs: Card) -> Deck {
-        let mut new_deck = self.clone();
-        new_deck.cards.retain(|c| c != &rhs);
-        new_deck
+    fn sub(mut self, rhs: Card) -> Deck {
+        self -= rhs;
+        self
     }
 }
 
@@ -956,7 +1509,7 @@ impl SubAssign<Card> for Deck {
     /// assert_eq!(deck.len(), 0);
     /// ```
     fn sub_assign(&mut self, rhs: Card) {
-        *self = self.clone() - rhs;
+        self.cards.retain(|c| c != &rhs);
     }
 }
 
@@ -973,14 +1526,9 @@ impl Mul<usize> for Deck {
     /// let new_deck = deck * 2;
     /// assert_eq!(new_deck.len(), 2);
     /// ```
-    fn mul(self, rhs: usize) -> Deck {
-        let mut new_deck = Deck::new(VecDeque::new());
-        for _ in 0..rhs {
-            for card in &self.cards {
-                new_deck.add_card(*card);
-            }
-        }
-        new_deck
+    fn mul(mut self, rhs: usize) -> Deck {
+        self *= rhs;
+        self
     }
 }
 
@@ -996,13 +1544,16 @@ impl MulAssign<usize> for Deck {
     /// assert_eq!(deck.len(), 2);
     /// ```
     fn mul_assign(&mut self, rhs: usize) {
-        let original_deck = self.clone();
-        self.clear();
-        for _ in 0..rhs {
-            for card in &original_deck.cards {
-                self.add_card(*card);
-            }
+        // Same order as adding each card to the top, `rhs` times over.
+        self.reverse();
+        let once = self.len();
+        let total = once * rhs;
+        self.cards.reserve(total.saturating_sub(once));
+        for i in once..total {
+            let card = self.cards[i - once];
+            self.cards.push_back(card);
         }
+        self.cards.truncate(total);
     }
 }
 
@@ -1021,12 +1572,9 @@ impl Add<Deck> for Deck {
     /// let new_deck = deck1 + deck2;
     /// assert_eq!(new_deck.len(), 2);
     /// ```
-    fn add(self, rhs: Deck) -> Deck {
-        let mut new_deck = self.clone();
-        for card in rhs.cards {
-            new_deck.add_card(card);
-        }
-        new_deck
+    fn add(mut self, rhs: Deck) -> Deck {
+        self += rhs;
+        self
     }
 }
 
@@ -1066,12 +1614,9 @@ impl Sub<Deck> for Deck {
     /// let new_deck = deck1 - deck2;
     /// assert_eq!(new_deck.len(), 1);
     /// ```
-    fn sub(self, rhs: Deck) -> Deck {
-        let mut new_deck = self.clone();
-        for card in rhs.cards {
-            new_deck = new_deck.clone() - card;
-        }
-        new_deck
+    fn sub(mut self, rhs: Deck) -> Deck {
+        self -= rhs;
+        self
     }
 }
 
@@ -1090,7 +1635,8 @@ impl SubAssign<Deck> for Deck {
     /// assert_eq!(deck1.len(), 1);
     /// ```
     fn sub_assign(&mut self, rhs: Deck) {
-        *self = self.clone() - rhs;
+        let removed: HashSet<Card> = rhs.cards.into_iter().collect();
+        self.cards.retain(|c| !removed.contains(c));
     }
 }
 
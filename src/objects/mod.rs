@@ -1,11 +1,34 @@
 pub mod card;
 pub mod color;
+pub mod composition;
+pub mod deal;
 pub mod deck;
+pub mod ordered_card;
+pub mod packed_deck;
 pub mod rank;
 pub mod suit;
+pub mod trick;
+pub mod visibility;
 
 pub use card::Card;
 pub use color::Color;
+pub use composition::Composition;
+pub use composition::CompositionReport;
+pub use composition::EnforcedDeck;
+pub use deal::Deal;
+pub use deal::DealPattern;
+pub use deal::DealStep;
+pub use deal::DealTarget;
 pub use deck::Deck;
+pub use ordered_card::OrderedCard;
+pub use packed_deck::PackedDeck;
 pub use rank::Rank;
 pub use suit::Suit;
+pub use trick::Trick;
+pub use trick::TrickRules;
+pub use visibility::CardView;
+pub use visibility::RedactedDeck;
+pub use visibility::TrackedCard;
+pub use visibility::TrackedDeck;
+pub use visibility::Viewer;
+pub use visibility::Visibility;
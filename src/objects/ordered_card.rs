@@ -0,0 +1,127 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::{Card, CardComparator, StandardComparator};
+
+/// A card ordered by the comparator `C`, for use in sorted collections such
+/// as `BTreeSet` and `BinaryHeap`.
+///
+/// The comparator is part of the type and built with `C::default()`, so the
+/// wrapper is the same size as a [`Card`]. For a comparator with
+/// configuration, such as a trump suit, define a unit struct that implements
+/// [`CardComparator`] by delegating to it.
+///
+/// Cards the comparator ranks equal are ordered by their byte encoding, so
+/// `Ord` agrees with [`Card`]'s `Eq` and a set never merges different cards.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{AceLowComparator, Card, OrderedCard, Rank, Suit};
+/// use std::collections::{BTreeSet, BinaryHeap};
+///
+/// let ace = Card::new(Suit::Spades, Rank::Ace);
+/// let king = Card::new(Suit::Hearts, Rank::King);
+///
+/// let mut heap: BinaryHeap<OrderedCard> = [king, ace].into_iter().map(OrderedCard::new).collect();
+/// assert_eq!(heap.pop().unwrap().card(), ace);
+///
+/// let low: BTreeSet<OrderedCard<AceLowComparator>> =
+///     [king, ace].into_iter().map(OrderedCard::new).collect();
+/// assert_eq!(low.first().unwrap().card(), ace);
+/// ```
+pub struct OrderedCard<C = StandardComparator> {
+    card: Card,
+    comparator: PhantomData<fn() -> C>,
+}
+
+impl<C> OrderedCard<C> {
+    /// Wraps `card`.
+    pub fn new(card: Card) -> Self {
+        OrderedCard {
+            card,
+            comparator: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped card.
+    pub fn card(&self) -> Card {
+        self.card
+    }
+
+    /// Unwraps the card.
+    pub fn into_card(self) -> Card {
+        self.card
+    }
+}
+
+impl<C> From<Card> for OrderedCard<C> {
+    fn from(card: Card) -> Self {
+        OrderedCard::new(card)
+    }
+}
+
+impl<C> From<OrderedCard<C>> for Card {
+    fn from(ordered: OrderedCard<C>) -> Self {
+        ordered.card
+    }
+}
+
+impl<C> Deref for OrderedCard<C> {
+    type Target = Card;
+
+    fn deref(&self) -> &Card {
+        &self.card
+    }
+}
+
+impl<C> Clone for OrderedCard<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for OrderedCard<C> {}
+
+impl<C> fmt::Debug for OrderedCard<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OrderedCard").field(&self.card).finish()
+    }
+}
+
+impl<C> fmt::Display for OrderedCard<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.card.fmt(f)
+    }
+}
+
+impl<C> PartialEq for OrderedCard<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.card == other.card
+    }
+}
+
+impl<C> Eq for OrderedCard<C> {}
+
+impl<C> Hash for OrderedCard<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.card.hash(state);
+    }
+}
+
+impl<C: CardComparator + Default> PartialOrd for OrderedCard<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: CardComparator + Default> Ord for OrderedCard<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        C::default()
+            .compare(&self.card, &other.card)
+            .then_with(|| u8::from(self.card).cmp(&u8::from(other.card)))
+    }
+}
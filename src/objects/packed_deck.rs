@@ -0,0 +1,505 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::objects::deck::shuffle_prefix;
+use crate::Card;
+use crate::CardComparator;
+use crate::Deck;
+use crate::DeckFactory;
+
+/// A compact deck storing one byte per card, built for high-throughput simulation.
+///
+/// Cards are kept in a contiguous buffer using the crate's `u8` card encoding
+/// (see [`Card`]'s numeric conversions). Dealing moves a cursor instead of
+/// removing cards, so dealing is O(1) and [`PackedDeck::reset`] returns every
+/// dealt card to the deck without allocating. Cloning copies a single byte buffer.
+///
+/// The live cards are those between the top and bottom cursors; equality,
+/// hashing, iteration and serialization only consider the live cards.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{PackedDeck, Standard52};
+///
+/// let mut deck = PackedDeck::from_factory(Standard52);
+/// for _ in 0..1000 {
+///     deck.reset_and_shuffle();
+///     let hole_cards = deck.deal_n(2).unwrap();
+///     assert_eq!(hole_cards.len(), 2);
+///     assert_eq!(deck.len(), 50);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(into = "Vec<u8>", try_from = "Vec<u8>")]
+pub struct PackedDeck {
+    cards: Vec<u8>,
+    top: usize,
+    bottom: usize,
+}
+
+/// Decodes a byte that is known to hold a valid card encoding.
+fn decode(byte: u8) -> Card {
+    Card::try_from(byte).expect("PackedDeck only stores valid card encodings")
+}
+
+impl PackedDeck {
+    /// Creates a packed deck with the given cards, top first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, PackedDeck, Rank, Suit};
+    ///
+    /// let deck = PackedDeck::new(&[Card::new(Suit::Spades, Rank::Ace)]);
+    /// assert_eq!(deck.len(), 1);
+    /// ```
+    pub fn new(cards: &[Card]) -> Self {
+        cards.iter().copied().collect()
+    }
+
+    /// Creates a packed deck using a [`DeckFactory`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{PackedDeck, Standard52};
+    ///
+    /// let deck = PackedDeck::from_factory(Standard52);
+    /// assert_eq!(deck.len(), 52);
+    /// ```
+    pub fn from_factory<F>(factory: F) -> Self
+    where
+        F: DeckFactory,
+    {
+        factory.generate().into_iter().collect()
+    }
+
+    /// Returns the live cards as their `u8` encodings, top first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{PackedDeck, Standard52};
+    ///
+    /// let deck = PackedDeck::from_factory(Standard52);
+    /// assert_eq!(deck.as_bytes()[0], 0);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.cards[self.top..self.bottom]
+    }
+
+    /// Returns an iterator over the live cards, top first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{PackedDeck, Standard52};
+    ///
+    /// let deck = PackedDeck::from_factory(Standard52);
+    /// assert_eq!(deck.iter().filter(|c| c.is_ace()).count(), 4);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Card> + '_ {
+        self.as_bytes().iter().map(|&byte| decode(byte))
+    }
+
+    /// Returns the number of live cards.
+    pub fn len(&self) -> usize {
+        self.bottom - self.top
+    }
+
+    /// Returns `true` if no live cards remain.
+    pub fn is_empty(&self) -> bool {
+        self.top == self.bottom
+    }
+
+    /// Returns the number of cards dealt since the last reset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{PackedDeck, Standard52};
+    ///
+    /// let mut deck = PackedDeck::from_factory(Standard52);
+    /// deck.deal();
+    /// deck.deal_bottom();
+    /// assert_eq!(deck.dealt(), 2);
+    /// ```
+    pub fn dealt(&self) -> usize {
+        self.cards.len() - self.len()
+    }
+
+    /// Returns every dealt card to the deck, restoring the order of the buffer.
+    ///
+    /// Runs in O(1) and does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{PackedDeck, Standard52};
+    ///
+    /// let mut deck = PackedDeck::from_factory(Standard52);
+    /// let top = deck.deal().unwrap();
+    /// deck.reset();
+    /// assert_eq!(deck.len(), 52);
+    /// assert_eq!(deck.peek(), Some(top));
+    /// ```
+    pub fn reset(&mut self) {
+        self.top = 0;
+        self.bottom = self.cards.len();
+    }
+
+    /// Returns every dealt card to the deck and shuffles it, without allocating.
+    pub fn reset_and_shuffle(&mut self) {
+        self.reset();
+        self.shuffle();
+    }
+
+    /// Randomly shuffles the live cards in place using Fisher-Yates.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{PackedDeck, Standard52};
+    /// let mut deck = PackedDeck::from_factory(Standard52);
+    /// deck.shuffle();
+    /// assert_eq!(deck.len(), 52);
+    /// ```
+    pub fn shuffle(&mut self) {
+        self.cards[self.top..self.bottom].shuffle(&mut rand::rng());
+    }
+
+    /// Randomly shuffles the live cards in place using `rng`.
+    pub fn shuffle_with_rng<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        self.cards[self.top..self.bottom].shuffle(rng);
+    }
+
+    /// Randomly shuffles only the top `k` live positions. See [`Deck::partial_shuffle`].
+    pub fn partial_shuffle(&mut self, k: usize) {
+        self.partial_shuffle_with_rng(k, &mut rand::rng());
+    }
+
+    /// Randomly shuffles only the top `k` live positions using `rng`.
+    /// See [`Deck::partial_shuffle_with_rng`].
+    pub fn partial_shuffle_with_rng<R>(&mut self, k: usize, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        shuffle_prefix(&mut self.cards[self.top..self.bottom], k, rng);
+    }
+
+    /// Deals a uniformly random card in O(1), using the thread-local random
+    /// number generator.
+    ///
+    /// Returns `None` if the deck is empty.
+    pub fn draw_random(&mut self) -> Option<Card> {
+        self.draw_random_with_rng(&mut rand::rng())
+    }
+
+    /// Deals a uniformly random card in O(1) using `rng`.
+    ///
+    /// The drawn card swaps places with the top card before being dealt, so
+    /// unlike [`Deck::draw_random`] the order of the remaining cards may
+    /// change. Returns `None` if the deck is empty.
+    pub fn draw_random_with_rng<R>(&mut self, rng: &mut R) -> Option<Card>
+    where
+        R: Rng + ?Sized,
+    {
+        self.partial_shuffle_with_rng(1, rng);
+        self.deal()
+    }
+
+    /// Returns `k` distinct random cards without dealing them, using the
+    /// thread-local random number generator.
+    ///
+    /// Returns `None` if the deck has fewer than `k` cards.
+    pub fn sample(&self, k: usize) -> Option<Vec<Card>> {
+        self.sample_with_rng(k, &mut rand::rng())
+    }
+
+    /// Returns `k` cards from distinct random positions, in random order,
+    /// without dealing them, using `rng`.
+    ///
+    /// Returns `None` if the deck has fewer than `k` cards.
+    pub fn sample_with_rng<R>(&self, k: usize, rng: &mut R) -> Option<Vec<Card>>
+    where
+        R: Rng + ?Sized,
+    {
+        if k > self.len() {
+            return None;
+        }
+        Some(
+            rand::seq::index::sample(rng, self.len(), k)
+                .into_iter()
+                .map(|index| decode(self.cards[self.top + index]))
+                .collect(),
+        )
+    }
+
+    /// Deals `k` random cards, using the thread-local random number generator.
+    ///
+    /// Returns `None`, dealing nothing, if the deck has fewer than `k` cards.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{PackedDeck, Standard52};
+    /// let mut deck = PackedDeck::from_factory(Standard52);
+    /// let hand = deck.take_random(5).unwrap();
+    /// assert_eq!(hand.len(), 5);
+    /// assert_eq!(deck.len(), 47);
+    /// ```
+    pub fn take_random(&mut self, k: usize) -> Option<Vec<Card>> {
+        self.take_random_with_rng(k, &mut rand::rng())
+    }
+
+    /// Deals `k` random cards using `rng`, touching only `k` positions.
+    ///
+    /// As with [`PackedDeck::draw_random_with_rng`], the order of the remaining
+    /// cards may change. Returns `None`, dealing nothing, if the deck has fewer
+    /// than `k` cards.
+    pub fn take_random_with_rng<R>(&mut self, k: usize, rng: &mut R) -> Option<Vec<Card>>
+    where
+        R: Rng + ?Sized,
+    {
+        if k > self.len() {
+            return None;
+        }
+        self.partial_shuffle_with_rng(k, rng);
+        self.deal_n(k)
+    }
+
+    /// Cuts the deck at the given index, moving cards from index to end to the top.
+    ///
+    /// Returns `false` if index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Deck, PackedDeck};
+    ///
+    /// let deck: Deck = "A♠ K♠ Q♠ J♠".parse().unwrap();
+    /// let mut packed = PackedDeck::from(&deck);
+    /// packed.cut(2);
+    /// assert_eq!(packed.to_string(), "Q♠ J♠ A♠ K♠");
+    /// ```
+    pub fn cut(&mut self, index: usize) -> bool {
+        if index >= self.len() {
+            return false;
+        }
+        self.cards[self.top..self.bottom].rotate_left(index);
+        true
+    }
+
+    /// Reverses the order of the live cards.
+    pub fn reverse(&mut self) {
+        self.cards[self.top..self.bottom].reverse();
+    }
+
+    /// Sorts the live cards using a custom comparator.
+    pub fn sort_by_comparator<C: CardComparator + ?Sized>(&mut self, comparator: &C) {
+        self.cards[self.top..self.bottom]
+            .sort_by(|&a, &b| comparator.compare(&decode(a), &decode(b)));
+    }
+
+    /// Deals (removes and returns) the top card in O(1).
+    ///
+    /// Returns `None` if the deck is empty.
+    pub fn deal(&mut self) -> Option<Card> {
+        if self.is_empty() {
+            return None;
+        }
+        self.top += 1;
+        Some(decode(self.cards[self.top - 1]))
+    }
+
+    /// Deals (removes and returns) n cards from the top.
+    ///
+    /// Returns `None`, dealing nothing, if fewer than n cards remain.
+    pub fn deal_n(&mut self, n: usize) -> Option<Vec<Card>> {
+        if n > self.len() {
+            return None;
+        }
+        self.top += n;
+        Some(
+            self.cards[self.top - n..self.top]
+                .iter()
+                .map(|&byte| decode(byte))
+                .collect(),
+        )
+    }
+
+    /// Deals (removes and returns) the bottom card in O(1).
+    ///
+    /// Returns `None` if the deck is empty.
+    pub fn deal_bottom(&mut self) -> Option<Card> {
+        if self.is_empty() {
+            return None;
+        }
+        self.bottom -= 1;
+        Some(decode(self.cards[self.bottom]))
+    }
+
+    /// Adds a card to the top of the deck.
+    ///
+    /// Reuses the slot of the most recently dealt top card when there is one,
+    /// so the card that was in that slot is no longer restored by [`PackedDeck::reset`].
+    pub fn add_card(&mut self, card: Card) {
+        if self.top > 0 {
+            self.top -= 1;
+            self.cards[self.top] = card.into();
+        } else {
+            self.cards.insert(0, card.into());
+            self.bottom += 1;
+        }
+    }
+
+    /// Adds a card to the bottom of the deck.
+    ///
+    /// Reuses the slot of the most recently dealt bottom card when there is one,
+    /// so the card that was in that slot is no longer restored by [`PackedDeck::reset`].
+    pub fn add_card_bottom(&mut self, card: Card) {
+        if self.bottom < self.cards.len() {
+            self.cards[self.bottom] = card.into();
+        } else {
+            self.cards.push(card.into());
+        }
+        self.bottom += 1;
+    }
+
+    /// Returns the top card without removing it.
+    pub fn peek(&self) -> Option<Card> {
+        self.as_bytes().first().map(|&byte| decode(byte))
+    }
+
+    /// Returns the bottom card without removing it.
+    pub fn peek_bottom(&self) -> Option<Card> {
+        self.as_bytes().last().map(|&byte| decode(byte))
+    }
+
+    /// Returns the card at `index` (0 is the top) without removing it.
+    pub fn peek_at(&self, index: usize) -> Option<Card> {
+        self.as_bytes().get(index).map(|&byte| decode(byte))
+    }
+
+    /// Returns `true` if the live cards contain `card`.
+    pub fn contains(&self, card: &Card) -> bool {
+        self.as_bytes().contains(&u8::from(*card))
+    }
+
+    /// Returns the index of the first occurrence of `card`, or `None` if not found.
+    pub fn find(&self, card: &Card) -> Option<usize> {
+        let byte = u8::from(*card);
+        self.as_bytes().iter().position(|&b| b == byte)
+    }
+
+    /// Counts occurrences of `card` among the live cards.
+    pub fn count(&self, card: &Card) -> usize {
+        let byte = u8::from(*card);
+        self.as_bytes().iter().filter(|&&b| b == byte).count()
+    }
+
+    /// Removes every card, including dealt ones.
+    pub fn clear(&mut self) {
+        self.cards.clear();
+        self.top = 0;
+        self.bottom = 0;
+    }
+
+    /// Returns the live cards as a [`Deck`].
+    pub fn to_deck(&self) -> Deck {
+        self.iter().collect()
+    }
+}
+
+impl PartialEq for PackedDeck {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for PackedDeck {}
+
+impl Hash for PackedDeck {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl fmt::Display for PackedDeck {
+    /// Formats the live cards like a [`Deck`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards: Vec<String> = self.iter().map(|card| card.to_string()).collect();
+        write!(f, "{}", cards.join(" "))
+    }
+}
+
+impl FromIterator<Card> for PackedDeck {
+    /// Creates a PackedDeck from an iterator of Cards.
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let cards: Vec<u8> = iter.into_iter().map(u8::from).collect();
+        let bottom = cards.len();
+        PackedDeck {
+            cards,
+            top: 0,
+            bottom,
+        }
+    }
+}
+
+impl From<&Deck> for PackedDeck {
+    /// Packs the cards of a [`Deck`].
+    fn from(deck: &Deck) -> Self {
+        deck.iter().copied().collect()
+    }
+}
+
+impl From<Deck> for PackedDeck {
+    /// Packs the cards of a [`Deck`].
+    fn from(deck: Deck) -> Self {
+        deck.into_iter().collect()
+    }
+}
+
+impl From<PackedDeck> for Deck {
+    /// Unpacks the live cards into a [`Deck`].
+    fn from(deck: PackedDeck) -> Self {
+        deck.to_deck()
+    }
+}
+
+impl From<PackedDeck> for Vec<u8> {
+    /// Returns the live cards as their `u8` encodings.
+    fn from(deck: PackedDeck) -> Self {
+        deck.as_bytes().to_vec()
+    }
+}
+
+impl TryFrom<Vec<u8>> for PackedDeck {
+    type Error = String;
+
+    /// Creates a PackedDeck from `u8` card encodings, validating each byte.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::PackedDeck;
+    /// assert_eq!(PackedDeck::try_from(vec![0, 1, 2]).unwrap().len(), 3);
+    /// assert!(PackedDeck::try_from(vec![56]).is_err());
+    /// ```
+    fn try_from(cards: Vec<u8>) -> Result<Self, Self::Error> {
+        if let Some(value) = cards.iter().find(|&&byte| Card::try_from(byte).is_err()) {
+            return Err(format!("Invalid card value: {}", value));
+        }
+        let bottom = cards.len();
+        Ok(PackedDeck {
+            cards,
+            top: 0,
+            bottom,
+        })
+    }
+}
@@ -0,0 +1,362 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::{Card, CardComparator, StandardComparator, Suit};
+
+/// The cards played to one trick, in play order, with the seat that played
+/// each.
+///
+/// The first card played sets the led suit. A trick is won by the highest
+/// trump played, or by the highest card of the led suit if no trump was
+/// played. Cards of any other suit cannot win. Ties (possible with several
+/// decks) go to the card played first.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, Rank, Suit, Trick};
+///
+/// let mut trick = Trick::new().with_trump(Suit::Spades);
+/// trick.play(0, Card::new(Suit::Hearts, Rank::King)).unwrap();
+/// trick.play(1, Card::new(Suit::Hearts, Rank::Ace)).unwrap();
+/// trick.play(2, Card::new(Suit::Clubs, Rank::Ace)).unwrap();
+/// assert_eq!(trick.led_suit(), Some(Suit::Hearts));
+/// assert_eq!(trick.winner(), Some(1));
+///
+/// trick.play(3, Card::new(Suit::Spades, Rank::Two)).unwrap();
+/// assert_eq!(trick.winner(), Some(3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Trick {
+    plays: Vec<(usize, Card)>,
+    trump: Option<Suit>,
+}
+
+impl Trick {
+    /// Creates an empty trick with no trump suit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the trump suit.
+    pub fn with_trump(mut self, trump: Suit) -> Self {
+        self.trump = Some(trump);
+        self
+    }
+
+    /// Returns the trump suit, if any.
+    pub fn trump(&self) -> Option<Suit> {
+        self.trump
+    }
+
+    /// Plays `card` from `seat`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `seat` has already played to this trick.
+    pub fn play(&mut self, seat: usize, card: Card) -> Result<(), String> {
+        if self.plays.iter().any(|&(played, _)| played == seat) {
+            return Err(format!("Seat {} has already played to this trick", seat));
+        }
+        self.plays.push((seat, card));
+        Ok(())
+    }
+
+    /// Returns the plays as `(seat, card)` pairs, in play order.
+    pub fn plays(&self) -> &[(usize, Card)] {
+        &self.plays
+    }
+
+    /// Returns the cards played, in play order.
+    pub fn cards(&self) -> Vec<Card> {
+        self.plays.iter().map(|&(_, card)| card).collect()
+    }
+
+    /// Returns the card played by `seat`, if it has played.
+    pub fn card_of(&self, seat: usize) -> Option<Card> {
+        self.plays
+            .iter()
+            .find(|&&(played, _)| played == seat)
+            .map(|&(_, card)| card)
+    }
+
+    /// Returns the number of cards played.
+    pub fn len(&self) -> usize {
+        self.plays.len()
+    }
+
+    /// Returns true if no card has been played.
+    pub fn is_empty(&self) -> bool {
+        self.plays.is_empty()
+    }
+
+    /// Returns the seat that led, if any card has been played.
+    pub fn leader(&self) -> Option<usize> {
+        self.plays.first().map(|&(seat, _)| seat)
+    }
+
+    /// Returns the suit of the first card played.
+    pub fn led_suit(&self) -> Option<Suit> {
+        self.plays.first().map(|(_, card)| card.suit())
+    }
+
+    /// Returns the effective suit of the first card played under
+    /// `comparator`, such as trump when a Euchre left bower is led.
+    pub fn led_suit_by<C: CardComparator + ?Sized>(&self, comparator: &C) -> Option<Suit> {
+        self.plays
+            .first()
+            .map(|(_, card)| comparator.effective_suit(card))
+    }
+
+    /// Returns the seat currently winning the trick, ranking cards Ace high.
+    pub fn winner(&self) -> Option<usize> {
+        self.winner_by(&StandardComparator)
+    }
+
+    /// Returns the seat currently winning the trick, ranking cards within a
+    /// suit with `comparator`. A card is a trump if it is in the trick's
+    /// trump suit by the comparator's
+    /// [`effective_suit`](CardComparator::effective_suit), or if the
+    /// comparator's [`is_trump`](CardComparator::is_trump) says so.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{AceLowComparator, Card, Rank, Suit, Trick};
+    ///
+    /// let mut trick = Trick::new();
+    /// trick.play(2, Card::new(Suit::Clubs, Rank::Ace)).unwrap();
+    /// trick.play(3, Card::new(Suit::Clubs, Rank::Five)).unwrap();
+    /// assert_eq!(trick.winner(), Some(2));
+    /// assert_eq!(trick.winner_by(&AceLowComparator), Some(3));
+    /// ```
+    pub fn winner_by<C: CardComparator + ?Sized>(&self, comparator: &C) -> Option<usize> {
+        self.winning_play(comparator).map(|(seat, _)| seat)
+    }
+
+    /// Returns the card currently winning the trick, ranking cards Ace high.
+    pub fn winning_card(&self) -> Option<Card> {
+        self.winning_card_by(&StandardComparator)
+    }
+
+    /// Returns the card currently winning the trick under `comparator`, as
+    /// decided by [`winner_by`](Self::winner_by).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, EuchreComparator, Rank, Suit, Trick};
+    ///
+    /// let left_bower = Card::new(Suit::Diamonds, Rank::Jack);
+    /// let mut trick = Trick::new().with_trump(Suit::Hearts);
+    /// trick.play(0, Card::new(Suit::Hearts, Rank::Ace)).unwrap();
+    /// trick.play(1, left_bower).unwrap();
+    /// assert_eq!(
+    ///     trick.winning_card_by(&EuchreComparator::new(Suit::Hearts)),
+    ///     Some(left_bower)
+    /// );
+    /// ```
+    pub fn winning_card_by<C: CardComparator + ?Sized>(&self, comparator: &C) -> Option<Card> {
+        self.winning_play(comparator).map(|(_, card)| card)
+    }
+
+    /// Returns the group `card` follows under `comparator`: the trumps, or
+    /// its effective suit.
+    fn group_of<C: CardComparator + ?Sized>(&self, card: &Card, comparator: &C) -> SuitGroup {
+        let suit = comparator.effective_suit(card);
+        if comparator.is_trump(card) || self.trump == Some(suit) {
+            SuitGroup::Trump
+        } else {
+            SuitGroup::Plain(suit)
+        }
+    }
+
+    fn winning_play<C: CardComparator + ?Sized>(&self, comparator: &C) -> Option<(usize, Card)> {
+        let (_, lead) = self.plays.first()?;
+        let trumped = self
+            .plays
+            .iter()
+            .any(|(_, card)| self.group_of(card, comparator) == SuitGroup::Trump);
+        let winning_group = if trumped {
+            SuitGroup::Trump
+        } else {
+            self.group_of(lead, comparator)
+        };
+        let mut best: Option<(usize, Card)> = None;
+        for &(seat, card) in self
+            .plays
+            .iter()
+            .filter(|(_, c)| self.group_of(c, comparator) == winning_group)
+        {
+            let beats = best.is_none_or(|(_, current)| {
+                comparator.compare(&card, &current) == Ordering::Greater
+            });
+            if beats {
+                best = Some((seat, card));
+            }
+        }
+        best
+    }
+}
+
+/// The cards that follow one another: all trumps together, or a plain suit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SuitGroup {
+    Trump,
+    Plain(Suit),
+}
+
+impl fmt::Display for Trick {
+    /// Formats the trick as `seat:card` pairs in play order, e.g. `0:K♥ 1:A♥`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (seat, card)) in self.plays.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}:{}", seat, card)?;
+        }
+        Ok(())
+    }
+}
+
+/// The rules deciding which cards a player may play to a trick.
+///
+/// By default players must follow the led suit when they can, and may
+/// otherwise play anything. With [`with_must_trump`](Self::with_must_trump),
+/// a player who cannot follow suit must play a trump if they hold one.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, Rank, Suit, Trick, TrickRules};
+///
+/// let hand = vec![
+///     Card::new(Suit::Clubs, Rank::Two),
+///     Card::new(Suit::Spades, Rank::Three),
+/// ];
+/// let mut trick = Trick::new().with_trump(Suit::Spades);
+/// trick.play(0, Card::new(Suit::Hearts, Rank::Ace)).unwrap();
+///
+/// assert_eq!(TrickRules::new().legal_plays(&hand, &trick), hand);
+/// assert_eq!(
+///     TrickRules::new().with_must_trump(true).legal_plays(&hand, &trick),
+///     vec![Card::new(Suit::Spades, Rank::Three)]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrickRules {
+    must_follow: bool,
+    must_trump: bool,
+}
+
+impl TrickRules {
+    /// Creates rules where players must follow suit but need not trump.
+    pub fn new() -> Self {
+        Self {
+            must_follow: true,
+            must_trump: false,
+        }
+    }
+
+    /// Sets whether players must follow the led suit when they can.
+    pub fn with_must_follow(mut self, must_follow: bool) -> Self {
+        self.must_follow = must_follow;
+        self
+    }
+
+    /// Sets whether players who cannot follow suit must play a trump.
+    pub fn with_must_trump(mut self, must_trump: bool) -> Self {
+        self.must_trump = must_trump;
+        self
+    }
+
+    /// Returns true if players must follow the led suit when they can.
+    pub fn must_follow(&self) -> bool {
+        self.must_follow
+    }
+
+    /// Returns true if players who cannot follow suit must play a trump.
+    pub fn must_trump(&self) -> bool {
+        self.must_trump
+    }
+
+    /// Returns the cards in `hand` that may be played to `trick`, in hand
+    /// order. Any card may be led.
+    pub fn legal_plays(&self, hand: &[Card], trick: &Trick) -> Vec<Card> {
+        self.legal_plays_by(hand, trick, &StandardComparator)
+    }
+
+    /// Returns the cards in `hand` that may be played to `trick`, judging
+    /// suits with `comparator`'s
+    /// [`effective_suit`](CardComparator::effective_suit) and
+    /// [`is_trump`](CardComparator::is_trump). When trumps are led, following
+    /// suit means playing a trump.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, EuchreComparator, Rank, Suit, Trick, TrickRules};
+    ///
+    /// // With hearts trump, the jack of diamonds is a heart.
+    /// let left_bower = Card::new(Suit::Diamonds, Rank::Jack);
+    /// let hand = vec![left_bower, Card::new(Suit::Diamonds, Rank::Ace)];
+    /// let mut trick = Trick::new().with_trump(Suit::Hearts);
+    /// trick.play(0, Card::new(Suit::Hearts, Rank::Nine)).unwrap();
+    ///
+    /// let euchre = EuchreComparator::new(Suit::Hearts);
+    /// assert_eq!(TrickRules::new().legal_plays_by(&hand, &trick, &euchre), vec![left_bower]);
+    /// ```
+    pub fn legal_plays_by<C: CardComparator + ?Sized>(
+        &self,
+        hand: &[Card],
+        trick: &Trick,
+        comparator: &C,
+    ) -> Vec<Card> {
+        let Some((_, lead)) = trick.plays().first() else {
+            return hand.to_vec();
+        };
+        let of_group = |group: SuitGroup| -> Vec<Card> {
+            hand.iter()
+                .copied()
+                .filter(|c| trick.group_of(c, comparator) == group)
+                .collect()
+        };
+        if self.must_follow {
+            let following = of_group(trick.group_of(lead, comparator));
+            if !following.is_empty() {
+                return following;
+            }
+        }
+        if self.must_trump {
+            let trumps = of_group(SuitGroup::Trump);
+            if !trumps.is_empty() {
+                return trumps;
+            }
+        }
+        hand.to_vec()
+    }
+
+    /// Returns true if `card` from `hand` may be played to `trick`.
+    pub fn is_legal(&self, card: &Card, hand: &[Card], trick: &Trick) -> bool {
+        self.is_legal_by(card, hand, trick, &StandardComparator)
+    }
+
+    /// Returns true if `card` from `hand` may be played to `trick`, judging
+    /// suits with `comparator`.
+    pub fn is_legal_by<C: CardComparator + ?Sized>(
+        &self,
+        card: &Card,
+        hand: &[Card],
+        trick: &Trick,
+        comparator: &C,
+    ) -> bool {
+        hand.contains(card) && self.legal_plays_by(hand, trick, comparator).contains(card)
+    }
+}
+
+impl Default for TrickRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,362 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt;
+
+use crate::{Card, Deck};
+
+/// Who is allowed to see the face of a card.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Visibility, Viewer};
+///
+/// let hole_card = Visibility::known_to([2]);
+/// assert!(hole_card.is_visible_to(Viewer::Seat(2)));
+/// assert!(!hole_card.is_visible_to(Viewer::Seat(0)));
+/// assert!(!hole_card.is_visible_to(Viewer::Spectator));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Visibility {
+    /// Everybody sees the face of the card.
+    FaceUp,
+    /// Nobody sees the face of the card.
+    FaceDown,
+    /// Only the listed seats see the face of the card.
+    KnownTo(BTreeSet<usize>),
+}
+
+impl Visibility {
+    /// Creates a [`Visibility::KnownTo`] from a list of seats.
+    pub fn known_to<I: IntoIterator<Item = usize>>(seats: I) -> Self {
+        Visibility::KnownTo(seats.into_iter().collect())
+    }
+
+    /// Returns `true` if `viewer` may see the face of the card.
+    ///
+    /// [`Viewer::Omniscient`] sees every card; [`Viewer::Spectator`] only sees
+    /// face-up cards.
+    pub fn is_visible_to(&self, viewer: Viewer) -> bool {
+        match (self, viewer) {
+            (_, Viewer::Omniscient) => true,
+            (Visibility::FaceUp, _) => true,
+            (Visibility::FaceDown, _) => false,
+            (Visibility::KnownTo(seats), Viewer::Seat(seat)) => seats.contains(&seat),
+            (Visibility::KnownTo(_), Viewer::Spectator) => false,
+        }
+    }
+}
+
+/// The party a redacted view is produced for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Viewer {
+    /// A player sitting at the given seat.
+    Seat(usize),
+    /// Someone who only sees face-up cards.
+    Spectator,
+    /// Sees every card (e.g. the authoritative server or a replay).
+    Omniscient,
+}
+
+/// A card as seen by a particular viewer: either its face or its back.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardView, Rank, Suit};
+///
+/// let face = CardView::Face(Card::new(Suit::Hearts, Rank::Ace));
+/// assert_eq!(face.to_string(), "A♥");
+/// assert_eq!(CardView::Back.to_string(), "??");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CardView {
+    /// The face of the card is visible.
+    Face(Card),
+    /// Only the back of the card is visible.
+    Back,
+}
+
+impl CardView {
+    /// Returns the card if its face is visible.
+    pub fn card(&self) -> Option<Card> {
+        match self {
+            CardView::Face(card) => Some(*card),
+            CardView::Back => None,
+        }
+    }
+
+    /// Returns `true` if only the back of the card is visible.
+    pub fn is_back(&self) -> bool {
+        matches!(self, CardView::Back)
+    }
+}
+
+impl fmt::Display for CardView {
+    /// Formats a visible card with its `Display` implementation and a hidden one as "??".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CardView::Face(card) => write!(f, "{}", card),
+            CardView::Back => write!(f, "??"),
+        }
+    }
+}
+
+/// A card together with who may see it.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardView, Rank, Suit, TrackedCard, Viewer, Visibility};
+///
+/// let card = Card::new(Suit::Spades, Rank::King);
+/// let tracked = TrackedCard::new(card, Visibility::known_to([0]));
+/// assert_eq!(tracked.view_for(Viewer::Seat(0)), CardView::Face(card));
+/// assert_eq!(tracked.view_for(Viewer::Seat(1)), CardView::Back);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TrackedCard {
+    card: Card,
+    visibility: Visibility,
+}
+
+impl TrackedCard {
+    /// Creates a tracked card with the given visibility.
+    pub fn new(card: Card, visibility: Visibility) -> Self {
+        TrackedCard { card, visibility }
+    }
+
+    /// Returns the underlying card, regardless of visibility.
+    pub fn card(&self) -> Card {
+        self.card
+    }
+
+    /// Returns the visibility of the card.
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    /// Replaces the visibility of the card.
+    pub fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    /// Lets `seat` see the card in addition to anyone who already can.
+    ///
+    /// Has no effect on face-up cards.
+    pub fn reveal_to(&mut self, seat: usize) {
+        match &mut self.visibility {
+            Visibility::FaceUp => {}
+            Visibility::FaceDown => self.visibility = Visibility::known_to([seat]),
+            Visibility::KnownTo(seats) => {
+                seats.insert(seat);
+            }
+        }
+    }
+
+    /// Returns the card as seen by `viewer`.
+    pub fn view_for(&self, viewer: Viewer) -> CardView {
+        if self.visibility.is_visible_to(viewer) {
+            CardView::Face(self.card)
+        } else {
+            CardView::Back
+        }
+    }
+}
+
+/// An ordered pile of cards that tracks per-card [`Visibility`].
+///
+/// Keeps the authoritative cards and produces a [`RedactedDeck`] for any
+/// [`Viewer`], so game state can be sent to players without leaking hidden cards.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, TrackedDeck, Viewer, Visibility};
+///
+/// let hand: Deck = "A♠ K♠".parse().unwrap();
+/// let mut tracked = TrackedDeck::from_deck(hand, Visibility::known_to([1]));
+/// tracked.reveal(0);
+///
+/// assert_eq!(tracked.view_for(Viewer::Seat(1)).to_string(), "A♠ K♠");
+/// assert_eq!(tracked.view_for(Viewer::Seat(0)).to_string(), "A♠ ??");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct TrackedDeck {
+    cards: VecDeque<TrackedCard>,
+}
+
+impl TrackedDeck {
+    /// Creates a tracked deck from tracked cards.
+    pub fn new(cards: VecDeque<TrackedCard>) -> Self {
+        TrackedDeck { cards }
+    }
+
+    /// Creates a tracked deck where every card of `deck` has the same visibility.
+    pub fn from_deck(deck: Deck, visibility: Visibility) -> Self {
+        deck.into_iter()
+            .map(|card| TrackedCard::new(card, visibility.clone()))
+            .collect()
+    }
+
+    /// Returns the number of cards.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns `true` if there are no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns an iterator over the tracked cards.
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, TrackedCard> {
+        self.cards.iter()
+    }
+
+    /// Returns the tracked card at `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&TrackedCard> {
+        self.cards.get(index)
+    }
+
+    /// Adds a card to the top.
+    pub fn add_card(&mut self, card: Card, visibility: Visibility) {
+        self.cards.push_front(TrackedCard::new(card, visibility));
+    }
+
+    /// Adds a card to the bottom.
+    pub fn add_card_bottom(&mut self, card: Card, visibility: Visibility) {
+        self.cards.push_back(TrackedCard::new(card, visibility));
+    }
+
+    /// Removes and returns the top card.
+    pub fn deal(&mut self) -> Option<TrackedCard> {
+        self.cards.pop_front()
+    }
+
+    /// Removes and returns the card at `index`.
+    pub fn remove_at(&mut self, index: usize) -> Option<TrackedCard> {
+        self.cards.remove(index)
+    }
+
+    /// Sets the visibility of the card at `index`.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn set_visibility(&mut self, index: usize, visibility: Visibility) -> bool {
+        match self.cards.get_mut(index) {
+            Some(tracked) => {
+                tracked.set_visibility(visibility);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Turns the card at `index` face up.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn reveal(&mut self, index: usize) -> bool {
+        self.set_visibility(index, Visibility::FaceUp)
+    }
+
+    /// Lets `seat` see the card at `index`.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn reveal_to(&mut self, index: usize, seat: usize) -> bool {
+        match self.cards.get_mut(index) {
+            Some(tracked) => {
+                tracked.reveal_to(seat);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Turns every card face up.
+    pub fn reveal_all(&mut self) {
+        for tracked in &mut self.cards {
+            tracked.set_visibility(Visibility::FaceUp);
+        }
+    }
+
+    /// Returns the authoritative cards, ignoring visibility.
+    pub fn to_deck(&self) -> Deck {
+        self.cards.iter().map(TrackedCard::card).collect()
+    }
+
+    /// Returns the cards as seen by `viewer`, with hidden cards replaced by backs.
+    pub fn view_for(&self, viewer: Viewer) -> RedactedDeck {
+        RedactedDeck {
+            cards: self
+                .cards
+                .iter()
+                .map(|tracked| tracked.view_for(viewer))
+                .collect(),
+        }
+    }
+}
+
+impl FromIterator<TrackedCard> for TrackedDeck {
+    fn from_iter<I: IntoIterator<Item = TrackedCard>>(iter: I) -> Self {
+        TrackedDeck::new(iter.into_iter().collect())
+    }
+}
+
+/// A viewer-specific view of a [`TrackedDeck`], safe to send to that viewer.
+///
+/// Serializes like a [`Deck`], with each entry either `{"Face": <card>}` or `"Back"`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, TrackedDeck, Viewer, Visibility};
+///
+/// let deck: Deck = "A♠ K♠".parse().unwrap();
+/// let tracked = TrackedDeck::from_deck(deck, Visibility::FaceDown);
+/// let json = tracked.view_for(Viewer::Spectator).to_json().unwrap();
+/// assert_eq!(json, r#"{"cards":["Back","Back"]}"#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct RedactedDeck {
+    cards: Vec<CardView>,
+}
+
+impl RedactedDeck {
+    /// Returns the card views, top first.
+    pub fn cards(&self) -> &[CardView] {
+        &self.cards
+    }
+
+    /// Returns the number of cards, visible or not.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns `true` if there are no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Returns the number of cards whose face is hidden.
+    pub fn hidden_count(&self) -> usize {
+        self.cards.iter().filter(|view| view.is_back()).count()
+    }
+
+    /// Serializes the view to a JSON string.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Creates a view from a JSON string.
+    pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+}
+
+impl fmt::Display for RedactedDeck {
+    /// Formats the view like a [`Deck`], with hidden cards shown as "??".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let views: Vec<String> = self.cards.iter().map(|view| view.to_string()).collect();
+        write!(f, "{}", views.join(" "))
+    }
+}
@@ -0,0 +1,423 @@
+use crate::{Card, CardComparator, Rank, Suit};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A comparator that reverses another comparator's order.
+///
+/// Created by [`CardComparator::reversed`]. Rank and suit values are the
+/// inner comparator's negated, saturating so that `i32::MIN` becomes
+/// `i32::MAX`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardComparator, Rank, StandardComparator, Suit};
+///
+/// let high_first = StandardComparator.reversed();
+/// let ace = Card::new(Suit::Spades, Rank::Ace);
+/// let two = Card::new(Suit::Spades, Rank::Two);
+/// assert!(high_first.is_less(&ace, &two));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Reversed<C> {
+    inner: C,
+}
+
+impl<C: CardComparator> Reversed<C> {
+    /// Reverses `inner`.
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the comparator being reversed.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: CardComparator> CardComparator for Reversed<C> {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        self.inner.rank_value(rank).saturating_neg()
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        self.inner.suit_value(suit).saturating_neg()
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        self.inner.effective_suit(card)
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.inner.is_trump(card)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.inner.compare(b, a)
+    }
+}
+
+/// A comparator that breaks ties in one comparator with a second.
+///
+/// Created by [`CardComparator::then_by`]. Rank and suit values come from the
+/// first comparator.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{BridgeComparator, Card, CardComparator, Rank, StandardComparator, Suit};
+///
+/// let cmp = StandardComparator.then_by(BridgeComparator);
+/// let ace_spades = Card::new(Suit::Spades, Rank::Ace);
+/// let ace_clubs = Card::new(Suit::Clubs, Rank::Ace);
+/// assert!(cmp.is_greater(&ace_spades, &ace_clubs));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThenBy<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: CardComparator, B: CardComparator> ThenBy<A, B> {
+    /// Compares with `first`, falling back to `second` on ties.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Returns the primary comparator.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns the tie-breaking comparator.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A: CardComparator, B: CardComparator> CardComparator for ThenBy<A, B> {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        self.first.rank_value(rank)
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        self.first.suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        self.first.effective_suit(card)
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.first.is_trump(card)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.first
+            .compare(a, b)
+            .then_with(|| self.second.compare(a, b))
+    }
+}
+
+/// A comparator that orders cards by suit first, then by another comparator
+/// within each suit.
+///
+/// Suits are ordered by their position in the suit order, which defaults to
+/// [`Suit::ALL`] (♥ ♦ ♣ ♠). Cards are grouped by the inner comparator's
+/// [`effective_suit`](CardComparator::effective_suit). Created by
+/// [`CardComparator::suit_first`].
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{CardComparator, Deck, StandardComparator, Suit, SuitFirst};
+///
+/// let mut deck: Deck = "A♠ 2♥ K♥ 3♠".parse().unwrap();
+/// deck.sort_by_comparator(&StandardComparator.suit_first());
+/// assert_eq!(deck.to_string(), "2♥ K♥ 3♠ A♠");
+///
+/// let order = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+/// deck.sort_by_comparator(&SuitFirst::new(StandardComparator).with_suit_order(order));
+/// assert_eq!(deck.to_string(), "3♠ A♠ 2♥ K♥");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SuitFirst<C> {
+    inner: C,
+    order: [Suit; 4],
+}
+
+impl<C: CardComparator> SuitFirst<C> {
+    /// Groups by suit in [`Suit::ALL`] order, ordering each suit with `inner`.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            order: Suit::ALL,
+        }
+    }
+
+    /// Sets the order of the suit groups, lowest first.
+    pub fn with_suit_order(mut self, order: [Suit; 4]) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Returns the order of the suit groups, lowest first.
+    pub fn suit_order(&self) -> [Suit; 4] {
+        self.order
+    }
+
+    /// Returns the comparator used within each suit.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: CardComparator> CardComparator for SuitFirst<C> {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        self.inner.rank_value(rank)
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        self.order
+            .iter()
+            .position(|&s| s == suit)
+            .map_or(0, |index| index as i32)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        self.inner.effective_suit(card)
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.inner.is_trump(card)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.suit_value(self.effective_suit(a))
+            .cmp(&self.suit_value(self.effective_suit(b)))
+            .then_with(|| self.inner.compare(a, b))
+    }
+}
+
+/// A comparator that puts red cards before black cards, ordering each color
+/// with another comparator.
+///
+/// Created by [`CardComparator::color_grouped`].
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{CardComparator, Deck, StandardComparator};
+///
+/// let mut deck: Deck = "A♠ 2♥ K♦ 3♣".parse().unwrap();
+/// deck.sort_by_comparator(&StandardComparator.color_grouped());
+/// assert_eq!(deck.to_string(), "2♥ K♦ 3♣ A♠");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ColorGrouped<C> {
+    inner: C,
+}
+
+impl<C: CardComparator> ColorGrouped<C> {
+    /// Groups by color, ordering each color with `inner`.
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the comparator used within each color.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: CardComparator> CardComparator for ColorGrouped<C> {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        self.inner.rank_value(rank)
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        self.inner.suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        self.inner.effective_suit(card)
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.inner.is_trump(card)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        a.color()
+            .cmp(&b.color())
+            .then_with(|| self.inner.compare(a, b))
+    }
+}
+
+/// A comparator that ranks jokers above every other card.
+///
+/// Jokers are ordered among themselves by the wrapped comparator. Created by
+/// [`CardComparator::jokers_high`].
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{AceLowComparator, Card, CardComparator, Rank, Suit};
+///
+/// let cmp = AceLowComparator.reversed().jokers_high();
+/// let joker = Card::new(Suit::Hearts, Rank::Joker);
+/// let ace = Card::new(Suit::Spades, Rank::Ace);
+/// assert!(cmp.is_greater(&joker, &ace));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JokersHigh<C> {
+    inner: C,
+}
+
+impl<C: CardComparator> JokersHigh<C> {
+    /// Wraps `inner`, moving jokers to the top.
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped comparator.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: CardComparator> CardComparator for JokersHigh<C> {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        match rank {
+            Rank::Joker => i32::MAX,
+            _ => self.inner.rank_value(rank),
+        }
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        self.inner.suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        self.inner.effective_suit(card)
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.inner.is_trump(card)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        a.is_joker()
+            .cmp(&b.is_joker())
+            .then_with(|| self.inner.compare(a, b))
+    }
+}
+
+/// A comparator that ranks jokers below every other card.
+///
+/// Jokers are ordered among themselves by the wrapped comparator. Created by
+/// [`CardComparator::jokers_low`].
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardComparator, Rank, StandardComparator, Suit};
+///
+/// let cmp = StandardComparator.jokers_low();
+/// let joker = Card::new(Suit::Hearts, Rank::Joker);
+/// let two = Card::new(Suit::Spades, Rank::Two);
+/// assert!(cmp.is_less(&joker, &two));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JokersLow<C> {
+    inner: C,
+}
+
+impl<C: CardComparator> JokersLow<C> {
+    /// Wraps `inner`, moving jokers to the bottom.
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped comparator.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: CardComparator> CardComparator for JokersLow<C> {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        match rank {
+            Rank::Joker => i32::MIN,
+            _ => self.inner.rank_value(rank),
+        }
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        self.inner.suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        self.inner.effective_suit(card)
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.inner.is_trump(card)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        b.is_joker()
+            .cmp(&a.is_joker())
+            .then_with(|| self.inner.compare(a, b))
+    }
+}
+
+/// A comparator backed by a closure.
+///
+/// Rank values are the natural [`Rank::value`]s; only
+/// [`compare`](CardComparator::compare) uses the closure.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{CardComparator, Deck, FnComparator};
+///
+/// // Order by the card's byte encoding.
+/// let cmp = FnComparator::new(|a, b| u8::from(*a).cmp(&u8::from(*b)));
+/// let mut deck: Deck = "2♠ A♥ 2♥".parse().unwrap();
+/// deck.sort_by_comparator(&cmp.reversed());
+/// assert_eq!(deck.to_string(), "2♠ A♥ 2♥");
+/// ```
+#[derive(Clone, Copy)]
+pub struct FnComparator<F> {
+    compare: F,
+}
+
+impl<F> FnComparator<F>
+where
+    F: Fn(&Card, &Card) -> Ordering,
+{
+    /// Creates a comparator that orders cards with `compare`.
+    pub fn new(compare: F) -> Self {
+        Self { compare }
+    }
+}
+
+impl<F> CardComparator for FnComparator<F>
+where
+    F: Fn(&Card, &Card) -> Ordering,
+{
+    fn rank_value(&self, rank: Rank) -> i32 {
+        rank.value() as i32
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        (self.compare)(a, b)
+    }
+}
+
+impl<F> fmt::Debug for FnComparator<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnComparator").finish_non_exhaustive()
+    }
+}
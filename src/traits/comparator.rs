@@ -1,4 +1,5 @@
 use crate::{Card, Rank, Suit};
+use crate::{ColorGrouped, JokersHigh, JokersLow, Reversed, SuitFirst, ThenBy};
 use std::cmp::Ordering;
 
 /// Trait for defining custom card ordering rules.
@@ -10,6 +11,8 @@ use std::cmp::Ordering;
 /// - Bridge: Complex trump rules
 ///
 /// Implement this trait to define custom ordering for your game.
+/// Comparators compose with adapters such as [`reversed`](Self::reversed),
+/// [`then_by`](Self::then_by) and [`suit_first`](Self::suit_first).
 ///
 /// # Example
 /// ```
@@ -39,6 +42,21 @@ pub trait CardComparator {
         0
     }
 
+    /// Returns the suit `card` belongs to under this comparator's rules, for
+    /// following suit and finding trumps. In Euchre, for example, the left
+    /// bower counts as a trump.
+    /// Default implementation returns the card's printed suit.
+    fn effective_suit(&self, card: &Card) -> Suit {
+        card.suit()
+    }
+
+    /// Returns true if `card` is a trump under this comparator's rules, such
+    /// as any jack in Skat. Trumps form their own suit when following suit.
+    /// Default implementation returns false.
+    fn is_trump(&self, _card: &Card) -> bool {
+        false
+    }
+
     /// Compares two cards according to this comparator's rules.
     /// Default implementation compares by rank first, then by suit.
     fn compare(&self, a: &Card, b: &Card) -> Ordering {
@@ -77,6 +95,150 @@ pub trait CardComparator {
             b
         }
     }
+
+    /// Returns the highest card in `cards`, or the first of equally high
+    /// cards, or `None` if `cards` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{AceLowComparator, CardComparator, Deck};
+    ///
+    /// let hand: Deck = "A♠ K♥ 2♣".parse().unwrap();
+    /// assert_eq!(AceLowComparator.max_of(hand.iter()).unwrap().to_string(), "K♥");
+    /// assert_eq!(AceLowComparator.min_of(hand.iter()).unwrap().to_string(), "A♠");
+    /// ```
+    fn max_of<'a, I>(&self, cards: I) -> Option<&'a Card>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = &'a Card>,
+    {
+        cards.into_iter().reduce(|best, card| self.max(best, card))
+    }
+
+    /// Returns the lowest card in `cards`, or the first of equally low
+    /// cards, or `None` if `cards` is empty.
+    fn min_of<'a, I>(&self, cards: I) -> Option<&'a Card>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = &'a Card>,
+    {
+        cards.into_iter().reduce(|best, card| self.min(best, card))
+    }
+
+    /// Collects `cards` into a vector sorted lowest first. The sort is
+    /// stable, so equal cards keep their order.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{CardComparator, Deck, StandardComparator};
+    ///
+    /// let deck: Deck = "A♠ 2♥ K♦".parse().unwrap();
+    /// let sorted = StandardComparator.reversed().sorted(deck);
+    /// assert_eq!(sorted[0].to_string(), "A♠");
+    /// ```
+    fn sorted<I>(&self, cards: I) -> Vec<Card>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Card>,
+    {
+        let mut cards: Vec<Card> = cards.into_iter().collect();
+        cards.sort_by(|a, b| self.compare(a, b));
+        cards
+    }
+
+    /// Returns a comparator with the opposite order.
+    fn reversed(self) -> Reversed<Self>
+    where
+        Self: Sized,
+    {
+        Reversed::new(self)
+    }
+
+    /// Returns a comparator that breaks this comparator's ties with `other`.
+    fn then_by<C: CardComparator>(self, other: C) -> ThenBy<Self, C>
+    where
+        Self: Sized,
+    {
+        ThenBy::new(self, other)
+    }
+
+    /// Returns a comparator that groups cards by suit, ordering each suit
+    /// with this comparator.
+    fn suit_first(self) -> SuitFirst<Self>
+    where
+        Self: Sized,
+    {
+        SuitFirst::new(self)
+    }
+
+    /// Returns a comparator that puts red cards before black cards, ordering
+    /// each color with this comparator.
+    fn color_grouped(self) -> ColorGrouped<Self>
+    where
+        Self: Sized,
+    {
+        ColorGrouped::new(self)
+    }
+
+    /// Returns a comparator that ranks jokers above every other card.
+    fn jokers_high(self) -> JokersHigh<Self>
+    where
+        Self: Sized,
+    {
+        JokersHigh::new(self)
+    }
+
+    /// Returns a comparator that ranks jokers below every other card.
+    fn jokers_low(self) -> JokersLow<Self>
+    where
+        Self: Sized,
+    {
+        JokersLow::new(self)
+    }
+}
+
+impl<C: CardComparator + ?Sized> CardComparator for &C {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        (**self).rank_value(rank)
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        (**self).suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        (**self).effective_suit(card)
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        (**self).is_trump(card)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        (**self).compare(a, b)
+    }
+}
+
+impl<C: CardComparator + ?Sized> CardComparator for Box<C> {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        (**self).rank_value(rank)
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        (**self).suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        (**self).effective_suit(card)
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        (**self).is_trump(card)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        (**self).compare(a, b)
+    }
 }
 
 /// Standard comparator with Ace high (14) and Joker highest (15).
@@ -137,6 +299,10 @@ impl CardComparator for BridgeComparator {
 }
 
 /// A comparator with a trump suit. Trump cards are always higher than non-trump.
+///
+/// Two non-trump cards compare by rank alone, regardless of suit. To find
+/// the winner of a trick, where only the led suit and trumps can win, use
+/// [`Trick`](crate::Trick).
 #[derive(Debug, Clone, Copy)]
 pub struct TrumpComparator {
     trump: Suit,
@@ -157,6 +323,10 @@ impl CardComparator for TrumpComparator {
         rank.value() as i32
     }
 
+    fn is_trump(&self, card: &Card) -> bool {
+        card.suit() == self.trump
+    }
+
     fn compare(&self, a: &Card, b: &Card) -> Ordering {
         let a_is_trump = a.suit() == self.trump;
         let b_is_trump = b.suit() == self.trump;
@@ -171,3 +341,425 @@ impl CardComparator for TrumpComparator {
         }
     }
 }
+
+/// Euchre ordering for a given trump suit.
+///
+/// The jack of trump (the right bower) is the highest card and the other
+/// jack of the same color (the left bower) is second. The left bower belongs
+/// to the trump suit for following suit, as reported by
+/// [`effective_suit`](CardComparator::effective_suit). The remaining trumps
+/// follow, Ace high. With a led suit set, cards of the led suit beat the
+/// other non-trump suits, so the highest card is the trick winner.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardComparator, EuchreComparator, Rank, Suit};
+///
+/// let cmp = EuchreComparator::new(Suit::Hearts).with_led(Suit::Clubs);
+/// let right = Card::new(Suit::Hearts, Rank::Jack);
+/// let left = Card::new(Suit::Diamonds, Rank::Jack);
+/// let ace_trump = Card::new(Suit::Hearts, Rank::Ace);
+/// let nine_clubs = Card::new(Suit::Clubs, Rank::Nine);
+/// let ace_spades = Card::new(Suit::Spades, Rank::Ace);
+///
+/// assert!(cmp.is_greater(&right, &left));
+/// assert!(cmp.is_greater(&left, &ace_trump));
+/// assert!(cmp.is_greater(&nine_clubs, &ace_spades));
+/// assert_eq!(cmp.effective_suit(&left), Suit::Hearts);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EuchreComparator {
+    trump: Suit,
+    led: Option<Suit>,
+}
+
+impl EuchreComparator {
+    /// Creates a Euchre comparator with `trump` as the trump suit.
+    pub fn new(trump: Suit) -> Self {
+        Self { trump, led: None }
+    }
+
+    /// Sets the led suit, which outranks the other non-trump suits.
+    pub fn with_led(mut self, led: Suit) -> Self {
+        self.led = Some(led);
+        self
+    }
+
+    /// Returns the trump suit.
+    pub fn trump(&self) -> Suit {
+        self.trump
+    }
+
+    /// Returns the led suit, if set.
+    pub fn led(&self) -> Option<Suit> {
+        self.led
+    }
+
+    /// Returns true if `card` is the jack of trump.
+    pub fn is_right_bower(&self, card: &Card) -> bool {
+        card.rank() == Rank::Jack && card.suit() == self.trump
+    }
+
+    /// Returns true if `card` is the jack of the other suit of trump's color.
+    pub fn is_left_bower(&self, card: &Card) -> bool {
+        card.rank() == Rank::Jack
+            && card.suit() != self.trump
+            && card.suit().color() == self.trump.color()
+    }
+
+    /// Ranks `card` as (suit tier, value within the tier).
+    fn key(&self, card: &Card) -> (u8, i32) {
+        let rank = self.rank_value(card.rank());
+        if self.is_right_bower(card) {
+            (3, i32::MAX)
+        } else if self.is_left_bower(card) {
+            (3, i32::MAX - 1)
+        } else if card.suit() == self.trump {
+            (3, rank)
+        } else if Some(card.suit()) == self.led {
+            (2, rank)
+        } else {
+            (1, rank)
+        }
+    }
+}
+
+impl CardComparator for EuchreComparator {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        rank.value() as i32
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        if self.is_left_bower(card) {
+            self.trump
+        } else {
+            card.suit()
+        }
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.effective_suit(card) == self.trump
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+}
+
+/// Plain-suit rank order of the German games: A, 10, K, Q, J, 9, 8, 7.
+fn german_rank_value(rank: Rank) -> i32 {
+    match rank {
+        Rank::Seven => 0,
+        Rank::Eight => 1,
+        Rank::Nine => 2,
+        Rank::Jack => 3,
+        Rank::Queen => 4,
+        Rank::King => 5,
+        Rank::Ten => 6,
+        Rank::Ace => 7,
+        Rank::Joker => 8,
+        // Below Seven; these ranks are not in the German decks.
+        _ => rank.value() as i32 - 10,
+    }
+}
+
+/// Suit order of the German games, from Diamonds (Schellen) up to Clubs
+/// (Eichel), used to rank jacks and queens.
+fn german_suit_value(suit: Suit) -> i32 {
+    match suit {
+        Suit::Diamonds => 0,
+        Suit::Hearts => 1,
+        Suit::Spades => 2,
+        Suit::Clubs => 3,
+    }
+}
+
+/// The contract of a Skat game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkatGame {
+    /// Only the four jacks are trumps.
+    Grand,
+    /// The four jacks and the given suit are trumps.
+    Trump(Suit),
+    /// No trumps, and ranks keep their natural order.
+    Null,
+}
+
+/// Skat ordering for a given game.
+///
+/// In Grand and suit games the four jacks are the highest trumps, ranked
+/// ♣ ♠ ♥ ♦, followed in suit games by the trump suit. Within a suit the
+/// order is A, 10, K, Q, 9, 8, 7. In Null there are no trumps and the order
+/// is A, K, Q, J, 10, 9, 8, 7. Cards in different plain suits tie; use
+/// [`Trick`](crate::Trick) to find trick winners.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardComparator, Rank, SkatComparator, SkatGame, Suit};
+///
+/// let cmp = SkatComparator::new(SkatGame::Trump(Suit::Hearts));
+/// let jack_diamonds = Card::new(Suit::Diamonds, Rank::Jack);
+/// let ace_hearts = Card::new(Suit::Hearts, Rank::Ace);
+/// let ten_spades = Card::new(Suit::Spades, Rank::Ten);
+/// let king_spades = Card::new(Suit::Spades, Rank::King);
+///
+/// assert!(cmp.is_greater(&jack_diamonds, &ace_hearts));
+/// assert!(cmp.is_greater(&ten_spades, &king_spades));
+/// assert_eq!(cmp.effective_suit(&jack_diamonds), Suit::Hearts);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkatComparator {
+    game: SkatGame,
+}
+
+impl SkatComparator {
+    /// Creates a Skat comparator for `game`.
+    pub fn new(game: SkatGame) -> Self {
+        Self { game }
+    }
+
+    /// Returns the game being played.
+    pub fn game(&self) -> SkatGame {
+        self.game
+    }
+
+    /// Ranks `card` as (suit tier, value within the tier).
+    fn key(&self, card: &Card) -> (u8, i32) {
+        match self.game {
+            SkatGame::Null => (1, self.rank_value(card.rank())),
+            _ if card.rank() == Rank::Jack => (3, german_suit_value(card.suit())),
+            SkatGame::Trump(trump) if card.suit() == trump => (2, self.rank_value(card.rank())),
+            _ => (1, self.rank_value(card.rank())),
+        }
+    }
+}
+
+impl CardComparator for SkatComparator {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        match self.game {
+            SkatGame::Null => rank.value() as i32,
+            _ => german_rank_value(rank),
+        }
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        german_suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        match self.game {
+            SkatGame::Trump(trump) if card.rank() == Rank::Jack => trump,
+            _ => card.suit(),
+        }
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.key(card).0 > 1
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+}
+
+/// The contract of a Schafkopf game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchafkopfGame {
+    /// Queens (Obers), jacks (Unters) and the given suit are trumps. Hearts
+    /// is the trump suit of the normal game; other suits are solos.
+    Trump(Suit),
+    /// Only the jacks (Unters) are trumps.
+    Wenz,
+    /// Only the queens (Obers) are trumps.
+    Geier,
+}
+
+/// Schafkopf ordering for a given game, with French suits standing in for
+/// German ones (♣ Eichel, ♠ Gras, ♥ Herz, ♦ Schellen).
+///
+/// In the normal game and solos the queens are the highest trumps, then the
+/// jacks, each ranked ♣ ♠ ♥ ♦, then the trump suit. Within a suit the order
+/// is A, 10, K, Q, J, 9, 8, 7. Cards in different plain suits tie; use
+/// [`Trick`](crate::Trick) to find trick winners.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardComparator, Rank, SchafkopfComparator, SchafkopfGame, Suit};
+///
+/// let cmp = SchafkopfComparator::default();
+/// let queen_diamonds = Card::new(Suit::Diamonds, Rank::Queen);
+/// let jack_clubs = Card::new(Suit::Clubs, Rank::Jack);
+/// let ace_hearts = Card::new(Suit::Hearts, Rank::Ace);
+/// assert!(cmp.is_greater(&queen_diamonds, &jack_clubs));
+/// assert!(cmp.is_greater(&jack_clubs, &ace_hearts));
+///
+/// let wenz = SchafkopfComparator::new(SchafkopfGame::Wenz);
+/// assert!(!wenz.is_trump(&queen_diamonds));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchafkopfComparator {
+    game: SchafkopfGame,
+}
+
+impl SchafkopfComparator {
+    /// Creates a Schafkopf comparator for `game`.
+    pub fn new(game: SchafkopfGame) -> Self {
+        Self { game }
+    }
+
+    /// Returns the game being played.
+    pub fn game(&self) -> SchafkopfGame {
+        self.game
+    }
+
+    /// Ranks `card` as (suit tier, value within the tier).
+    fn key(&self, card: &Card) -> (u8, i32) {
+        let suit = german_suit_value(card.suit());
+        match (self.game, card.rank()) {
+            (SchafkopfGame::Trump(_) | SchafkopfGame::Geier, Rank::Queen) => (4, suit),
+            (SchafkopfGame::Trump(_) | SchafkopfGame::Wenz, Rank::Jack) => (3, suit),
+            (SchafkopfGame::Trump(trump), rank) if card.suit() == trump => {
+                (2, german_rank_value(rank))
+            }
+            (_, rank) => (1, german_rank_value(rank)),
+        }
+    }
+}
+
+impl Default for SchafkopfComparator {
+    /// The normal game, with hearts as the trump suit.
+    fn default() -> Self {
+        Self::new(SchafkopfGame::Trump(Suit::Hearts))
+    }
+}
+
+impl CardComparator for SchafkopfComparator {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        german_rank_value(rank)
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        german_suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        match self.game {
+            SchafkopfGame::Trump(trump) if self.is_trump(card) => trump,
+            _ => card.suit(),
+        }
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.key(card).0 > 1
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+}
+
+/// The contract of a Doppelkopf game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DoppelkopfGame {
+    /// The ten of hearts, queens, jacks and the given suit are trumps.
+    /// Diamonds is the trump suit of the normal game; other suits are solos.
+    Trump(Suit),
+    /// Only the queens are trumps.
+    QueenSolo,
+    /// Only the jacks are trumps.
+    JackSolo,
+    /// No trumps (Fleischloser).
+    NoTrump,
+}
+
+/// Doppelkopf ordering for a given game.
+///
+/// In the normal game and suit solos the tens of hearts (Dullen) are the
+/// highest trumps, then the queens and then the jacks, each ranked ♣ ♠ ♥ ♦,
+/// then the trump suit. Within a suit the order is A, 10, K, Q, J, 9. The
+/// deck holds every card twice; of two equal cards in a trick the first
+/// played wins, as [`Trick`](crate::Trick) does.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardComparator, DoppelkopfComparator, DoppelkopfGame, Rank, Suit};
+///
+/// let cmp = DoppelkopfComparator::default();
+/// let dulle = Card::new(Suit::Hearts, Rank::Ten);
+/// let queen_clubs = Card::new(Suit::Clubs, Rank::Queen);
+/// let ace_diamonds = Card::new(Suit::Diamonds, Rank::Ace);
+/// let ace_hearts = Card::new(Suit::Hearts, Rank::Ace);
+/// assert!(cmp.is_greater(&dulle, &queen_clubs));
+/// assert!(cmp.is_trump(&ace_diamonds));
+/// assert!(!cmp.is_trump(&ace_hearts));
+///
+/// let no_trump = DoppelkopfComparator::new(DoppelkopfGame::NoTrump);
+/// assert!(!no_trump.is_trump(&dulle));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DoppelkopfComparator {
+    game: DoppelkopfGame,
+}
+
+impl DoppelkopfComparator {
+    /// Creates a Doppelkopf comparator for `game`.
+    pub fn new(game: DoppelkopfGame) -> Self {
+        Self { game }
+    }
+
+    /// Returns the game being played.
+    pub fn game(&self) -> DoppelkopfGame {
+        self.game
+    }
+
+    /// Ranks `card` as (suit tier, value within the tier).
+    fn key(&self, card: &Card) -> (u8, i32) {
+        let suit = german_suit_value(card.suit());
+        let is_dulle = card.suit() == Suit::Hearts && card.rank() == Rank::Ten;
+        match (self.game, card.rank()) {
+            (DoppelkopfGame::Trump(_), _) if is_dulle => (5, 0),
+            (DoppelkopfGame::Trump(_) | DoppelkopfGame::QueenSolo, Rank::Queen) => (4, suit),
+            (DoppelkopfGame::Trump(_) | DoppelkopfGame::JackSolo, Rank::Jack) => (3, suit),
+            (DoppelkopfGame::Trump(trump), rank) if card.suit() == trump => {
+                (2, german_rank_value(rank))
+            }
+            (_, rank) => (1, german_rank_value(rank)),
+        }
+    }
+}
+
+impl Default for DoppelkopfComparator {
+    /// The normal game, with diamonds as the trump suit.
+    fn default() -> Self {
+        Self::new(DoppelkopfGame::Trump(Suit::Diamonds))
+    }
+}
+
+impl CardComparator for DoppelkopfComparator {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        german_rank_value(rank)
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        german_suit_value(suit)
+    }
+
+    fn effective_suit(&self, card: &Card) -> Suit {
+        match self.game {
+            DoppelkopfGame::Trump(trump) if self.is_trump(card) => trump,
+            _ => card.suit(),
+        }
+    }
+
+    fn is_trump(&self, card: &Card) -> bool {
+        self.key(card).0 > 1
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.key(a).cmp(&self.key(b))
+    }
+}
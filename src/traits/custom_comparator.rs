@@ -0,0 +1,295 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use crate::{Card, CardComparator, Rank, Suit};
+
+/// A comparator built from explicit rank and suit orders.
+///
+/// Orders are listed lowest first. Each entry is a level, and a level may
+/// hold several ranks (or suits) that tie. Ranks and suits that are not
+/// listed rank below every listed one. Excluded cards sort below all other
+/// cards, and among themselves by the same rank and suit orders.
+///
+/// Cards compare by rank level first, then by suit level.
+///
+/// The comparator loads from JSON or YAML, so a game variant's ranking can
+/// ship as configuration. A level is either a single name or a list of names
+/// that tie, and every field is optional:
+///
+/// ```yaml
+/// rank_order: [Two, Three, Four, Five, Six, Seven, Eight, Nine, [Ten, Jack, Queen, King], Ace]
+/// suit_order: [Clubs, Diamonds, Hearts, Spades]
+/// excluded:
+///   - { suit: Hearts, rank: Joker }
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardComparator, CustomComparator, Rank, Suit};
+///
+/// let cmp = CustomComparator::new()
+///     .with_rank_order(&[Rank::Two, Rank::Three, Rank::Ace])
+///     .with_suit_groups(&[&[Suit::Clubs, Suit::Spades], &[Suit::Hearts, Suit::Diamonds]]);
+///
+/// let ace_clubs = Card::new(Suit::Clubs, Rank::Ace);
+/// let ace_hearts = Card::new(Suit::Hearts, Rank::Ace);
+/// let ace_spades = Card::new(Suit::Spades, Rank::Ace);
+/// let king = Card::new(Suit::Hearts, Rank::King);
+/// assert!(cmp.is_greater(&ace_hearts, &ace_clubs));
+/// assert_eq!(cmp.compare(&ace_clubs, &ace_spades), std::cmp::Ordering::Equal);
+/// assert!(cmp.is_less(&king, &ace_clubs));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "CustomOrder", into = "CustomOrder")]
+pub struct CustomComparator {
+    rank_order: Vec<Vec<Rank>>,
+    suit_order: Vec<Vec<Suit>>,
+    excluded: Vec<Card>,
+}
+
+impl CustomComparator {
+    /// Creates a comparator with no orders, under which all cards are equal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rank order, lowest first, with every rank on its own level.
+    pub fn with_rank_order(self, ranks: &[Rank]) -> Self {
+        let groups: Vec<&[Rank]> = ranks.iter().map(std::slice::from_ref).collect();
+        self.with_rank_groups(&groups)
+    }
+
+    /// Sets the rank order, lowest level first, where the ranks within a
+    /// level tie. A rank listed twice keeps its first level.
+    pub fn with_rank_groups(mut self, groups: &[&[Rank]]) -> Self {
+        self.rank_order = dedup_groups(groups);
+        self
+    }
+
+    /// Sets the suit order, lowest first, with every suit on its own level.
+    pub fn with_suit_order(self, suits: &[Suit]) -> Self {
+        let groups: Vec<&[Suit]> = suits.iter().map(std::slice::from_ref).collect();
+        self.with_suit_groups(&groups)
+    }
+
+    /// Sets the suit order, lowest level first, where the suits within a
+    /// level tie. A suit listed twice keeps its first level.
+    pub fn with_suit_groups(mut self, groups: &[&[Suit]]) -> Self {
+        self.suit_order = dedup_groups(groups);
+        self
+    }
+
+    /// Sets the cards that sort below every other card.
+    pub fn with_excluded(mut self, cards: &[Card]) -> Self {
+        self.excluded = cards.to_vec();
+        self
+    }
+
+    /// Returns the rank levels, lowest first.
+    pub fn rank_order(&self) -> &[Vec<Rank>] {
+        &self.rank_order
+    }
+
+    /// Returns the suit levels, lowest first.
+    pub fn suit_order(&self) -> &[Vec<Suit>] {
+        &self.suit_order
+    }
+
+    /// Returns the excluded cards.
+    pub fn excluded(&self) -> &[Card] {
+        &self.excluded
+    }
+
+    /// Returns true if `card` is excluded.
+    pub fn is_excluded(&self, card: &Card) -> bool {
+        self.excluded.contains(card)
+    }
+
+    /// Serializes the comparator to a JSON string.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Creates a comparator from a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed, has unknown fields, or
+    /// lists a rank or suit more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{CustomComparator, Rank};
+    ///
+    /// let cmp = CustomComparator::from_json(r#"{"rank_order": ["Ace", ["Two", "Three"]]}"#).unwrap();
+    /// assert_eq!(cmp.rank_order(), &[vec![Rank::Ace], vec![Rank::Two, Rank::Three]]);
+    /// ```
+    pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+
+    /// Serializes the comparator to a YAML string.
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// Creates a comparator from a YAML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the YAML is malformed, has unknown fields, or
+    /// lists a rank or suit more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{CardComparator, CustomComparator, Deck};
+    ///
+    /// let cmp = CustomComparator::from_yaml("rank_order: [Ace, Two, Three]\nsuit_order: [Spades, Hearts]\n").unwrap();
+    /// let mut deck: Deck = "3♥ A♥ 2♠ A♠".parse().unwrap();
+    /// deck.sort_by_comparator(&cmp);
+    /// assert_eq!(deck.to_string(), "A♠ A♥ 2♠ 3♥");
+    /// ```
+    pub fn from_yaml(s: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(s)
+    }
+}
+
+impl CardComparator for CustomComparator {
+    fn rank_value(&self, rank: Rank) -> i32 {
+        level(&self.rank_order, &rank)
+    }
+
+    fn suit_value(&self, suit: Suit) -> i32 {
+        level(&self.suit_order, &suit)
+    }
+
+    fn compare(&self, a: &Card, b: &Card) -> Ordering {
+        self.is_excluded(b)
+            .cmp(&self.is_excluded(a))
+            .then_with(|| self.rank_value(a.rank()).cmp(&self.rank_value(b.rank())))
+            .then_with(|| self.suit_value(a.suit()).cmp(&self.suit_value(b.suit())))
+    }
+}
+
+/// Returns the index of the level holding `item`, or -1 if it is unlisted.
+fn level<T: PartialEq>(groups: &[Vec<T>], item: &T) -> i32 {
+    groups
+        .iter()
+        .position(|group| group.contains(item))
+        .map_or(-1, |index| index as i32)
+}
+
+fn dedup_groups<T: PartialEq + Copy>(groups: &[&[T]]) -> Vec<Vec<T>> {
+    let mut seen = Vec::new();
+    let mut result = Vec::new();
+    for group in groups {
+        let mut level = Vec::new();
+        for &item in group.iter() {
+            if !seen.contains(&item) {
+                seen.push(item);
+                level.push(item);
+            }
+        }
+        if !level.is_empty() {
+            result.push(level);
+        }
+    }
+    result
+}
+
+/// The serialized form of [`CustomComparator`].
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CustomOrder {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rank_order: Vec<Level<Rank>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    suit_order: Vec<Level<Suit>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    excluded: Vec<Card>,
+}
+
+/// One level of an order: a single rank or suit, or several that tie.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Level<T> {
+    One(T),
+    Tie(Vec<T>),
+}
+
+impl<T> Level<T> {
+    fn into_vec(self) -> Vec<T> {
+        match self {
+            Level::One(item) => vec![item],
+            Level::Tie(items) => items,
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Level<T> {
+    fn from(mut items: Vec<T>) -> Self {
+        if items.len() == 1 {
+            Level::One(items.remove(0))
+        } else {
+            Level::Tie(items)
+        }
+    }
+}
+
+/// Checks that no item appears twice in a configuration `field`, naming the
+/// first repeated item with `label`.
+pub(crate) fn check_unique<T, D>(
+    items: impl IntoIterator<Item = T>,
+    field: &str,
+    label: impl Fn(&T) -> D,
+) -> Result<(), String>
+where
+    T: PartialEq,
+    D: std::fmt::Display,
+{
+    let items: Vec<T> = items.into_iter().collect();
+    for (i, item) in items.iter().enumerate() {
+        if items[i + 1..].contains(item) {
+            return Err(format!(
+                "{} appears more than once in {}",
+                label(item),
+                field
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn checked_levels<T>(levels: Vec<Level<T>>, field: &str) -> Result<Vec<Vec<T>>, String>
+where
+    T: PartialEq + std::fmt::Debug,
+{
+    let groups: Vec<Vec<T>> = levels.into_iter().map(Level::into_vec).collect();
+    check_unique(groups.iter().flatten(), field, |item| format!("{:?}", item))?;
+    Ok(groups)
+}
+
+impl TryFrom<CustomOrder> for CustomComparator {
+    type Error = String;
+
+    fn try_from(order: CustomOrder) -> Result<Self, Self::Error> {
+        Ok(CustomComparator {
+            rank_order: checked_levels(order.rank_order, "rank_order")?,
+            suit_order: checked_levels(order.suit_order, "suit_order")?,
+            excluded: order.excluded,
+        })
+    }
+}
+
+impl From<CustomComparator> for CustomOrder {
+    fn from(comparator: CustomComparator) -> Self {
+        CustomOrder {
+            rank_order: comparator.rank_order.into_iter().map(Level::from).collect(),
+            suit_order: comparator.suit_order.into_iter().map(Level::from).collect(),
+            excluded: comparator.excluded,
+        }
+    }
+}
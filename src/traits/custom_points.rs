@@ -0,0 +1,268 @@
+use serde::de::{Deserializer, MapAccess, Visitor};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::traits::custom_comparator::check_unique;
+use crate::{Card, CardPoints, Rank, Suit};
+
+/// A point scheme built from per-rank, per-suit and per-card values.
+///
+/// A card listed under `cards` scores exactly that value. Any other card
+/// scores its rank's points plus its suit's points, and unlisted ranks and
+/// suits score 0.
+///
+/// In JSON or YAML, `ranks` and `suits` map names to points and `cards`
+/// lists individual cards. Leave out any of the three that a scheme does not
+/// use:
+///
+/// ```yaml
+/// suits: { Hearts: 1 }
+/// cards:
+///   - { suit: Spades, rank: Queen, points: 13 }
+///   - { suit: Diamonds, rank: Jack, points: -10 }
+/// ```
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, CardPoints, CustomPoints, Rank, Suit};
+///
+/// let pinochle = CustomPoints::new()
+///     .with_rank_points(Rank::Ace, 1)
+///     .with_rank_points(Rank::Ten, 1)
+///     .with_rank_points(Rank::King, 1);
+/// let ace = Card::new(Suit::Hearts, Rank::Ace);
+/// let nine = Card::new(Suit::Hearts, Rank::Nine);
+/// assert_eq!(pinochle.total(&[ace, nine]), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "PointsConfig", into = "PointsConfig")]
+pub struct CustomPoints {
+    ranks: Vec<(Rank, i32)>,
+    suits: Vec<(Suit, i32)>,
+    cards: Vec<(Card, i32)>,
+}
+
+impl CustomPoints {
+    /// Creates a scheme under which every card scores 0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the points for cards of `rank`.
+    pub fn with_rank_points(mut self, rank: Rank, points: i32) -> Self {
+        set(&mut self.ranks, rank, points);
+        self
+    }
+
+    /// Sets the points added for cards of `suit`.
+    pub fn with_suit_points(mut self, suit: Suit, points: i32) -> Self {
+        set(&mut self.suits, suit, points);
+        self
+    }
+
+    /// Sets the points for `card`, overriding its rank and suit points.
+    pub fn with_card_points(mut self, card: Card, points: i32) -> Self {
+        set(&mut self.cards, card, points);
+        self
+    }
+
+    /// Returns the points for cards of `rank`.
+    pub fn rank_points(&self, rank: Rank) -> i32 {
+        get(&self.ranks, &rank).unwrap_or(0)
+    }
+
+    /// Returns the points added for cards of `suit`.
+    pub fn suit_points(&self, suit: Suit) -> i32 {
+        get(&self.suits, &suit).unwrap_or(0)
+    }
+
+    /// Returns the points set for `card` itself, if any.
+    pub fn card_points(&self, card: &Card) -> Option<i32> {
+        get(&self.cards, card)
+    }
+
+    /// Serializes the scheme to a JSON string.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Creates a scheme from a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is malformed, has unknown fields, or
+    /// lists a rank, suit or card more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{CustomPoints, Rank};
+    ///
+    /// let scheme = CustomPoints::from_json(r#"{"ranks": {"Ace": 11, "Ten": 10}}"#).unwrap();
+    /// assert_eq!(scheme.rank_points(Rank::Ten), 10);
+    /// ```
+    pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+
+    /// Serializes the scheme to a YAML string.
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// Creates a scheme from a YAML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the YAML is malformed, has unknown fields, or
+    /// lists a rank, suit or card more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{CardPoints, CustomPoints, Deck, Standard52};
+    ///
+    /// let hearts = CustomPoints::from_yaml(
+    ///     "suits: { Hearts: 1 }\ncards:\n  - { suit: Spades, rank: Queen, points: 13 }\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(Deck::from_factory(Standard52).points(&hearts), 26);
+    /// ```
+    pub fn from_yaml(s: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(s)
+    }
+}
+
+impl CardPoints for CustomPoints {
+    fn points(&self, card: &Card) -> i32 {
+        self.card_points(card)
+            .unwrap_or_else(|| self.rank_points(card.rank()) + self.suit_points(card.suit()))
+    }
+}
+
+fn get<K: PartialEq>(entries: &[(K, i32)], key: &K) -> Option<i32> {
+    entries
+        .iter()
+        .find(|(existing, _)| existing == key)
+        .map(|&(_, points)| points)
+}
+
+fn set<K: PartialEq>(entries: &mut Vec<(K, i32)>, key: K, points: i32) {
+    match entries.iter_mut().find(|(existing, _)| *existing == key) {
+        Some(entry) => entry.1 = points,
+        None => entries.push((key, points)),
+    }
+}
+
+/// The serialized form of [`CustomPoints`].
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PointsConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "ordered_map")]
+    ranks: Vec<(Rank, i32)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "ordered_map")]
+    suits: Vec<(Suit, i32)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cards: Vec<CardEntry>,
+}
+
+/// One entry of the `cards` list: a card and its points.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CardEntry {
+    suit: Suit,
+    rank: Rank,
+    points: i32,
+}
+
+impl TryFrom<PointsConfig> for CustomPoints {
+    type Error = String;
+
+    fn try_from(config: PointsConfig) -> Result<Self, Self::Error> {
+        let cards: Vec<(Card, i32)> = config
+            .cards
+            .into_iter()
+            .map(|entry| (Card::new(entry.suit, entry.rank), entry.points))
+            .collect();
+        check_unique(config.ranks.iter().map(|(rank, _)| rank), "ranks", |rank| {
+            format!("{:?}", rank)
+        })?;
+        check_unique(config.suits.iter().map(|(suit, _)| suit), "suits", |suit| {
+            format!("{:?}", suit)
+        })?;
+        check_unique(cards.iter().map(|(card, _)| card), "cards", |card| {
+            card.to_string()
+        })?;
+        Ok(CustomPoints {
+            ranks: config.ranks,
+            suits: config.suits,
+            cards,
+        })
+    }
+}
+
+impl From<CustomPoints> for PointsConfig {
+    fn from(points: CustomPoints) -> Self {
+        PointsConfig {
+            ranks: points.ranks,
+            suits: points.suits,
+            cards: points
+                .cards
+                .into_iter()
+                .map(|(card, points)| CardEntry {
+                    suit: card.suit(),
+                    rank: card.rank(),
+                    points,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Serializes `(key, value)` pairs as a map, keeping their order.
+mod ordered_map {
+    use super::*;
+
+    pub fn serialize<S, K, V>(entries: &[(K, V)], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        K: Serialize,
+        V: Serialize,
+    {
+        serializer.collect_map(entries.iter().map(|(key, value)| (key, value)))
+    }
+
+    pub fn deserialize<'de, D, K, V>(deserializer: D) -> Result<Vec<(K, V)>, D::Error>
+    where
+        D: Deserializer<'de>,
+        K: Deserialize<'de>,
+        V: Deserialize<'de>,
+    {
+        struct PairsVisitor<K, V>(PhantomData<(K, V)>);
+
+        impl<'de, K, V> Visitor<'de> for PairsVisitor<K, V>
+        where
+            K: Deserialize<'de>,
+            V: Deserialize<'de>,
+        {
+            type Value = Vec<(K, V)>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(entries)
+            }
+        }
+
+        deserializer.deserialize_map(PairsVisitor(PhantomData))
+    }
+}
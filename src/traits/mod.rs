@@ -1,9 +1,34 @@
+pub mod combinator;
 pub mod comparator;
+pub mod custom_comparator;
+pub mod custom_points;
 pub mod factory;
+pub mod points;
 
+pub use combinator::ColorGrouped;
+pub use combinator::FnComparator;
+pub use combinator::JokersHigh;
+pub use combinator::JokersLow;
+pub use combinator::Reversed;
+pub use combinator::SuitFirst;
+pub use combinator::ThenBy;
 pub use comparator::AceLowComparator;
 pub use comparator::BridgeComparator;
 pub use comparator::CardComparator;
+pub use comparator::DoppelkopfComparator;
+pub use comparator::DoppelkopfGame;
+pub use comparator::EuchreComparator;
+pub use comparator::SchafkopfComparator;
+pub use comparator::SchafkopfGame;
+pub use comparator::SkatComparator;
+pub use comparator::SkatGame;
 pub use comparator::StandardComparator;
 pub use comparator::TrumpComparator;
+pub use custom_comparator::CustomComparator;
+pub use custom_points::CustomPoints;
 pub use factory::DeckFactory;
+pub use points::BlackjackPoints;
+pub use points::CardPoints;
+pub use points::CribbagePoints;
+pub use points::HeartsPoints;
+pub use points::SkatPoints;
@@ -0,0 +1,176 @@
+use crate::{Card, Rank, Suit};
+
+/// Trait for scoring cards in point-counting games.
+///
+/// Where [`CardComparator`](crate::CardComparator) decides which card is
+/// higher, a point scheme decides what a card is worth when counting tricks,
+/// hands or discards.
+///
+/// # Example
+/// ```
+/// use crusty_cards::{Card, CardPoints, Rank};
+///
+/// struct FivesAndTens;
+///
+/// impl CardPoints for FivesAndTens {
+///     fn points(&self, card: &Card) -> i32 {
+///         match card.rank() {
+///             Rank::Five => 5,
+///             Rank::Ten => 10,
+///             _ => 0,
+///         }
+///     }
+/// }
+///
+/// let cards = ["5♥", "T♠", "K♦"].map(|s| s.parse::<Card>().unwrap());
+/// assert_eq!(FivesAndTens.total(&cards), 15);
+/// ```
+pub trait CardPoints {
+    /// Returns the points `card` is worth.
+    fn points(&self, card: &Card) -> i32;
+
+    /// Returns the total points of `cards`.
+    fn total(&self, cards: &[Card]) -> i32 {
+        cards.iter().map(|card| self.points(card)).sum()
+    }
+}
+
+impl<P: CardPoints + ?Sized> CardPoints for &P {
+    fn points(&self, card: &Card) -> i32 {
+        (**self).points(card)
+    }
+}
+
+impl<P: CardPoints + ?Sized> CardPoints for Box<P> {
+    fn points(&self, card: &Card) -> i32 {
+        (**self).points(card)
+    }
+}
+
+/// Skat card points: Ace 11, Ten 10, King 4, Queen 3, Jack 2, others 0.
+///
+/// The 32-card deck totals 120. The same counters score Pinochle's
+/// traditional count and several other Central European games.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SkatPoints;
+
+impl CardPoints for SkatPoints {
+    fn points(&self, card: &Card) -> i32 {
+        match card.rank() {
+            Rank::Ace => 11,
+            Rank::Ten => 10,
+            Rank::King => 4,
+            Rank::Queen => 3,
+            Rank::Jack => 2,
+            _ => 0,
+        }
+    }
+}
+
+/// Hearts penalty points: 1 per heart and 13 for the queen of spades.
+///
+/// The jack of diamonds variant scores that card -10.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeartsPoints {
+    jack_of_diamonds: bool,
+}
+
+impl HeartsPoints {
+    /// Creates the standard Hearts scheme.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the jack of diamonds scores -10.
+    pub fn with_jack_of_diamonds(mut self, enabled: bool) -> Self {
+        self.jack_of_diamonds = enabled;
+        self
+    }
+
+    /// Returns true if the jack of diamonds scores -10.
+    pub fn jack_of_diamonds(&self) -> bool {
+        self.jack_of_diamonds
+    }
+}
+
+impl CardPoints for HeartsPoints {
+    fn points(&self, card: &Card) -> i32 {
+        match (card.suit(), card.rank()) {
+            (_, Rank::Joker) => 0,
+            (Suit::Hearts, _) => 1,
+            (Suit::Spades, Rank::Queen) => 13,
+            (Suit::Diamonds, Rank::Jack) if self.jack_of_diamonds => -10,
+            _ => 0,
+        }
+    }
+}
+
+/// Blackjack card values: pip value for Two through Ten, 10 for face cards
+/// and 11 for an Ace.
+///
+/// [`hand_total`](Self::hand_total) counts aces as 1 where needed to stay at
+/// 21 or under.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{BlackjackPoints, Card, CardPoints};
+///
+/// let hand = ["A♠", "A♥", "9♦"].map(|s| s.parse::<Card>().unwrap());
+/// assert_eq!(BlackjackPoints.total(&hand), 31);
+/// assert_eq!(BlackjackPoints.hand_total(&hand), 21);
+/// assert!(BlackjackPoints.is_soft(&hand));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BlackjackPoints;
+
+impl BlackjackPoints {
+    /// Returns the best total of `cards`, counting as many aces as 1 as
+    /// needed to reach 21 or under.
+    pub fn hand_total(&self, cards: &[Card]) -> i32 {
+        self.best(cards).0
+    }
+
+    /// Returns true if [`hand_total`](Self::hand_total) counts an ace as 11.
+    pub fn is_soft(&self, cards: &[Card]) -> bool {
+        self.best(cards).1
+    }
+
+    /// Returns the best total and whether an ace still counts 11.
+    fn best(&self, cards: &[Card]) -> (i32, bool) {
+        let mut total = self.total(cards);
+        let mut high_aces = cards.iter().filter(|card| card.is_ace()).count();
+        while total > 21 && high_aces > 0 {
+            total -= 10;
+            high_aces -= 1;
+        }
+        (total, high_aces > 0)
+    }
+}
+
+impl CardPoints for BlackjackPoints {
+    fn points(&self, card: &Card) -> i32 {
+        match card.rank() {
+            Rank::Ace => 11,
+            Rank::Jack | Rank::Queen | Rank::King => 10,
+            Rank::Joker => 0,
+            rank => rank.value() as i32 + 2,
+        }
+    }
+}
+
+/// Cribbage pip values: Ace 1, pip value for Two through Ten and 10 for face
+/// cards, as used when counting to fifteen and thirty-one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CribbagePoints;
+
+impl CardPoints for CribbagePoints {
+    fn points(&self, card: &Card) -> i32 {
+        match card.rank() {
+            Rank::Ace => 1,
+            Rank::Jack | Rank::Queen | Rank::King => 10,
+            Rank::Joker => 0,
+            rank => rank.value() as i32 + 2,
+        }
+    }
+}
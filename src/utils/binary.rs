@@ -0,0 +1,317 @@
+use std::convert::TryFrom;
+use std::io::{self, Read, Write};
+
+use crate::{Card, Deck, PackedDeck};
+
+/// Magic bytes opening every binary deck record.
+pub const BINARY_MAGIC: [u8; 4] = *b"CCDK";
+
+/// The binary deck format version written by [`DeckWriter`].
+pub const BINARY_VERSION: u8 = 1;
+
+/// Flag bit set when a record carries a factory identifier.
+const FLAG_FACTORY_ID: u8 = 0b0000_0001;
+
+/// A deck read from the binary format, with the factory identifier it was
+/// written with, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeckRecord {
+    deck: Deck,
+    factory_id: Option<String>,
+}
+
+impl DeckRecord {
+    /// Returns the deck.
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+
+    /// Returns the factory identifier, if the record has one.
+    pub fn factory_id(&self) -> Option<&str> {
+        self.factory_id.as_deref()
+    }
+
+    /// Returns the deck, dropping the factory identifier.
+    pub fn into_deck(self) -> Deck {
+        self.deck
+    }
+}
+
+/// Writes decks in the crate's compact binary format.
+///
+/// Each record is laid out as follows, with integers in little-endian order:
+///
+/// | Field        | Size          | Contents                                      |
+/// |--------------|---------------|-----------------------------------------------|
+/// | magic        | 4             | `CCDK`                                        |
+/// | version      | 1             | [`BINARY_VERSION`]                            |
+/// | flags        | 1             | bit 0: a factory identifier follows           |
+/// | factory id   | 1 + len       | length byte, then UTF-8 (only if flagged)     |
+/// | card count   | 4             | `u32`                                         |
+/// | cards        | count         | one byte per card, top first (see [`Card`])   |
+/// | checksum     | 4             | CRC-32 (IEEE) of every preceding record byte  |
+///
+/// Records can be written back to back into a single stream and read with
+/// [`DeckReader`].
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, DeckReader, DeckWriter, Standard52};
+///
+/// let mut writer = DeckWriter::new(Vec::new()).with_factory_id("standard52");
+/// for _ in 0..3 {
+///     let mut deck = Deck::from_factory(Standard52);
+///     deck.shuffle();
+///     writer.write_deck(&deck).unwrap();
+/// }
+/// let bytes = writer.into_inner();
+/// assert_eq!(bytes.len(), 3 * (4 + 1 + 1 + 11 + 4 + 52 + 4));
+///
+/// let records: Vec<_> = DeckReader::new(bytes.as_slice()).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(records.len(), 3);
+/// assert_eq!(records[0].factory_id(), Some("standard52"));
+/// ```
+#[derive(Debug)]
+pub struct DeckWriter<W: Write> {
+    writer: W,
+    factory_id: Option<String>,
+}
+
+impl<W: Write> DeckWriter<W> {
+    /// Creates a writer that writes records without a factory identifier.
+    pub fn new(writer: W) -> Self {
+        DeckWriter {
+            writer,
+            factory_id: None,
+        }
+    }
+
+    /// Tags every record written from now on with `factory_id`.
+    ///
+    /// The identifier must be at most 255 bytes of UTF-8; longer identifiers
+    /// make writes fail.
+    pub fn with_factory_id(mut self, factory_id: &str) -> Self {
+        self.factory_id = Some(factory_id.to_string());
+        self
+    }
+
+    /// Writes one deck record.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying writer, or an `InvalidInput`
+    /// error if the factory identifier or the deck are too long to encode.
+    pub fn write_deck(&mut self, deck: &Deck) -> io::Result<()> {
+        let cards: Vec<u8> = deck.iter().map(|card| u8::from(*card)).collect();
+        self.write_bytes(&cards)
+    }
+
+    /// Writes one record holding the live cards of a [`PackedDeck`], without
+    /// decoding them.
+    ///
+    /// # Errors
+    ///
+    /// See [`DeckWriter::write_deck`].
+    pub fn write_packed(&mut self, deck: &PackedDeck) -> io::Result<()> {
+        self.write_bytes(deck.as_bytes())
+    }
+
+    fn write_bytes(&mut self, cards: &[u8]) -> io::Result<()> {
+        let count = u32::try_from(cards.len()).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Too many cards for one record")
+        })?;
+        let mut header = Vec::with_capacity(16);
+        header.extend_from_slice(&BINARY_MAGIC);
+        header.push(BINARY_VERSION);
+        match &self.factory_id {
+            Some(id) => {
+                let len = u8::try_from(id.len()).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "Factory identifier is longer than 255 bytes",
+                    )
+                })?;
+                header.push(FLAG_FACTORY_ID);
+                header.push(len);
+                header.extend_from_slice(id.as_bytes());
+            }
+            None => header.push(0),
+        }
+        header.extend_from_slice(&count.to_le_bytes());
+        let checksum = crc32_update(crc32_update(!0, &header), cards) ^ !0;
+
+        self.writer.write_all(&header)?;
+        self.writer.write_all(cards)?;
+        self.writer.write_all(&checksum.to_le_bytes())
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads decks written by [`DeckWriter`].
+///
+/// Iterating yields one record at a time until the stream ends cleanly
+/// between records. A stream that ends inside a record yields an
+/// `UnexpectedEof` error; bad magic bytes, an unknown version or flags, an
+/// invalid card byte or a checksum mismatch yield an `InvalidData` error.
+#[derive(Debug)]
+pub struct DeckReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> DeckReader<R> {
+    /// Creates a reader over `reader`.
+    pub fn new(reader: R) -> Self {
+        DeckReader { reader }
+    }
+
+    /// Reads the next record, or returns `None` at the end of the stream.
+    ///
+    /// # Errors
+    ///
+    /// See [`DeckReader`].
+    pub fn read_record(&mut self) -> io::Result<Option<DeckRecord>> {
+        let mut magic = [0u8; 4];
+        let read = read_up_to(&mut self.reader, &mut magic)?;
+        if read == 0 {
+            return Ok(None);
+        }
+        if read < magic.len() {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        if magic != BINARY_MAGIC {
+            return Err(invalid_data("Invalid magic bytes".to_string()));
+        }
+        let mut crc = crc32_update(!0, &magic);
+
+        let mut fixed = [0u8; 2];
+        self.reader.read_exact(&mut fixed)?;
+        crc = crc32_update(crc, &fixed);
+        let [version, flags] = fixed;
+        if version != BINARY_VERSION {
+            return Err(invalid_data(format!(
+                "Unsupported binary deck version {}",
+                version
+            )));
+        }
+        if flags & !FLAG_FACTORY_ID != 0 {
+            return Err(invalid_data(format!("Unknown flags {:#04x}", flags)));
+        }
+
+        let factory_id =
+            if flags & FLAG_FACTORY_ID != 0 {
+                let mut len = [0u8; 1];
+                self.reader.read_exact(&mut len)?;
+                let mut id = vec![0u8; len[0] as usize];
+                self.reader.read_exact(&mut id)?;
+                crc = crc32_update(crc32_update(crc, &len), &id);
+                Some(String::from_utf8(id).map_err(|_| {
+                    invalid_data("Factory identifier is not valid UTF-8".to_string())
+                })?)
+            } else {
+                None
+            };
+
+        let mut count = [0u8; 4];
+        self.reader.read_exact(&mut count)?;
+        crc = crc32_update(crc, &count);
+        let count = u32::from_le_bytes(count) as u64;
+
+        // Grow as bytes arrive rather than trusting the count up front.
+        let mut cards = Vec::new();
+        (&mut self.reader).take(count).read_to_end(&mut cards)?;
+        if (cards.len() as u64) < count {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        crc = crc32_update(crc, &cards);
+
+        let mut checksum = [0u8; 4];
+        self.reader.read_exact(&mut checksum)?;
+        if u32::from_le_bytes(checksum) != crc ^ !0 {
+            return Err(invalid_data("Checksum mismatch".to_string()));
+        }
+
+        let deck = cards
+            .into_iter()
+            .map(|byte| {
+                Card::try_from(byte)
+                    .map_err(|_| invalid_data(format!("Invalid card value: {}", byte)))
+            })
+            .collect::<io::Result<Deck>>()?;
+        Ok(Some(DeckRecord { deck, factory_id }))
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for DeckReader<R> {
+    type Item = io::Result<DeckRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+/// Reads until `buf` is full or the stream ends, returning the bytes read.
+fn read_up_to<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Lookup table for the reflected CRC-32 (IEEE 802.3) polynomial.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Returns the CRC-32 (IEEE) checksum of `bytes`.
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    crc32_update(!0, bytes) ^ !0
+}
+
+/// Feeds `bytes` into a running CRC-32 register (start with `!0`, finish with `^ !0`).
+fn crc32_update(mut crc: u32, bytes: &[u8]) -> u32 {
+    for &byte in bytes {
+        crc = CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    crc
+}
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use crate::{Card, DeckFactory};
+
+/// Returns the binomial coefficient C(n, k), saturating at `u64::MAX`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::binomial;
+///
+/// assert_eq!(binomial(52, 5), 2_598_960);
+/// assert_eq!(binomial(3, 5), 0);
+/// ```
+pub fn binomial(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 0..k {
+        // Exact at every step: the running product is C(n, i + 1).
+        result = result * (n - i) as u128 / (i + 1) as u128;
+        if result > u64::MAX as u128 {
+            return u64::MAX;
+        }
+    }
+    result as u64
+}
+
+/// A lazy iterator over every `k`-card combination of a set of cards.
+///
+/// Combinations are produced in colexicographic order of card positions, the
+/// same order used by [`CombinationIndexer`], so the n-th combination yielded
+/// has index n. Each combination lists its cards in their original order.
+/// Cards are treated by position: duplicate cards in the source produce
+/// combinations that look the same.
+///
+/// For hot loops, [`Combinations::for_each_slice`] visits every combination
+/// through a single reused buffer instead of allocating one `Vec` per item.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, Standard52};
+///
+/// let deck = Deck::from_factory(Standard52);
+/// let mut hands = deck.combinations(2);
+/// assert_eq!(hands.size_hint(), (1326, Some(1326)));
+/// assert_eq!(hands.next().unwrap().len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Combinations {
+    cards: Vec<Card>,
+    positions: Vec<usize>,
+    remaining: u64,
+}
+
+impl Combinations {
+    /// Creates an iterator over every `k`-card combination of `cards`.
+    ///
+    /// Yields a single empty combination if `k` is 0 and nothing if `k`
+    /// exceeds the number of cards.
+    pub fn new(cards: &[Card], k: usize) -> Self {
+        Combinations {
+            cards: cards.to_vec(),
+            positions: (0..k).collect(),
+            remaining: binomial(cards.len(), k),
+        }
+    }
+
+    /// Creates an iterator over every `k`-card combination of `cards` that
+    /// contains none of the `dead` cards.
+    ///
+    /// Every copy of a dead card is excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, Combinations, Deck, Rank, Standard52, Suit};
+    ///
+    /// let deck: Vec<Card> = Deck::from_factory(Standard52).into_iter().collect();
+    /// let hole = [Card::new(Suit::Spades, Rank::Ace), Card::new(Suit::Hearts, Rank::Ace)];
+    /// assert_eq!(Combinations::excluding(&deck, 3, &hole).count(), 19600);
+    /// ```
+    pub fn excluding(cards: &[Card], k: usize, dead: &[Card]) -> Self {
+        let live: Vec<Card> = cards
+            .iter()
+            .filter(|card| !dead.contains(card))
+            .copied()
+            .collect();
+        Self::new(&live, k)
+    }
+
+    /// Returns the number of combinations not yet yielded.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Calls `f` with every remaining combination, reusing one buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, Deck, Standard52};
+    ///
+    /// let deck = Deck::from_factory(Standard52);
+    /// let mut four_aces = 0;
+    /// deck.combinations(5).for_each_slice(|hand| {
+    ///     if hand.iter().filter(|c| c.is_ace()).count() == 4 {
+    ///         four_aces += 1;
+    ///     }
+    /// });
+    /// assert_eq!(four_aces, 48);
+    /// ```
+    pub fn for_each_slice<F>(mut self, mut f: F)
+    where
+        F: FnMut(&[Card]),
+    {
+        let mut buffer = Vec::with_capacity(self.positions.len());
+        while self.remaining > 0 {
+            buffer.clear();
+            buffer.extend(self.positions.iter().map(|&p| self.cards[p]));
+            f(&buffer);
+            self.advance();
+        }
+    }
+
+    /// Moves the positions to the next combination in colex order.
+    fn advance(&mut self) {
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            return;
+        }
+        let k = self.positions.len();
+        let mut j = 0;
+        while j + 1 < k && self.positions[j] + 1 == self.positions[j + 1] {
+            j += 1;
+        }
+        self.positions[j] += 1;
+        for (i, position) in self.positions[..j].iter_mut().enumerate() {
+            *position = i;
+        }
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<Card>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let combination = self.positions.iter().map(|&p| self.cards[p]).collect();
+        self.advance();
+        Some(combination)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// Maps `k`-card combinations of a reference set to dense indices and back,
+/// using the combinatorial number system.
+///
+/// A combination whose cards sit at reference positions `c1 < c2 < ... < ck`
+/// has index `C(c1, 1) + C(c2, 2) + ... + C(ck, k)`. Indices run from 0 to
+/// `C(n, k) - 1` without gaps, and match the order [`Combinations`] yields.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{CombinationIndexer, Deck, Standard52};
+///
+/// let indexer = CombinationIndexer::from_factory(Standard52, 5).unwrap();
+/// assert_eq!(indexer.count(), 2_598_960);
+///
+/// let hand: Vec<_> = "A♠ K♠ Q♠ J♠ T♠".parse::<Deck>().unwrap().into_iter().collect();
+/// let index = indexer.index_of(&hand).unwrap();
+/// let back = indexer.combination_at(index).unwrap();
+/// assert_eq!(indexer.index_of(&back).unwrap(), index);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CombinationIndexer {
+    cards: Vec<Card>,
+    positions: HashMap<Card, usize>,
+    k: usize,
+}
+
+impl CombinationIndexer {
+    /// Creates an indexer for `k`-card combinations of `cards`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `cards` contains a duplicate card.
+    pub fn new(cards: &[Card], k: usize) -> Result<Self, String> {
+        let mut positions = HashMap::with_capacity(cards.len());
+        for (i, card) in cards.iter().enumerate() {
+            if positions.insert(*card, i).is_some() {
+                return Err(format!("Reference cards contain duplicate card {}", card));
+            }
+        }
+        Ok(CombinationIndexer {
+            cards: cards.to_vec(),
+            positions,
+            k,
+        })
+    }
+
+    /// Creates an indexer for `k`-card combinations of the cards a factory generates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the factory generates a duplicate card.
+    pub fn from_factory<F>(factory: F, k: usize) -> Result<Self, String>
+    where
+        F: DeckFactory,
+    {
+        let cards: Vec<Card> = factory.generate().into_iter().collect();
+        Self::new(&cards, k)
+    }
+
+    /// Returns the number of combinations, C(n, k).
+    pub fn count(&self) -> u64 {
+        binomial(self.cards.len(), self.k)
+    }
+
+    /// Returns the index of a combination. The order of `cards` does not matter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `cards` does not hold exactly `k` distinct cards
+    /// from the reference set.
+    pub fn index_of(&self, cards: &[Card]) -> Result<u64, String> {
+        if cards.len() != self.k {
+            return Err(format!(
+                "Expected {} cards but found {}",
+                self.k,
+                cards.len()
+            ));
+        }
+        let mut positions = Vec::with_capacity(self.k);
+        for card in cards {
+            let position = self
+                .positions
+                .get(card)
+                .ok_or_else(|| format!("Card {} is not in the reference cards", card))?;
+            positions.push(*position);
+        }
+        positions.sort_unstable();
+        if let Some(pair) = positions.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(format!("Duplicate card {}", self.cards[pair[0]]));
+        }
+        Ok(positions
+            .iter()
+            .enumerate()
+            .map(|(i, &position)| binomial(position, i + 1))
+            .sum())
+    }
+
+    /// Returns the combination with the given index, in reference order.
+    ///
+    /// Returns `None` if the index is not below [`CombinationIndexer::count`].
+    pub fn combination_at(&self, index: u64) -> Option<Vec<Card>> {
+        if index >= self.count() {
+            return None;
+        }
+        let mut rest = index;
+        let mut bound = self.cards.len();
+        let mut combination = Vec::with_capacity(self.k);
+        for i in (1..=self.k).rev() {
+            // Largest position with C(position, i) <= rest.
+            bound -= 1;
+            while binomial(bound, i) > rest {
+                bound -= 1;
+            }
+            rest -= binomial(bound, i);
+            combination.push(self.cards[bound]);
+        }
+        combination.reverse();
+        Some(combination)
+    }
+}
@@ -0,0 +1,466 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Card, Color, Deck, Rank, Suit};
+
+/// A column in a CSV card table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CsvColumn {
+    /// Identifier of the hand or deck the card belongs to.
+    Hand,
+    /// 1-based position of the card within its hand, top first.
+    Position,
+    /// The card's rank.
+    Rank,
+    /// The card's suit.
+    Suit,
+    /// The card's color, "Red" or "Black".
+    Color,
+    /// The card's numeric encoding (0-55, see [`Card`]).
+    Value,
+}
+
+impl CsvColumn {
+    /// Every column, in the order used by [`CsvFormat::all_columns`].
+    pub const ALL: [CsvColumn; 6] = [
+        CsvColumn::Hand,
+        CsvColumn::Position,
+        CsvColumn::Rank,
+        CsvColumn::Suit,
+        CsvColumn::Color,
+        CsvColumn::Value,
+    ];
+
+    /// Returns the header name of the column.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            CsvColumn::Hand => "Hand",
+            CsvColumn::Position => "Position",
+            CsvColumn::Rank => "Rank",
+            CsvColumn::Suit => "Suit",
+            CsvColumn::Color => "Color",
+            CsvColumn::Value => "Value",
+        }
+    }
+}
+
+impl FromStr for CsvColumn {
+    type Err = String;
+
+    /// Parses a header name, ignoring case and surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CsvColumn::ALL
+            .iter()
+            .find(|column| column.name().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| format!("Unknown column: {}", s))
+    }
+}
+
+/// How suits are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SuitStyle {
+    /// One ASCII letter: "H", "D", "C", "S".
+    #[default]
+    Letter,
+    /// The full name: "Hearts".
+    Name,
+    /// The Unicode symbol: "♥".
+    Symbol,
+}
+
+/// How ranks are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RankStyle {
+    /// Digits and letters, with ten written "10".
+    #[default]
+    Numeric,
+    /// One character per rank, with ten written "T".
+    Symbol,
+    /// The full name: "Ten".
+    Name,
+}
+
+/// An error while reading CSV, with the 1-based line it occurred on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvError {
+    line: usize,
+    message: String,
+}
+
+impl CsvError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        CsvError {
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the 1-based line the error occurred on.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the error message without the line number.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for CsvError {}
+
+/// Reads and writes cards as RFC 4180 CSV tables, one row per card.
+///
+/// The format controls which columns are written, whether a header row is
+/// written, how ranks and suits are spelled and whether lines end in CRLF.
+///
+/// Reading is tolerant: quoted fields, CRLF or LF line endings, a UTF-8 byte
+/// order mark and blank lines are accepted, ranks and suits may use any
+/// spelling the crate parses, and a header row is detected automatically and
+/// used to locate the columns in any order. Header columns the crate does not
+/// know, such as notes or metadata, are skipped. Without a header the
+/// format's columns are assumed. A card is taken from the rank and suit columns, or
+/// from the value column if those are missing.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{CsvColumn, CsvFormat, Deck, SuitStyle};
+///
+/// let deck: Deck = "T♠ A♥".parse().unwrap();
+/// let format = CsvFormat::new()
+///     .columns(&[CsvColumn::Position, CsvColumn::Rank, CsvColumn::Suit, CsvColumn::Color])
+///     .suit_style(SuitStyle::Name);
+///
+/// let csv = format.write_deck(&deck);
+/// assert_eq!(csv, "Position,Rank,Suit,Color\n1,10,Spades,Black\n2,A,Hearts,Red\n");
+/// assert_eq!(format.read_deck(&csv).unwrap(), deck);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvFormat {
+    header: bool,
+    columns: Vec<CsvColumn>,
+    suit_style: SuitStyle,
+    rank_style: RankStyle,
+    crlf: bool,
+}
+
+impl Default for CsvFormat {
+    fn default() -> Self {
+        CsvFormat {
+            header: true,
+            columns: vec![CsvColumn::Rank, CsvColumn::Suit],
+            suit_style: SuitStyle::default(),
+            rank_style: RankStyle::default(),
+            crlf: false,
+        }
+    }
+}
+
+impl CsvFormat {
+    /// Creates the default format: a header, rank and suit columns, ASCII
+    /// suit letters, "10" for ten and LF line endings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether a header row is written.
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Sets the columns to write, and to assume when reading without a header.
+    pub fn columns(mut self, columns: &[CsvColumn]) -> Self {
+        self.columns = columns.to_vec();
+        self
+    }
+
+    /// Uses every column.
+    pub fn all_columns(self) -> Self {
+        self.columns(&CsvColumn::ALL)
+    }
+
+    /// Sets how suits are written.
+    pub fn suit_style(mut self, style: SuitStyle) -> Self {
+        self.suit_style = style;
+        self
+    }
+
+    /// Sets how ranks are written.
+    pub fn rank_style(mut self, style: RankStyle) -> Self {
+        self.rank_style = style;
+        self
+    }
+
+    /// Sets whether lines end in CRLF, as RFC 4180 specifies, instead of LF.
+    pub fn crlf(mut self, crlf: bool) -> Self {
+        self.crlf = crlf;
+        self
+    }
+
+    /// Writes a single deck as a table. The hand column, if present, is "1".
+    pub fn write_deck(&self, deck: &Deck) -> String {
+        let cards: Vec<Card> = deck.iter().copied().collect();
+        self.write_hands(&[("1", &cards)])
+    }
+
+    /// Writes several hands as one table, one row per card.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, CsvColumn, CsvFormat, Deck};
+    ///
+    /// let north: Vec<Card> = "A♠ K♠".parse::<Deck>().unwrap().into_iter().collect();
+    /// let south: Vec<Card> = "2♣".parse::<Deck>().unwrap().into_iter().collect();
+    /// let format = CsvFormat::new().columns(&[CsvColumn::Hand, CsvColumn::Rank, CsvColumn::Suit]);
+    /// let csv = format.write_hands(&[("North", &north), ("South", &south)]);
+    /// assert_eq!(csv, "Hand,Rank,Suit\nNorth,A,S\nNorth,K,S\nSouth,2,C\n");
+    /// ```
+    pub fn write_hands(&self, hands: &[(&str, &[Card])]) -> String {
+        let newline = if self.crlf { "\r\n" } else { "\n" };
+        let mut out = String::new();
+        if self.header {
+            let names: Vec<&str> = self.columns.iter().map(CsvColumn::name).collect();
+            out.push_str(&names.join(","));
+            out.push_str(newline);
+        }
+        for (hand, cards) in hands {
+            for (i, card) in cards.iter().enumerate() {
+                let fields: Vec<String> = self
+                    .columns
+                    .iter()
+                    .map(|column| self.field(*column, hand, i, card))
+                    .collect();
+                out.push_str(&fields.join(","));
+                out.push_str(newline);
+            }
+        }
+        out
+    }
+
+    fn field(&self, column: CsvColumn, hand: &str, index: usize, card: &Card) -> String {
+        match column {
+            CsvColumn::Hand => quote(hand),
+            CsvColumn::Position => (index + 1).to_string(),
+            CsvColumn::Rank => match (self.rank_style, card.rank()) {
+                (RankStyle::Numeric, Rank::Ten) => "10".to_string(),
+                (RankStyle::Name, rank) => format!("{:?}", rank),
+                (_, rank) => rank.to_string(),
+            },
+            CsvColumn::Suit => match self.suit_style {
+                SuitStyle::Letter => format!("{:?}", card.suit())[..1].to_string(),
+                SuitStyle::Name => format!("{:?}", card.suit()),
+                SuitStyle::Symbol => card.suit().to_string(),
+            },
+            CsvColumn::Color => match card.color() {
+                Color::Red => "Red".to_string(),
+                Color::Black => "Black".to_string(),
+            },
+            CsvColumn::Value => u8::from(*card).to_string(),
+        }
+    }
+
+    /// Reads a table as a single deck, in row order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first malformed row, with its line number.
+    pub fn read_deck(&self, csv: &str) -> Result<Deck, CsvError> {
+        Ok(self
+            .read_rows(csv)?
+            .into_iter()
+            .map(|row| row.card)
+            .collect())
+    }
+
+    /// Reads a table with a hand column into hands, in order of first appearance.
+    ///
+    /// Within each hand, cards are ordered by the position column if present,
+    /// otherwise by row order. Rows without a hand column belong to hand "1".
+    ///
+    /// # Errors
+    ///
+    /// Returns the first malformed row, with its line number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::CsvFormat;
+    ///
+    /// let csv = "hand,position,rank,suit\r\n\"East, 1\",2,K,S\r\n\"East, 1\",1,A,S\r\nWest,1,2,C\r\n";
+    /// let hands = CsvFormat::new().read_hands(csv).unwrap();
+    /// assert_eq!(hands[0].0, "East, 1");
+    /// assert_eq!(hands[0].1.to_string(), "A♠ K♠");
+    /// assert_eq!(hands[1].1.to_string(), "2♣");
+    /// ```
+    pub fn read_hands(&self, csv: &str) -> Result<Vec<(String, Deck)>, CsvError> {
+        let mut hands: Vec<(String, Vec<CsvRow>)> = Vec::new();
+        for row in self.read_rows(csv)? {
+            let hand = row.hand.clone().unwrap_or_else(|| "1".to_string());
+            match hands.iter_mut().find(|(id, _)| *id == hand) {
+                Some((_, rows)) => rows.push(row),
+                None => hands.push((hand, vec![row])),
+            }
+        }
+        Ok(hands
+            .into_iter()
+            .map(|(id, mut rows)| {
+                rows.sort_by_key(|row| row.position);
+                (id, rows.into_iter().map(|row| row.card).collect())
+            })
+            .collect())
+    }
+
+    fn read_rows(&self, csv: &str) -> Result<Vec<CsvRow>, CsvError> {
+        let csv = csv.strip_prefix('\u{feff}').unwrap_or(csv);
+        let mut records = parse_records(csv)?.into_iter().peekable();
+        let first_line = records.peek().map_or(1, |(line, _)| *line);
+        let columns: Vec<Option<CsvColumn>> = match records.peek() {
+            Some((_, fields)) if is_header(fields) => {
+                let (_, fields) = records.next().expect("peeked");
+                fields.iter().map(|name| name.parse().ok()).collect()
+            }
+            _ => self.columns.iter().copied().map(Some).collect(),
+        };
+        let find = |column| columns.iter().position(|c| *c == Some(column));
+        let (rank, suit, value) = (
+            find(CsvColumn::Rank),
+            find(CsvColumn::Suit),
+            find(CsvColumn::Value),
+        );
+        let (hand, position) = (find(CsvColumn::Hand), find(CsvColumn::Position));
+        if (rank.is_none() || suit.is_none()) && value.is_none() {
+            return Err(CsvError::new(
+                first_line,
+                "Table needs rank and suit columns or a value column",
+            ));
+        }
+
+        let mut rows = Vec::new();
+        for (line, fields) in records {
+            if fields.len() != columns.len() {
+                return Err(CsvError::new(
+                    line,
+                    format!(
+                        "Expected {} fields but found {}",
+                        columns.len(),
+                        fields.len()
+                    ),
+                ));
+            }
+            let card = match (rank, suit, value) {
+                (Some(rank), Some(suit), _) => {
+                    let rank =
+                        Rank::from_str(fields[rank].trim()).map_err(|e| CsvError::new(line, e))?;
+                    let suit =
+                        Suit::from_str(fields[suit].trim()).map_err(|e| CsvError::new(line, e))?;
+                    Card::new(suit, rank)
+                }
+                (_, _, Some(value)) => fields[value]
+                    .trim()
+                    .parse::<u8>()
+                    .ok()
+                    .and_then(|value| Card::try_from(value).ok())
+                    .ok_or_else(|| {
+                        CsvError::new(line, format!("Invalid card value: {}", fields[value]))
+                    })?,
+                _ => unreachable!("checked above"),
+            };
+            let position = match position {
+                Some(index) => fields[index].trim().parse::<usize>().map_err(|_| {
+                    CsvError::new(line, format!("Invalid position: {}", fields[index]))
+                })?,
+                None => rows.len(),
+            };
+            rows.push(CsvRow {
+                hand: hand.map(|index| fields[index].clone()),
+                position,
+                card,
+            });
+        }
+        Ok(rows)
+    }
+}
+
+/// One parsed card row.
+struct CsvRow {
+    hand: Option<String>,
+    position: usize,
+    card: Card,
+}
+
+/// Returns `true` if any field names a known column.
+fn is_header(fields: &[String]) -> bool {
+    fields
+        .iter()
+        .any(|field| field.parse::<CsvColumn>().is_ok())
+}
+
+/// Quotes a field if it contains a comma, quote or line break.
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Splits RFC 4180 text into records, each with the line it starts on.
+/// Blank lines are skipped.
+fn parse_records(csv: &str) -> Result<Vec<(usize, Vec<String>)>, CsvError> {
+    let mut records = Vec::new();
+    let mut chars = csv.chars().peekable();
+    let mut line = 1;
+    while chars.peek().is_some() {
+        let start = line;
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        loop {
+            match chars.next() {
+                None => {
+                    if quoted {
+                        return Err(CsvError::new(start, "Unterminated quoted field"));
+                    }
+                    break;
+                }
+                Some('"') if quoted => {
+                    if chars.peek() == Some(&'"') {
+                        chars.next();
+                        field.push('"');
+                    } else {
+                        quoted = false;
+                    }
+                }
+                Some('"') if field.is_empty() => quoted = true,
+                Some(',') if !quoted => fields.push(std::mem::take(&mut field)),
+                Some('\r') if !quoted && chars.peek() == Some(&'\n') => {}
+                Some('\n') if !quoted => {
+                    line += 1;
+                    break;
+                }
+                Some(c) => {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            }
+        }
+        fields.push(field);
+        let blank = fields.len() == 1 && fields[0].trim().is_empty();
+        if !blank {
+            records.push((start, fields));
+        }
+    }
+    Ok(records)
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::binary::crc32;
+use crate::Deck;
+
+/// The envelope format version written by [`DeckEnvelope`].
+pub const ENVELOPE_VERSION: u32 = 1;
+
+/// A deck wrapped with metadata describing and protecting it.
+///
+/// The envelope serializes as a JSON object whose `deck` field is exactly
+/// what [`Deck::to_json`] writes:
+///
+/// ```json
+/// {
+///   "format_version": 1,
+///   "factory": "standard52",
+///   "seed": 42,
+///   "created_at": "2024-05-01T20:00:00Z",
+///   "card_count": 52,
+///   "checksum": "8f2a0c1d",
+///   "deck": { "cards": [ { "suit": "Hearts", "rank": "Two" }, ... ] }
+/// }
+/// ```
+///
+/// `factory`, `seed` and `created_at` are optional and omitted when unset.
+/// `checksum` is the CRC-32 (IEEE) of the cards' byte encoding
+/// (`suit * 14 + rank`, see [`Card`](crate::Card)) in deck order, written as
+/// eight lowercase hex digits, so other languages can verify it without
+/// reproducing this crate's JSON formatting.
+///
+/// [`Deck::from_json`] accepts envelopes as well as plain decks and rejects
+/// envelopes that fail [`DeckEnvelope::validate`]. The JSON Schema is
+/// [`deck_envelope_schema`](crate::deck_envelope_schema).
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, DeckEnvelope, Standard52};
+///
+/// let deck = Deck::from_factory(Standard52);
+/// let json = DeckEnvelope::new(&deck)
+///     .with_factory("standard52")
+///     .with_seed(42)
+///     .to_json()
+///     .unwrap();
+/// assert!(json.contains("\"card_count\":52"));
+/// assert_eq!(Deck::from_json(&json).unwrap(), deck);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckEnvelope {
+    format_version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    factory: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<String>,
+    card_count: usize,
+    checksum: String,
+    deck: Deck,
+}
+
+impl DeckEnvelope {
+    /// Wraps a copy of `deck`, filling in the version, card count and checksum.
+    pub fn new(deck: &Deck) -> Self {
+        DeckEnvelope {
+            format_version: ENVELOPE_VERSION,
+            factory: None,
+            seed: None,
+            created_at: None,
+            card_count: deck.len(),
+            checksum: checksum(deck),
+            deck: deck.clone(),
+        }
+    }
+
+    /// Records the name of the factory that generated the deck.
+    pub fn with_factory(mut self, factory: &str) -> Self {
+        self.factory = Some(factory.to_string());
+        self
+    }
+
+    /// Records the seed used to shuffle the deck.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Records when the deck was created, preferably as an RFC 3339 timestamp.
+    pub fn with_created_at(mut self, created_at: &str) -> Self {
+        self.created_at = Some(created_at.to_string());
+        self
+    }
+
+    /// Returns the envelope format version.
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    /// Returns the factory name, if recorded.
+    pub fn factory(&self) -> Option<&str> {
+        self.factory.as_deref()
+    }
+
+    /// Returns the shuffle seed, if recorded.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Returns the creation time, if recorded.
+    pub fn created_at(&self) -> Option<&str> {
+        self.created_at.as_deref()
+    }
+
+    /// Returns the recorded card count.
+    pub fn card_count(&self) -> usize {
+        self.card_count
+    }
+
+    /// Returns the recorded checksum.
+    pub fn checksum(&self) -> &str {
+        &self.checksum
+    }
+
+    /// Returns the wrapped deck.
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+
+    /// Returns the wrapped deck, dropping the metadata.
+    pub fn into_deck(self) -> Deck {
+        self.deck
+    }
+
+    /// Checks the version, card count and checksum against the wrapped deck.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format version is not supported or the card
+    /// count or checksum does not match the deck.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.format_version != ENVELOPE_VERSION {
+            return Err(format!(
+                "Unsupported deck envelope version {}",
+                self.format_version
+            ));
+        }
+        if self.card_count != self.deck.len() {
+            return Err(format!(
+                "Card count mismatch: envelope says {} but deck has {}",
+                self.card_count,
+                self.deck.len()
+            ));
+        }
+        let actual = checksum(&self.deck);
+        if !self.checksum.eq_ignore_ascii_case(&actual) {
+            return Err(format!(
+                "Checksum mismatch: envelope says {} but deck hashes to {}",
+                self.checksum, actual
+            ));
+        }
+        Ok(())
+    }
+
+    /// Serializes the envelope to a JSON string.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Serializes the envelope to a pretty-printed JSON string.
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Parses and validates an envelope from a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON does not have the envelope's shape or
+    /// fails [`DeckEnvelope::validate`].
+    pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+        Self::from_value(serde_json::from_str(s)?)
+    }
+
+    /// Parses and validates an envelope from an already parsed JSON value.
+    pub(crate) fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        let envelope: DeckEnvelope = serde_json::from_value(value)?;
+        envelope
+            .validate()
+            .map_err(<serde_json::Error as serde::de::Error>::custom)?;
+        Ok(envelope)
+    }
+}
+
+fn checksum(deck: &Deck) -> String {
+    let bytes: Vec<u8> = deck.iter().map(|card| u8::from(*card)).collect();
+    format!("{:08x}", crc32(&bytes))
+}
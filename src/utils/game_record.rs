@@ -0,0 +1,464 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::tags::{escape, parse_tag};
+use crate::{Card, Deck};
+
+/// The zone that holds the initial deck when a [`GameRecord`] is replayed.
+pub const DECK_ZONE: &str = "deck";
+
+/// What happened in a [`Move`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MoveKind {
+    /// Cards moved from one zone to another, such as `deck` to `hand:alice`.
+    Transfer {
+        cards: Vec<Card>,
+        from: String,
+        to: String,
+    },
+    /// A player action that moves no cards, such as a bid or a pass.
+    Action { name: String, args: Vec<String> },
+}
+
+/// One entry in a [`GameRecord`]'s move list.
+///
+/// Written as `[player: ]<cards> <from> -> <to>` for transfers and
+/// `[player: ]<name> <args>` for actions. Zone names, action names and
+/// arguments must be single words. A transfer of no cards is written as
+/// `from -> to` and reads back the same way.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, Move, Rank, Suit};
+///
+/// let play = Move::transfer(&[Card::new(Suit::Spades, Rank::Seven)], "hand:bob", "pile").by("bob");
+/// assert_eq!(play.to_string(), "bob: 7♠ hand:bob -> pile");
+/// assert_eq!("bob: 7♠ hand:bob -> pile".parse::<Move>().unwrap(), play);
+///
+/// let bid = Move::action("bid", &["3", "hearts"]).by("alice");
+/// assert_eq!(bid.to_string(), "alice: bid 3 hearts");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Move {
+    player: Option<String>,
+    kind: MoveKind,
+}
+
+impl Move {
+    /// Creates a move of `cards` from one zone to another.
+    pub fn transfer(cards: &[Card], from: &str, to: &str) -> Self {
+        Move {
+            player: None,
+            kind: MoveKind::Transfer {
+                cards: cards.to_vec(),
+                from: from.to_string(),
+                to: to.to_string(),
+            },
+        }
+    }
+
+    /// Creates a player action with arguments.
+    pub fn action(name: &str, args: &[&str]) -> Self {
+        Move {
+            player: None,
+            kind: MoveKind::Action {
+                name: name.to_string(),
+                args: args.iter().map(|arg| arg.to_string()).collect(),
+            },
+        }
+    }
+
+    /// Attributes the move to `player`.
+    pub fn by(mut self, player: &str) -> Self {
+        self.player = Some(player.to_string());
+        self
+    }
+
+    /// Returns the player who made the move, if any.
+    pub fn player(&self) -> Option<&str> {
+        self.player.as_deref()
+    }
+
+    /// Returns what happened.
+    pub fn kind(&self) -> &MoveKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(player) = &self.player {
+            write!(f, "{}: ", player)?;
+        }
+        match &self.kind {
+            MoveKind::Transfer { cards, from, to } => {
+                for card in cards {
+                    write!(f, "{} ", card)?;
+                }
+                write!(f, "{} -> {}", from, to)
+            }
+            MoveKind::Action { name, args } => {
+                write!(f, "{}", name)?;
+                for arg in args {
+                    write!(f, " {}", arg)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for Move {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (player, body) = match s.split_once(": ") {
+            Some((player, body)) => (Some(player.trim()), body.trim()),
+            None => (None, s),
+        };
+        let kind = match body.split_once(" -> ") {
+            Some((source, to)) => {
+                let mut words: Vec<&str> = source.split_whitespace().collect();
+                let to: Vec<&str> = to.split_whitespace().collect();
+                let from = words.pop();
+                match (from, to.as_slice()) {
+                    (Some(from), [to]) => MoveKind::Transfer {
+                        cards: words
+                            .into_iter()
+                            .map(Card::from_str)
+                            .collect::<Result<_, _>>()?,
+                        from: from.to_string(),
+                        to: to.to_string(),
+                    },
+                    _ => return Err(format!("Invalid card move: {}", body)),
+                }
+            }
+            None => {
+                let mut words = body.split_whitespace();
+                let name = words.next().ok_or_else(|| format!("Empty move: {}", s))?;
+                MoveKind::Action {
+                    name: name.to_string(),
+                    args: words.map(str::to_string).collect(),
+                }
+            }
+        };
+        Ok(Move {
+            player: player.map(str::to_string),
+            kind,
+        })
+    }
+}
+
+/// A text record of a card game: header tags, the initial deck order and a
+/// numbered move list.
+///
+/// The format is line based and meant to be diffed and archived:
+///
+/// ```text
+/// [Game "Crazy Eights"]
+/// [Players "alice, bob"]
+/// [Seed "42"]
+/// [Factory "standard52"]
+///
+/// Deck: 7♠ 5♥ 8♣ 2♦
+///
+/// 1. alice: 7♠ deck -> hand:alice
+/// 2. bob: 5♥ deck -> hand:bob
+/// 3. 8♣ deck -> pile
+/// 4. alice: pass
+/// ```
+///
+/// `Game`, `Players`, `Date`, `Seed` and `Factory` are typed; any other tag
+/// is kept as text in file order. Players are separated by commas. Cards are
+/// written with [`Card`]'s `Display` and read with its `FromStr`. `;` starts
+/// a comment that runs to the end of the line. Move numbers are optional when
+/// reading but must count up from 1 when present.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, Deck, GameRecord, Move, Rank, Standard52, Suit};
+///
+/// let deck = Deck::from_factory(Standard52);
+/// let mut record = GameRecord::new(deck.clone())
+///     .with_game("War")
+///     .with_players(&["alice", "bob"])
+///     .with_factory("standard52");
+/// let top = Card::new(Suit::Hearts, Rank::Two);
+/// record.push_move(Move::transfer(&[top], "deck", "hand:alice").by("alice"));
+///
+/// let text = record.to_string();
+/// let parsed: GameRecord = text.parse().unwrap();
+/// assert_eq!(parsed, record);
+/// assert_eq!(parsed.zones().unwrap()["hand:alice"], vec![top]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GameRecord {
+    game: Option<String>,
+    players: Vec<String>,
+    date: Option<String>,
+    seed: Option<u64>,
+    factory: Option<String>,
+    tags: Vec<(String, String)>,
+    deck: Deck,
+    moves: Vec<Move>,
+}
+
+impl GameRecord {
+    /// Creates a record starting from `deck`, with no tags or moves.
+    pub fn new(deck: Deck) -> Self {
+        GameRecord {
+            deck,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the game name.
+    pub fn with_game(mut self, game: &str) -> Self {
+        self.game = Some(game.to_string());
+        self
+    }
+
+    /// Sets the players, in seat order.
+    pub fn with_players(mut self, players: &[&str]) -> Self {
+        self.players = players.iter().map(|player| player.to_string()).collect();
+        self
+    }
+
+    /// Sets the date, in whatever format the game uses.
+    pub fn with_date(mut self, date: &str) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    /// Sets the seed used to shuffle the initial deck.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the identifier of the factory that generated the initial deck.
+    pub fn with_factory(mut self, factory: &str) -> Self {
+        self.factory = Some(factory.to_string());
+        self
+    }
+
+    /// Sets an additional tag, replacing any earlier value for `name`.
+    pub fn with_tag(mut self, name: &str, value: &str) -> Self {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+        self
+    }
+
+    /// Appends a move.
+    pub fn push_move(&mut self, m: Move) {
+        self.moves.push(m);
+    }
+
+    /// Returns the game name.
+    pub fn game(&self) -> Option<&str> {
+        self.game.as_deref()
+    }
+
+    /// Returns the players in seat order.
+    pub fn players(&self) -> &[String] {
+        &self.players
+    }
+
+    /// Returns the date.
+    pub fn date(&self) -> Option<&str> {
+        self.date.as_deref()
+    }
+
+    /// Returns the shuffle seed.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Returns the factory identifier.
+    pub fn factory(&self) -> Option<&str> {
+        self.factory.as_deref()
+    }
+
+    /// Returns the value of an additional tag.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the additional tags in file order.
+    pub fn tags(&self) -> &[(String, String)] {
+        &self.tags
+    }
+
+    /// Returns the initial deck order.
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+
+    /// Returns the moves in order.
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Replays every transfer and returns the cards in each zone afterwards.
+    ///
+    /// Replay starts with the initial deck in [`DECK_ZONE`] and every other
+    /// zone empty. Cards are appended to the zone they move to. Zones that
+    /// end up empty are still listed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the move if a card is not in the zone it
+    /// moves from.
+    pub fn zones(&self) -> Result<BTreeMap<String, Vec<Card>>, String> {
+        let mut zones = BTreeMap::new();
+        zones.insert(DECK_ZONE.to_string(), self.deck.iter().copied().collect());
+        for (i, m) in self.moves.iter().enumerate() {
+            let MoveKind::Transfer { cards, from, to } = &m.kind else {
+                continue;
+            };
+            let source: &mut Vec<Card> = zones.entry(from.clone()).or_default();
+            for card in cards {
+                let position = source.iter().position(|c| c == card).ok_or_else(|| {
+                    format!("Move {}: card {} is not in zone {}", i + 1, card, from)
+                })?;
+                source.remove(position);
+            }
+            zones.entry(to.clone()).or_default().extend(cards);
+        }
+        Ok(zones)
+    }
+
+    fn set_tag(&mut self, name: String, value: String) -> Result<(), String> {
+        match name.as_str() {
+            "Game" => self.game = Some(value),
+            "Players" => {
+                self.players = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|player| !player.is_empty())
+                    .map(str::to_string)
+                    .collect();
+            }
+            "Date" => self.date = Some(value),
+            "Seed" => {
+                self.seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid seed: {}", value))?,
+                );
+            }
+            "Factory" => self.factory = Some(value),
+            _ => *self = std::mem::take(self).with_tag(&name, &value),
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let players = self.players.join(", ");
+        let seed = self.seed.map(|seed| seed.to_string());
+        let typed = [
+            ("Game", self.game.as_deref()),
+            ("Players", (!players.is_empty()).then_some(players.as_str())),
+            ("Date", self.date.as_deref()),
+            ("Seed", seed.as_deref()),
+            ("Factory", self.factory.as_deref()),
+        ];
+        let typed = typed
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)));
+        let extra = self
+            .tags
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()));
+        let mut any_tags = false;
+        for (name, value) in typed.chain(extra) {
+            writeln!(f, "[{} \"{}\"]", name, escape(value))?;
+            any_tags = true;
+        }
+        if any_tags {
+            writeln!(f)?;
+        }
+        if self.deck.is_empty() {
+            writeln!(f, "Deck:")?;
+        } else {
+            writeln!(f, "Deck: {}", self.deck)?;
+        }
+        if !self.moves.is_empty() {
+            writeln!(f)?;
+        }
+        for (i, m) in self.moves.iter().enumerate() {
+            writeln!(f, "{}. {}", i + 1, m)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('\u{feff}').unwrap_or(s);
+        let mut record = GameRecord::default();
+        let mut seen_deck = false;
+        for (i, line) in s.lines().enumerate() {
+            let error = |message: String| format!("Failed to parse line {}: {}", i + 1, message);
+            let mut line = line.trim();
+            if !line.starts_with('[') {
+                line = line.split(';').next().unwrap_or_default().trim_end();
+            }
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            if line.starts_with('[') {
+                if seen_deck {
+                    return Err(error("Tags must come before the deck".to_string()));
+                }
+                let (name, value, _) = parse_tag(line).map_err(error)?;
+                record.set_tag(name, value).map_err(error)?;
+            } else if let Some(cards) = line.strip_prefix("Deck:") {
+                if seen_deck {
+                    return Err(error("Duplicate deck line".to_string()));
+                }
+                record.deck = cards
+                    .split_whitespace()
+                    .map(Card::from_str)
+                    .collect::<Result<Deck, _>>()
+                    .map_err(error)?;
+                seen_deck = true;
+            } else {
+                if !seen_deck {
+                    return Err(error("Moves must come after the deck".to_string()));
+                }
+                let expected = record.moves.len() + 1;
+                let body = match line.split_once(". ") {
+                    Some((number, body)) if number.chars().all(|c| c.is_ascii_digit()) => {
+                        if number.parse() != Ok(expected) {
+                            return Err(error(format!(
+                                "Expected move {} but found {}",
+                                expected, number
+                            )));
+                        }
+                        body
+                    }
+                    _ => line,
+                };
+                record.push_move(body.parse().map_err(error)?);
+            }
+        }
+        if !seen_deck {
+            return Err("Game record has no deck line".to_string());
+        }
+        Ok(record)
+    }
+}
@@ -0,0 +1,645 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Card;
+
+/// A betting round of a hold'em style hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+    Showdown,
+}
+
+impl fmt::Display for Street {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Street::Preflop => "Preflop",
+            Street::Flop => "Flop",
+            Street::Turn => "Turn",
+            Street::River => "River",
+            Street::Showdown => "Showdown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A seated player and the stack they started the hand with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Seat {
+    number: u32,
+    player: String,
+    stack: u64,
+    sitting_out: bool,
+}
+
+impl Seat {
+    /// Returns the seat number.
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Returns the player's name.
+    pub fn player(&self) -> &str {
+        &self.player
+    }
+
+    /// Returns the starting stack, in hundredths (see [`HandHistory`]).
+    pub fn stack(&self) -> u64 {
+        self.stack
+    }
+
+    /// Returns true if the player was sitting out.
+    pub fn is_sitting_out(&self) -> bool {
+        self.sitting_out
+    }
+}
+
+/// What a player did.
+///
+/// Amounts are in hundredths (see [`HandHistory`]).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ActionKind {
+    SmallBlind(u64),
+    BigBlind(u64),
+    Ante(u64),
+    /// Any other forced post, such as a dead blind.
+    Post(u64),
+    Fold,
+    Check,
+    Call(u64),
+    Bet(u64),
+    /// A raise by `by`, to a total of `to`.
+    Raise {
+        by: u64,
+        to: u64,
+    },
+    /// An uncalled bet returned to the player.
+    Return(u64),
+    Show(Vec<Card>),
+    Muck,
+    /// Chips won from a pot.
+    Collect(u64),
+}
+
+/// A single player action on a street.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Action {
+    street: Street,
+    player: String,
+    kind: ActionKind,
+    all_in: bool,
+}
+
+impl Action {
+    /// Returns the street the action was taken on.
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    /// Returns the acting player's name.
+    pub fn player(&self) -> &str {
+        &self.player
+    }
+
+    /// Returns what the player did.
+    pub fn kind(&self) -> &ActionKind {
+        &self.kind
+    }
+
+    /// Returns true if the action put the player all-in.
+    pub fn is_all_in(&self) -> bool {
+        self.all_in
+    }
+}
+
+/// A hand parsed from a PokerStars-style text hand history.
+///
+/// This is the layout most online rooms export: a header line with the hand
+/// number, game and stakes, a table line, seat lines, then `*** HOLE CARDS ***`,
+/// `*** FLOP ***`, `*** TURN ***`, `*** RIVER ***`, `*** SHOW DOWN ***` and
+/// `*** SUMMARY ***` sections. Cards are read with [`Card`]'s `FromStr`, so
+/// `Ah` and `Td` both work.
+///
+/// All amounts are stored as integers in hundredths of the unit shown in the
+/// history: `$0.25` is `25`, and a tournament stack of `1500` chips is `150000`.
+/// Lines the parser does not recognise, such as chat, are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{ActionKind, Card, HandHistory, Street};
+///
+/// let text = "\
+/// PokerStars Hand #1001: Hold'em No Limit ($0.01/$0.02 USD) - 2024/05/01 20:00:00 ET
+/// Table 'Alpha' 6-max Seat #1 is the button
+/// Seat 1: alice ($2.00 in chips)
+/// Seat 2: bob ($1.50 in chips)
+/// alice: posts small blind $0.01
+/// bob: posts big blind $0.02
+/// *** HOLE CARDS ***
+/// Dealt to alice [Ah Kd]
+/// alice: raises $0.04 to $0.06
+/// bob: calls $0.04
+/// *** FLOP *** [2c 7h Td]
+/// bob: checks
+/// alice: bets $0.08
+/// bob: folds
+/// Uncalled bet ($0.08) returned to alice
+/// alice collected $0.12 from pot
+/// *** SUMMARY ***
+/// Total pot $0.12 | Rake $0
+/// Board [2c 7h Td]
+/// ";
+/// let hand: HandHistory = text.parse().unwrap();
+/// assert_eq!(hand.hand_id(), "1001");
+/// assert_eq!(hand.big_blind(), Some(2));
+/// assert_eq!(hand.hole_cards("alice").unwrap()[0], "Ah".parse::<Card>().unwrap());
+/// assert_eq!(hand.board().len(), 3);
+/// assert_eq!(hand.actions()[2].kind(), &ActionKind::Raise { by: 4, to: 6 });
+/// assert_eq!(hand.actions_on(Street::Flop).count(), 5);
+/// assert_eq!(hand.winnings("alice"), 12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HandHistory {
+    site: String,
+    hand_id: String,
+    game: String,
+    small_blind: Option<u64>,
+    big_blind: Option<u64>,
+    table: Option<String>,
+    max_seats: Option<u32>,
+    button: Option<u32>,
+    seats: Vec<Seat>,
+    dealt: Vec<(String, Vec<Card>)>,
+    board: Vec<Card>,
+    actions: Vec<Action>,
+    total_pot: Option<u64>,
+    pots: Vec<u64>,
+    rake: Option<u64>,
+}
+
+impl HandHistory {
+    /// Returns the site name from the header, e.g. "PokerStars".
+    pub fn site(&self) -> &str {
+        &self.site
+    }
+
+    /// Returns the hand number.
+    pub fn hand_id(&self) -> &str {
+        &self.hand_id
+    }
+
+    /// Returns the game description, e.g. "Hold'em No Limit".
+    pub fn game(&self) -> &str {
+        &self.game
+    }
+
+    /// Returns the small blind from the header stakes.
+    pub fn small_blind(&self) -> Option<u64> {
+        self.small_blind
+    }
+
+    /// Returns the big blind from the header stakes.
+    pub fn big_blind(&self) -> Option<u64> {
+        self.big_blind
+    }
+
+    /// Returns the table name.
+    pub fn table(&self) -> Option<&str> {
+        self.table.as_deref()
+    }
+
+    /// Returns the table size, e.g. 6 for a "6-max" table.
+    pub fn max_seats(&self) -> Option<u32> {
+        self.max_seats
+    }
+
+    /// Returns the button's seat number.
+    pub fn button(&self) -> Option<u32> {
+        self.button
+    }
+
+    /// Returns the seats in the order listed.
+    pub fn seats(&self) -> &[Seat] {
+        &self.seats
+    }
+
+    /// Returns the seat of `player`.
+    pub fn seat(&self, player: &str) -> Option<&Seat> {
+        self.seats.iter().find(|seat| seat.player == player)
+    }
+
+    /// Returns the hole cards of `player`, if they were dealt face up to the
+    /// history's owner or shown down.
+    pub fn hole_cards(&self, player: &str) -> Option<&[Card]> {
+        self.dealt
+            .iter()
+            .find(|(name, _)| name == player)
+            .map(|(_, cards)| cards.as_slice())
+            .or_else(|| {
+                self.actions.iter().find_map(|action| match &action.kind {
+                    ActionKind::Show(cards) if action.player == player => Some(cards.as_slice()),
+                    _ => None,
+                })
+            })
+    }
+
+    /// Returns the board cards, in the order they were dealt.
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    /// Returns every action in the order it happened.
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// Returns the actions taken on `street`.
+    pub fn actions_on(&self, street: Street) -> impl Iterator<Item = &Action> {
+        self.actions
+            .iter()
+            .filter(move |action| action.street == street)
+    }
+
+    /// Returns the players who showed cards and the cards they showed.
+    pub fn showdown(&self) -> Vec<(&str, &[Card])> {
+        self.actions
+            .iter()
+            .filter_map(|action| match &action.kind {
+                ActionKind::Show(cards) => Some((action.player.as_str(), cards.as_slice())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the total pot from the summary.
+    pub fn total_pot(&self) -> Option<u64> {
+        self.total_pot
+    }
+
+    /// Returns the main pot followed by any side pots, from the summary.
+    ///
+    /// A hand without side pots has a single pot equal to the total pot.
+    pub fn pots(&self) -> &[u64] {
+        &self.pots
+    }
+
+    /// Returns the rake from the summary.
+    pub fn rake(&self) -> Option<u64> {
+        self.rake
+    }
+
+    /// Returns the total `player` collected from all pots.
+    pub fn winnings(&self, player: &str) -> u64 {
+        self.actions
+            .iter()
+            .filter(|action| action.player == player)
+            .map(|action| match action.kind {
+                ActionKind::Collect(amount) => amount,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    fn parse_lines(lines: &[(usize, &str)]) -> Result<Self, String> {
+        let mut lines = lines.iter();
+        let &(number, header) = lines
+            .next()
+            .ok_or_else(|| "Hand history is empty".to_string())?;
+        let mut hand = parse_header(header).map_err(|e| line_error(number, &e))?;
+        let mut street = Street::Preflop;
+        let mut in_summary = false;
+        for &(number, line) in lines {
+            hand.parse_line(line, &mut street, &mut in_summary)
+                .map_err(|e| line_error(number, &e))?;
+        }
+        if hand.pots.is_empty() {
+            hand.pots.extend(hand.total_pot);
+        }
+        Ok(hand)
+    }
+
+    fn parse_line(
+        &mut self,
+        line: &str,
+        street: &mut Street,
+        in_summary: &mut bool,
+    ) -> Result<(), String> {
+        if let Some(section) = line.strip_prefix("*** ") {
+            let (name, cards) = section.split_once(" ***").unwrap_or((section, ""));
+            match name {
+                "HOLE CARDS" => *street = Street::Preflop,
+                "FLOP" | "TURN" | "RIVER" => {
+                    *street = match name {
+                        "FLOP" => Street::Flop,
+                        "TURN" => Street::Turn,
+                        _ => Street::River,
+                    };
+                    if let Some(group) = bracket_groups(cards).last() {
+                        self.board.extend(parse_cards(group)?);
+                    }
+                }
+                "SHOW DOWN" => *street = Street::Showdown,
+                "SUMMARY" => *in_summary = true,
+                _ => {}
+            }
+            return Ok(());
+        }
+        if *in_summary {
+            return self.parse_summary_line(line);
+        }
+        if let Some(rest) = line.strip_prefix("Table '") {
+            return self.parse_table_line(rest);
+        }
+        if line.starts_with("Seat ") {
+            self.seats.extend(parse_seat(line)?);
+            return Ok(());
+        }
+        if let Some(rest) = line.strip_prefix("Dealt to ") {
+            if let Some((player, cards)) = rest.split_once(" [") {
+                let cards = cards.split(']').next().unwrap_or_default();
+                self.dealt.push((player.to_string(), parse_cards(cards)?));
+            }
+            return Ok(());
+        }
+        if let Some(rest) = line.strip_prefix("Uncalled bet (") {
+            if let Some((amount, player)) = rest.split_once(") returned to ") {
+                self.push_action(*street, player, ActionKind::Return(parse_amount(amount)?));
+            }
+            return Ok(());
+        }
+        if let Some(player) = self.player_prefix(line, " collected ") {
+            let rest = &line[player.len() + " collected ".len()..];
+            let amount = rest.split_whitespace().next().unwrap_or_default();
+            self.push_action(*street, &player, ActionKind::Collect(parse_amount(amount)?));
+            return Ok(());
+        }
+        if let Some(player) = self.player_prefix(line, ": ") {
+            let rest = &line[player.len() + 2..];
+            if let Some((kind, all_in)) = parse_action(rest)? {
+                self.actions.push(Action {
+                    street: *street,
+                    player,
+                    kind,
+                    all_in,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_table_line(&mut self, rest: &str) -> Result<(), String> {
+        let (name, rest) = rest
+            .split_once('\'')
+            .ok_or_else(|| "Unterminated table name".to_string())?;
+        self.table = Some(name.to_string());
+        for word in rest.split_whitespace() {
+            if let Some(size) = word.strip_suffix("-max") {
+                self.max_seats = size.parse().ok();
+            }
+            if let Some(seat) = word.strip_prefix('#') {
+                self.button = Some(
+                    seat.parse()
+                        .map_err(|_| format!("Invalid button seat: {}", seat))?,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_summary_line(&mut self, line: &str) -> Result<(), String> {
+        if let Some(rest) = line.strip_prefix("Total pot ") {
+            for part in rest.split('|') {
+                let part = part.trim();
+                if let Some(rake) = part.strip_prefix("Rake ") {
+                    self.rake = Some(parse_amount(rake)?);
+                    continue;
+                }
+                let words: Vec<&str> = part.split_whitespace().collect();
+                if let Some(total) = words.first() {
+                    self.total_pot = Some(parse_amount(total)?);
+                }
+                for pair in words.windows(3) {
+                    if pair[1].starts_with("pot") && (pair[0] == "Main" || pair[0] == "Side") {
+                        self.pots.push(parse_amount(pair[2].trim_end_matches('.'))?);
+                    }
+                }
+            }
+        } else if let Some(rest) = line.strip_prefix("Board ") {
+            if self.board.is_empty() {
+                if let Some(group) = bracket_groups(rest).first() {
+                    self.board = parse_cards(group)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the longest seated player name that `line` starts with,
+    /// followed by `separator`.
+    fn player_prefix(&self, line: &str, separator: &str) -> Option<String> {
+        self.seats
+            .iter()
+            .map(|seat| seat.player.as_str())
+            .filter(|player| {
+                line.strip_prefix(player)
+                    .is_some_and(|rest| rest.starts_with(separator))
+            })
+            .max_by_key(|player| player.len())
+            .map(str::to_string)
+    }
+
+    fn push_action(&mut self, street: Street, player: &str, kind: ActionKind) {
+        self.actions.push(Action {
+            street,
+            player: player.to_string(),
+            kind,
+            all_in: false,
+        });
+    }
+}
+
+impl FromStr for HandHistory {
+    type Err = String;
+
+    /// Parses a single hand. Fails if the input holds more than one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut hands = read_hand_histories(s)?;
+        match hands.len() {
+            1 => Ok(hands.remove(0)),
+            n => Err(format!("Expected 1 hand but found {}", n)),
+        }
+    }
+}
+
+/// Reads every hand in a hand-history file.
+///
+/// Each hand starts at a header line containing `Hand #`; blank lines between
+/// hands are ignored.
+///
+/// # Errors
+///
+/// Returns an error naming the line if text appears before the first header,
+/// or if a header, seat, amount or card cannot be parsed.
+pub fn read_hand_histories(input: &str) -> Result<Vec<HandHistory>, String> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut blocks: Vec<Vec<(usize, &str)>> = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.contains(" Hand #") {
+            blocks.push(Vec::new());
+        }
+        match blocks.last_mut() {
+            Some(block) => block.push((i + 1, line)),
+            None => return Err(line_error(i + 1, "Expected a hand header")),
+        }
+    }
+    blocks
+        .iter()
+        .map(|block| HandHistory::parse_lines(block))
+        .collect()
+}
+
+/// Parses "Site Hand #ID: Game (SB/BB ...) - date".
+fn parse_header(line: &str) -> Result<HandHistory, String> {
+    let (site, rest) = line
+        .split_once(" Hand #")
+        .ok_or_else(|| format!("Invalid hand header: {}", line))?;
+    let (hand_id, rest) = rest
+        .split_once(':')
+        .ok_or_else(|| format!("Invalid hand header: {}", line))?;
+    let description = rest.split(" - ").next().unwrap_or_default().trim();
+    let game = description
+        .split(" (")
+        .next()
+        .unwrap_or_default()
+        .to_string();
+
+    let mut hand = HandHistory {
+        site: site.trim().to_string(),
+        hand_id: hand_id.trim().to_string(),
+        game,
+        ..Default::default()
+    };
+    // Stakes are the first "(small/big ...)" group, which also covers
+    // tournament levels such as "Level I (10/20)".
+    for group in rest.split('(').skip(1) {
+        let group = group.split(')').next().unwrap_or_default();
+        let stakes = group.split_whitespace().next().unwrap_or_default();
+        if let Some((small, big)) = stakes.split_once('/') {
+            if let (Ok(small), Ok(big)) = (parse_amount(small), parse_amount(big)) {
+                hand.small_blind = Some(small);
+                hand.big_blind = Some(big);
+                break;
+            }
+        }
+    }
+    Ok(hand)
+}
+
+/// Parses "Seat N: name (stack in chips)", returning `None` for summary-style
+/// seat lines that carry no stack.
+fn parse_seat(line: &str) -> Result<Option<Seat>, String> {
+    let Some(rest) = line.strip_prefix("Seat ") else {
+        return Ok(None);
+    };
+    let Some((number, rest)) = rest.split_once(": ") else {
+        return Ok(None);
+    };
+    let Some(open) = rest.rfind(" (") else {
+        return Ok(None);
+    };
+    let (player, stack) = (&rest[..open], &rest[open + 2..]);
+    let Some((stack, after)) = stack.split_once(" in chips") else {
+        return Ok(None);
+    };
+    Ok(Some(Seat {
+        number: number
+            .parse()
+            .map_err(|_| format!("Invalid seat number: {}", number))?,
+        player: player.to_string(),
+        stack: parse_amount(stack)?,
+        sitting_out: after.contains("sitting out"),
+    }))
+}
+
+/// Parses the text after "name: ". Returns `None` for lines that are not
+/// actions, such as "doesn't show hand".
+fn parse_action(rest: &str) -> Result<Option<(ActionKind, bool)>, String> {
+    let all_in = rest.ends_with("and is all-in");
+    let rest = rest.trim_end_matches("and is all-in").trim_end();
+    let words: Vec<&str> = rest.split_whitespace().collect();
+    let amount = |i: usize| -> Result<u64, String> {
+        parse_amount(words.get(i).copied().unwrap_or_default())
+    };
+    let kind = match words.as_slice() {
+        ["posts", "small", "blind", ..] => ActionKind::SmallBlind(amount(3)?),
+        ["posts", "big", "blind", ..] => ActionKind::BigBlind(amount(3)?),
+        ["posts", "the", "ante", ..] => ActionKind::Ante(amount(3)?),
+        ["posts", .., last] => ActionKind::Post(parse_amount(last)?),
+        ["folds", ..] => ActionKind::Fold,
+        ["checks", ..] => ActionKind::Check,
+        ["calls", ..] => ActionKind::Call(amount(1)?),
+        ["bets", ..] => ActionKind::Bet(amount(1)?),
+        ["raises", _, "to", ..] => ActionKind::Raise {
+            by: amount(1)?,
+            to: amount(3)?,
+        },
+        ["shows", ..] => {
+            let cards = bracket_groups(rest)
+                .first()
+                .copied()
+                .ok_or_else(|| format!("Missing cards: {}", rest))?;
+            ActionKind::Show(parse_cards(cards)?)
+        }
+        ["mucks", ..] => ActionKind::Muck,
+        _ => return Ok(None),
+    };
+    Ok(Some((kind, all_in)))
+}
+
+/// Returns the contents of every `[...]` group in `s`.
+fn bracket_groups(s: &str) -> Vec<&str> {
+    s.split('[')
+        .skip(1)
+        .filter_map(|group| group.split_once(']').map(|(inside, _)| inside))
+        .collect()
+}
+
+fn parse_cards(s: &str) -> Result<Vec<Card>, String> {
+    s.split_whitespace().map(Card::from_str).collect()
+}
+
+/// Parses an amount such as "$1,250.5" or "1500" into hundredths.
+fn parse_amount(s: &str) -> Result<u64, String> {
+    let error = || format!("Invalid amount: {}", s);
+    let digits: String = s
+        .trim_start_matches(|c: char| !c.is_ascii_digit())
+        .chars()
+        .filter(|c| *c != ',')
+        .collect();
+    let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+    if whole.is_empty() || fraction.len() > 2 {
+        return Err(error());
+    }
+    let whole: u64 = whole.parse().map_err(|_| error())?;
+    let fraction: u64 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{:0<2}", fraction).parse().map_err(|_| error())?
+    };
+    whole
+        .checked_mul(100)
+        .and_then(|n| n.checked_add(fraction))
+        .ok_or_else(error)
+}
+
+fn line_error(line: usize, message: &str) -> String {
+    format!("Failed to parse line {}: {}", line, message)
+}
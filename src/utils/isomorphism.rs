@@ -0,0 +1,373 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::{Card, Combinations, DeckFactory, Suit};
+
+/// A relabelling of the four suits.
+///
+/// Jokers are left unchanged by every permutation.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, Rank, Suit, SuitPermutation};
+///
+/// let swap = SuitPermutation::new([Suit::Spades, Suit::Diamonds, Suit::Clubs, Suit::Hearts]).unwrap();
+/// let card = Card::new(Suit::Hearts, Rank::Ace);
+/// assert_eq!(swap.apply(&card), Card::new(Suit::Spades, Rank::Ace));
+/// assert_eq!(swap.inverse().apply(&swap.apply(&card)), card);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SuitPermutation {
+    /// The suit each suit of [`Suit::ALL`] maps to, in that order.
+    map: [Suit; 4],
+}
+
+impl SuitPermutation {
+    /// Creates a permutation mapping the suits of [`Suit::ALL`], in order, to `map`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `map` does not contain every suit exactly once.
+    pub fn new(map: [Suit; 4]) -> Result<Self, String> {
+        if Suit::ALL.iter().any(|suit| !map.contains(suit)) {
+            return Err(format!("Not a suit permutation: {:?}", map));
+        }
+        Ok(SuitPermutation { map })
+    }
+
+    /// Returns the permutation that leaves every suit unchanged.
+    pub fn identity() -> Self {
+        SuitPermutation { map: Suit::ALL }
+    }
+
+    /// Returns all 24 suit permutations, starting with the identity.
+    pub fn all() -> Vec<SuitPermutation> {
+        let mut permutations = Vec::with_capacity(24);
+        for a in 0..4 {
+            for b in (0..4).filter(|&b| b != a) {
+                for c in (0..4).filter(|&c| c != a && c != b) {
+                    let d = 6 - a - b - c;
+                    permutations.push(SuitPermutation {
+                        map: [Suit::ALL[a], Suit::ALL[b], Suit::ALL[c], Suit::ALL[d]],
+                    });
+                }
+            }
+        }
+        permutations
+    }
+
+    /// Returns the suit that `suit` maps to.
+    pub fn map_suit(&self, suit: Suit) -> Suit {
+        self.map[suit.value() as usize]
+    }
+
+    /// Returns `card` with its suit relabelled. Jokers are returned unchanged.
+    pub fn apply(&self, card: &Card) -> Card {
+        if card.is_joker() {
+            *card
+        } else {
+            Card::new(self.map_suit(card.suit()), card.rank())
+        }
+    }
+
+    /// Returns `cards` with every suit relabelled.
+    pub fn apply_all(&self, cards: &[Card]) -> Vec<Card> {
+        cards.iter().map(|card| self.apply(card)).collect()
+    }
+
+    /// Returns the permutation that undoes this one.
+    pub fn inverse(&self) -> Self {
+        let mut map = Suit::ALL;
+        for suit in Suit::ALL {
+            map[self.map_suit(suit).value() as usize] = suit;
+        }
+        SuitPermutation { map }
+    }
+}
+
+impl Default for SuitPermutation {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl fmt::Display for SuitPermutation {
+    /// Formats the permutation as "♥→♠ ♦→♦ ♣→♣ ♠→♥".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs: Vec<String> = Suit::ALL
+            .iter()
+            .map(|suit| format!("{}→{}", suit, self.map_suit(*suit)))
+            .collect();
+        write!(f, "{}", pairs.join(" "))
+    }
+}
+
+/// The canonical representative of a hand under suit permutation.
+///
+/// A hand is made of ordered groups of cards (for example hole cards, then the
+/// flop). Cards within a group are unordered; groups are not interchangeable.
+/// Two hands are equivalent if some [`SuitPermutation`] maps one onto the other,
+/// and equivalent hands always have the same canonical form.
+///
+/// In the canonical form each group is sorted by rank, highest first, then by
+/// suit, and suits are relabelled so that the hand is as early as possible in
+/// that order, which means the first suit used is Hearts, the next Diamonds, and so on.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{CanonicalHand, Deck};
+///
+/// let a: Deck = "A♥ K♥".parse().unwrap();
+/// let b: Deck = "K♠ A♠".parse().unwrap();
+/// assert_eq!(a.canonical_form(), b.canonical_form());
+///
+/// let hole: Vec<_> = "A♠ K♠".parse::<Deck>().unwrap().into_iter().collect();
+/// let flop: Vec<_> = "Q♠ 7♣ 2♦".parse::<Deck>().unwrap().into_iter().collect();
+/// let canonical = CanonicalHand::new(&[&hole, &flop]);
+/// assert_eq!(canonical.to_string(), "A♥ K♥ | Q♥ 7♦ 2♣");
+/// assert_eq!(canonical.permutation().apply_all(&flop), canonical.group(1).unwrap());
+/// ```
+///
+/// Equality and hashing compare only the canonical groups, not the permutation
+/// that produced them.
+#[derive(Debug, Clone)]
+pub struct CanonicalHand {
+    groups: Vec<Vec<Card>>,
+    permutation: SuitPermutation,
+}
+
+impl CanonicalHand {
+    /// Canonicalizes a hand made of ordered groups of cards.
+    pub fn new(groups: &[&[Card]]) -> Self {
+        let mut best: Option<(Vec<(u8, u8)>, CanonicalHand)> = None;
+        for permutation in SuitPermutation::all() {
+            let mapped: Vec<Vec<Card>> = groups
+                .iter()
+                .map(|group| {
+                    let mut cards = permutation.apply_all(group);
+                    cards.sort_by_key(sort_key);
+                    cards
+                })
+                .collect();
+            let key: Vec<(u8, u8)> = mapped.iter().flatten().map(sort_key).collect();
+            let better = match &best {
+                Some((best_key, _)) => key < *best_key,
+                None => true,
+            };
+            if better {
+                let hand = CanonicalHand {
+                    groups: mapped,
+                    permutation,
+                };
+                best = Some((key, hand));
+            }
+        }
+        best.expect("there is always at least one permutation").1
+    }
+
+    /// Canonicalizes a single group of cards.
+    pub fn of(cards: &[Card]) -> Self {
+        Self::new(&[cards])
+    }
+
+    /// Returns the canonical groups.
+    pub fn groups(&self) -> &[Vec<Card>] {
+        &self.groups
+    }
+
+    /// Returns one canonical group, or `None` if out of range.
+    pub fn group(&self, index: usize) -> Option<&[Card]> {
+        self.groups.get(index).map(Vec::as_slice)
+    }
+
+    /// Returns every canonical card, group after group.
+    pub fn cards(&self) -> Vec<Card> {
+        self.groups.iter().flatten().copied().collect()
+    }
+
+    /// Returns the permutation that maps the original hand to this form.
+    ///
+    /// Use [`SuitPermutation::inverse`] to map results back to the original suits.
+    pub fn permutation(&self) -> SuitPermutation {
+        self.permutation
+    }
+
+    /// Returns a compact key identifying the equivalence class.
+    fn key(&self) -> Vec<u8> {
+        let mut key = Vec::new();
+        for group in &self.groups {
+            key.extend(group.iter().map(|card| u8::from(*card)));
+            key.push(u8::MAX);
+        }
+        key
+    }
+}
+
+impl PartialEq for CanonicalHand {
+    fn eq(&self, other: &Self) -> bool {
+        self.groups == other.groups
+    }
+}
+
+impl Eq for CanonicalHand {}
+
+impl Hash for CanonicalHand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.groups.hash(state);
+    }
+}
+
+impl fmt::Display for CanonicalHand {
+    /// Formats the groups separated by " | ", e.g. "A♥ K♥ | Q♥ 7♦ 2♣".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups: Vec<String> = self
+            .groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(Card::to_string)
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect();
+        write!(f, "{}", groups.join(" | "))
+    }
+}
+
+/// Sorts by rank, highest first, then by suit.
+fn sort_key(card: &Card) -> (u8, u8) {
+    (u8::MAX - card.rank().value(), card.suit().value())
+}
+
+/// Returns `true` if some suit permutation maps one hand onto the other.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{is_isomorphic, Deck};
+///
+/// let a: Vec<_> = "A♥ K♦".parse::<Deck>().unwrap().into_iter().collect();
+/// let b: Vec<_> = "A♣ K♠".parse::<Deck>().unwrap().into_iter().collect();
+/// let c: Vec<_> = "A♣ K♣".parse::<Deck>().unwrap().into_iter().collect();
+/// assert!(is_isomorphic(&[&a], &[&b]));
+/// assert!(!is_isomorphic(&[&a], &[&c]));
+/// ```
+pub fn is_isomorphic(a: &[&[Card]], b: &[&[Card]]) -> bool {
+    CanonicalHand::new(a).groups == CanonicalHand::new(b).groups
+}
+
+/// The suit-isomorphism classes of every hand with the given group sizes.
+///
+/// Built by enumerating every hand that can be dealt from a set of cards, so
+/// the cost grows with the number of raw hands: fine for preflop hands (1,326)
+/// or flops (22,100), expensive for hole cards and flop together (25,989,600).
+///
+/// Classes are numbered densely from 0 in the order they are first met.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{CanonicalHand, Deck, IsomorphismClasses, Standard52};
+///
+/// let preflop = IsomorphismClasses::from_factory(Standard52, &[2]);
+/// assert_eq!(preflop.len(), 169);
+///
+/// let aces: Vec<_> = "A♠ A♦".parse::<Deck>().unwrap().into_iter().collect();
+/// let index = preflop.index_of(&[&aces]).unwrap();
+/// assert_eq!(preflop.class_size(index), Some(6));
+/// assert_eq!(preflop.representative(index).unwrap().to_string(), "A♥ A♦");
+/// ```
+#[derive(Debug, Clone)]
+pub struct IsomorphismClasses {
+    group_sizes: Vec<usize>,
+    indices: HashMap<Vec<u8>, usize>,
+    representatives: Vec<CanonicalHand>,
+    sizes: Vec<usize>,
+}
+
+impl IsomorphismClasses {
+    /// Enumerates the classes of hands dealt from `cards` with the given group sizes.
+    pub fn new(cards: &[Card], group_sizes: &[usize]) -> Self {
+        let mut classes = IsomorphismClasses {
+            group_sizes: group_sizes.to_vec(),
+            indices: HashMap::new(),
+            representatives: Vec::new(),
+            sizes: Vec::new(),
+        };
+        classes.enumerate(cards, &mut Vec::new());
+        classes
+    }
+
+    /// Enumerates the classes of hands dealt from a factory's cards.
+    pub fn from_factory<F>(factory: F, group_sizes: &[usize]) -> Self
+    where
+        F: DeckFactory,
+    {
+        let cards: Vec<Card> = factory.generate().into_iter().collect();
+        Self::new(&cards, group_sizes)
+    }
+
+    fn enumerate(&mut self, cards: &[Card], dealt: &mut Vec<Vec<Card>>) {
+        let Some(&size) = self.group_sizes.get(dealt.len()) else {
+            let groups: Vec<&[Card]> = dealt.iter().map(Vec::as_slice).collect();
+            self.insert(CanonicalHand::new(&groups));
+            return;
+        };
+        let dead: Vec<Card> = dealt.iter().flatten().copied().collect();
+        for group in Combinations::excluding(cards, size, &dead) {
+            dealt.push(group);
+            self.enumerate(cards, dealt);
+            dealt.pop();
+        }
+    }
+
+    fn insert(&mut self, hand: CanonicalHand) {
+        let next = self.representatives.len();
+        let index = *self.indices.entry(hand.key()).or_insert(next);
+        if index == next {
+            self.representatives.push(hand);
+            self.sizes.push(0);
+        }
+        self.sizes[index] += 1;
+    }
+
+    /// Returns the number of classes.
+    pub fn len(&self) -> usize {
+        self.representatives.len()
+    }
+
+    /// Returns `true` if there are no classes.
+    pub fn is_empty(&self) -> bool {
+        self.representatives.is_empty()
+    }
+
+    /// Returns the group sizes the classes were built for.
+    pub fn group_sizes(&self) -> &[usize] {
+        &self.group_sizes
+    }
+
+    /// Returns the class index of a hand, or `None` if the hand was never dealt
+    /// during enumeration (wrong group sizes or foreign cards).
+    pub fn index_of(&self, groups: &[&[Card]]) -> Option<usize> {
+        self.indices.get(&CanonicalHand::new(groups).key()).copied()
+    }
+
+    /// Returns the canonical hand of a class.
+    pub fn representative(&self, index: usize) -> Option<&CanonicalHand> {
+        self.representatives.get(index)
+    }
+
+    /// Returns how many raw hands belong to a class.
+    pub fn class_size(&self, index: usize) -> Option<usize> {
+        self.sizes.get(index).copied()
+    }
+
+    /// Returns an iterator over each class's canonical hand and size, in index order.
+    pub fn iter(&self) -> impl Iterator<Item = (&CanonicalHand, usize)> + '_ {
+        self.representatives.iter().zip(self.sizes.iter().copied())
+    }
+}
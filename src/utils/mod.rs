@@ -1,4 +1,67 @@
+pub mod binary;
+pub mod combinations;
+pub mod csv;
+pub mod envelope;
+pub mod game_record;
+pub mod hand_history;
+pub mod isomorphism;
+pub mod pbn;
+pub mod permutation;
+pub mod probability;
+pub mod schema;
+pub mod spec;
+pub mod stacked;
 pub mod standard;
+pub(crate) mod tags;
 
+pub use binary::DeckReader;
+pub use binary::DeckRecord;
+pub use binary::DeckWriter;
+pub use binary::BINARY_MAGIC;
+pub use binary::BINARY_VERSION;
+pub use combinations::binomial;
+pub use combinations::CombinationIndexer;
+pub use combinations::Combinations;
+pub use csv::CsvColumn;
+pub use csv::CsvError;
+pub use csv::CsvFormat;
+pub use csv::RankStyle;
+pub use csv::SuitStyle;
+pub use envelope::DeckEnvelope;
+pub use envelope::ENVELOPE_VERSION;
+pub use game_record::GameRecord;
+pub use game_record::Move;
+pub use game_record::MoveKind;
+pub use game_record::DECK_ZONE;
+pub use hand_history::read_hand_histories;
+pub use hand_history::Action;
+pub use hand_history::ActionKind;
+pub use hand_history::HandHistory;
+pub use hand_history::Seat;
+pub use hand_history::Street;
+pub use isomorphism::is_isomorphic;
+pub use isomorphism::CanonicalHand;
+pub use isomorphism::IsomorphismClasses;
+pub use isomorphism::SuitPermutation;
+pub use pbn::read_pbn;
+pub use pbn::write_pbn;
+pub use pbn::Direction;
+pub use pbn::PbnBoard;
+pub use pbn::PbnDeal;
+pub use pbn::Vulnerability;
+pub use permutation::PermutationIndex;
+pub use probability::DrawOdds;
+pub use probability::DrawRequirement;
+pub use schema::card_schema;
+pub use schema::deck_envelope_schema;
+pub use schema::deck_schema;
+pub use schema::packed_deck_schema;
+pub use schema::permutation_index_schema;
+pub use spec::DeckSpec;
+pub use stacked::StackedDeckBuilder;
+pub use standard::Doppelkopf48;
+pub use standard::Euchre24;
+pub use standard::Schafkopf24;
+pub use standard::Skat32;
 pub use standard::Standard52;
 pub use standard::Standard54;
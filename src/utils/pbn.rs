@@ -0,0 +1,688 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::utils::tags::{escape, parse_tag};
+use crate::{BridgeComparator, Card, CardComparator, Deal, Rank, Suit};
+
+/// A seat at the bridge table.
+///
+/// Seats are indexed clockwise from North, which is the seat order used by
+/// [`PbnDeal::hands`] and by the [`Deal`] conversions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting with North.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Returns the seat index: North is 0, East 1, South 2 and West 3.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the next direction clockwise.
+    pub const fn next(self) -> Direction {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    /// Returns the one-letter PBN abbreviation.
+    pub const fn letter(self) -> char {
+        match self {
+            Direction::North => 'N',
+            Direction::East => 'E',
+            Direction::South => 'S',
+            Direction::West => 'W',
+        }
+    }
+
+    /// Returns the dealer of a duplicate board. Board numbers start at 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::Direction;
+    /// assert_eq!(Direction::dealer_for_board(1), Direction::North);
+    /// assert_eq!(Direction::dealer_for_board(6), Direction::East);
+    /// ```
+    pub const fn dealer_for_board(board: u32) -> Direction {
+        Direction::ALL[(board.wrapping_sub(1) % 4) as usize]
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    /// Parses a direction from "N", "E", "S", "W" or a full name (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "N" | "NORTH" => Ok(Direction::North),
+            "E" | "EAST" => Ok(Direction::East),
+            "S" | "SOUTH" => Ok(Direction::South),
+            "W" | "WEST" => Ok(Direction::West),
+            _ => Err(format!("Invalid direction string: {}", s)),
+        }
+    }
+}
+
+/// Which partnerships are vulnerable on a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Vulnerability {
+    #[default]
+    None,
+    NorthSouth,
+    EastWest,
+    Both,
+}
+
+impl Vulnerability {
+    /// Returns the vulnerability of a duplicate board. Board numbers start at 1.
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::Vulnerability;
+    /// assert_eq!(Vulnerability::for_board(1), Vulnerability::None);
+    /// assert_eq!(Vulnerability::for_board(4), Vulnerability::Both);
+    /// assert_eq!(Vulnerability::for_board(17), Vulnerability::None);
+    /// ```
+    pub const fn for_board(board: u32) -> Vulnerability {
+        use Vulnerability::{Both, EastWest, None, NorthSouth};
+        const CYCLE: [Vulnerability; 16] = [
+            None, NorthSouth, EastWest, Both, NorthSouth, EastWest, Both, None, EastWest, Both,
+            None, NorthSouth, Both, None, NorthSouth, EastWest,
+        ];
+        CYCLE[(board.wrapping_sub(1) % 16) as usize]
+    }
+
+    /// Returns true if the partnership sitting in `direction` is vulnerable.
+    pub const fn is_vulnerable(self, direction: Direction) -> bool {
+        match self {
+            Vulnerability::None => false,
+            Vulnerability::Both => true,
+            Vulnerability::NorthSouth => {
+                matches!(direction, Direction::North | Direction::South)
+            }
+            Vulnerability::EastWest => matches!(direction, Direction::East | Direction::West),
+        }
+    }
+
+    /// Returns the PBN tag value: "None", "NS", "EW" or "All".
+    pub const fn name(self) -> &'static str {
+        match self {
+            Vulnerability::None => "None",
+            Vulnerability::NorthSouth => "NS",
+            Vulnerability::EastWest => "EW",
+            Vulnerability::Both => "All",
+        }
+    }
+}
+
+impl fmt::Display for Vulnerability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Vulnerability {
+    type Err = String;
+
+    /// Parses a PBN vulnerability: "None", "Love" or "-"; "NS"; "EW";
+    /// "All" or "Both" (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "NONE" | "LOVE" | "-" => Ok(Vulnerability::None),
+            "NS" => Ok(Vulnerability::NorthSouth),
+            "EW" => Ok(Vulnerability::EastWest),
+            "ALL" | "BOTH" => Ok(Vulnerability::Both),
+            _ => Err(format!("Invalid vulnerability string: {}", s)),
+        }
+    }
+}
+
+/// The four hands of a bridge deal, as written in a PBN `Deal` tag.
+///
+/// A deal string names the first hand's direction, then lists the hands
+/// clockwise. Each hand lists its spades, hearts, diamonds and clubs separated
+/// by dots, highest rank first, with `T` for ten. A hand written as `-` is
+/// unknown and parses as empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Direction, PbnDeal};
+///
+/// let deal: PbnDeal = "N:AKQ2.JT9.876.543 JT98.AK2.AK2.762 7654.876.QJT.AKQ 3.Q543.9543.JT98"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(deal.hand(Direction::North).len(), 13);
+/// assert_eq!(
+///     deal.to_deal_string(Direction::West),
+///     "W:3.Q543.9543.JT98 AKQ2.JT9.876.543 JT98.AK2.AK2.762 7654.876.QJT.AKQ"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PbnDeal {
+    hands: [Vec<Card>; 4],
+}
+
+impl PbnDeal {
+    /// Creates a deal from hands indexed by [`Direction::index`].
+    ///
+    /// Each hand is sorted as PBN writes it: spades, hearts, diamonds, clubs,
+    /// highest rank first within each suit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a hand holds a joker or a card appears twice.
+    pub fn new(mut hands: [Vec<Card>; 4]) -> Result<Self, String> {
+        let mut seen = HashSet::new();
+        for card in hands.iter().flatten() {
+            if card.is_joker() {
+                return Err(format!("Bridge deals cannot contain {}", card));
+            }
+            if !seen.insert(*card) {
+                return Err(format!("Duplicate card {}", card));
+            }
+        }
+        for hand in hands.iter_mut() {
+            hand.sort_by(|a, b| bridge_order(b, a));
+        }
+        Ok(PbnDeal { hands })
+    }
+
+    /// Creates a deal from a four-seat [`Deal`], seat 0 being North.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deal does not have exactly four hands, or for
+    /// the reasons given on [`PbnDeal::new`].
+    ///
+    /// # Examples
+    /// ```
+    /// use crusty_cards::{DealPattern, Deck, Direction, PbnDeal, Standard52};
+    ///
+    /// let mut deck = Deck::from_factory(Standard52);
+    /// deck.shuffle();
+    /// let deal = deck.deal_to(4, &DealPattern::round_robin(13)).unwrap();
+    /// let pbn = PbnDeal::from_deal(&deal).unwrap();
+    /// assert_eq!(pbn.hand(Direction::South).len(), 13);
+    /// assert!(deal.hand(2).unwrap().contains(&pbn.hand(Direction::South)[0]));
+    /// ```
+    pub fn from_deal(deal: &Deal) -> Result<Self, String> {
+        let hands: [Vec<Card>; 4] = deal
+            .hands()
+            .to_vec()
+            .try_into()
+            .map_err(|_| format!("Expected 4 hands but found {}", deal.hands().len()))?;
+        Self::new(hands)
+    }
+
+    /// Returns the hand held by `direction`.
+    pub fn hand(&self, direction: Direction) -> &[Card] {
+        &self.hands[direction.index()]
+    }
+
+    /// Returns all four hands, indexed by [`Direction::index`].
+    pub fn hands(&self) -> &[Vec<Card>; 4] {
+        &self.hands
+    }
+
+    /// Converts the deal into a four-seat [`Deal`] with an empty kitty.
+    pub fn into_deal(self) -> Deal {
+        Deal::new(self.hands.into(), Vec::new())
+    }
+
+    /// Writes the deal string, listing hands clockwise from `first`.
+    pub fn to_deal_string(&self, first: Direction) -> String {
+        let mut out = format!("{}:", first);
+        let mut direction = first;
+        for i in 0..4 {
+            if i > 0 {
+                out.push(' ');
+            }
+            out.push_str(&hand_string(self.hand(direction)));
+            direction = direction.next();
+        }
+        out
+    }
+}
+
+impl fmt::Display for PbnDeal {
+    /// Writes the deal string starting with North.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_deal_string(Direction::North))
+    }
+}
+
+impl FromStr for PbnDeal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (first, rest) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Deal must start with a direction and ':': {}", s))?;
+        let mut direction: Direction = first.trim().parse()?;
+        let hands: Vec<&str> = rest.split_whitespace().collect();
+        if hands.len() != 4 {
+            return Err(format!("Expected 4 hands but found {}", hands.len()));
+        }
+        let mut parsed: [Vec<Card>; 4] = Default::default();
+        for hand in hands {
+            parsed[direction.index()] = parse_hand(hand)?;
+            direction = direction.next();
+        }
+        Self::new(parsed)
+    }
+}
+
+impl From<PbnDeal> for Deal {
+    fn from(deal: PbnDeal) -> Self {
+        deal.into_deal()
+    }
+}
+
+/// Bridge suit order for PBN hands, highest first, taken from [`BridgeComparator`].
+fn bridge_suits() -> [Suit; 4] {
+    let mut suits = Suit::ALL;
+    suits.sort_by_key(|suit| Reverse(BridgeComparator.suit_value(*suit)));
+    suits
+}
+
+/// Orders cards by bridge suit, then by rank.
+fn bridge_order(a: &Card, b: &Card) -> Ordering {
+    let cmp = BridgeComparator;
+    cmp.suit_value(a.suit())
+        .cmp(&cmp.suit_value(b.suit()))
+        .then_with(|| cmp.rank_value(a.rank()).cmp(&cmp.rank_value(b.rank())))
+}
+
+fn hand_string(cards: &[Card]) -> String {
+    if cards.is_empty() {
+        return "-".to_string();
+    }
+    bridge_suits()
+        .iter()
+        .map(|suit| {
+            cards
+                .iter()
+                .filter(|card| card.suit() == *suit)
+                .map(|card| card.rank().to_string())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn parse_hand(hand: &str) -> Result<Vec<Card>, String> {
+    if hand == "-" {
+        return Ok(Vec::new());
+    }
+    let holdings: Vec<&str> = hand.split('.').collect();
+    if holdings.len() != 4 {
+        return Err(format!(
+            "Hand {} must list four suits separated by '.'",
+            hand
+        ));
+    }
+    let mut cards = Vec::new();
+    for (suit, holding) in bridge_suits().into_iter().zip(holdings) {
+        for c in holding.chars() {
+            let rank = Rank::from_str(&c.to_string())
+                .ok()
+                .filter(|rank| *rank != Rank::Joker)
+                .ok_or_else(|| format!("Invalid card character '{}' in hand {}", c, hand))?;
+            cards.push(Card::new(suit, rank));
+        }
+    }
+    Ok(cards)
+}
+
+/// One board of a PBN file: the deal plus its common tags.
+///
+/// `Board`, `Dealer`, `Vulnerable` and `Deal` are parsed into typed fields;
+/// every other tag is kept as text in file order. When a board is written,
+/// the tags of the PBN mandatory tag set come first in the standard's order,
+/// followed by the other tags in file order. The deal string starts with the
+/// dealer, or with North if there is none.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Direction, PbnBoard, Vulnerability};
+///
+/// let board: PbnBoard = r#"
+/// [Event "Club pairs"]
+/// [Board "3"]
+/// [Dealer "S"]
+/// [Vulnerable "EW"]
+/// [Deal "S:AKQ2.JT9.876.543 JT98.AK2.AK2.762 7654.876.QJT.AKQ 3.Q543.9543.JT98"]
+/// "#
+/// .parse()
+/// .unwrap();
+/// assert_eq!(board.board(), Some(3));
+/// assert_eq!(board.dealer(), Some(Direction::South));
+/// assert_eq!(board.vulnerable(), Some(Vulnerability::EastWest));
+/// assert_eq!(board.tag("Event"), Some("Club pairs"));
+/// assert_eq!(board.deal().hand(Direction::West)[0].to_string(), "J♠");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PbnBoard {
+    board: Option<u32>,
+    dealer: Option<Direction>,
+    vulnerable: Option<Vulnerability>,
+    deal: PbnDeal,
+    tags: Vec<(String, String)>,
+}
+
+impl PbnBoard {
+    /// Creates a board holding `deal` and no tags.
+    pub fn new(deal: PbnDeal) -> Self {
+        PbnBoard {
+            deal,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the board number.
+    pub fn with_board(mut self, board: u32) -> Self {
+        self.board = Some(board);
+        self
+    }
+
+    /// Sets the dealer.
+    pub fn with_dealer(mut self, dealer: Direction) -> Self {
+        self.dealer = Some(dealer);
+        self
+    }
+
+    /// Sets the vulnerability.
+    pub fn with_vulnerable(mut self, vulnerable: Vulnerability) -> Self {
+        self.vulnerable = Some(vulnerable);
+        self
+    }
+
+    /// Sets an additional tag, replacing any earlier value for `name`.
+    ///
+    /// The typed tags (`Board`, `Dealer`, `Vulnerable`, `Deal`) are set
+    /// through their own methods and must not be passed here.
+    pub fn with_tag(mut self, name: &str, value: &str) -> Self {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+        self
+    }
+
+    /// Returns the board number, if tagged.
+    pub fn board(&self) -> Option<u32> {
+        self.board
+    }
+
+    /// Returns the dealer, if tagged.
+    pub fn dealer(&self) -> Option<Direction> {
+        self.dealer
+    }
+
+    /// Returns the vulnerability, if tagged.
+    pub fn vulnerable(&self) -> Option<Vulnerability> {
+        self.vulnerable
+    }
+
+    /// Returns the deal.
+    pub fn deal(&self) -> &PbnDeal {
+        &self.deal
+    }
+
+    /// Returns the value of an additional tag.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the additional tags in file order.
+    pub fn tags(&self) -> &[(String, String)] {
+        &self.tags
+    }
+
+    /// Consumes the board, returning the deal.
+    pub fn into_deal(self) -> PbnDeal {
+        self.deal
+    }
+
+    fn from_tags(tags: Vec<(String, String)>) -> Result<Self, String> {
+        let mut board = PbnBoard::default();
+        let mut deal = None;
+        for (name, value) in tags {
+            let unknown = value.is_empty() || value == "?";
+            match name.as_str() {
+                "Board" if !unknown => {
+                    board.board = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("Invalid board number: {}", value))?,
+                    );
+                }
+                "Dealer" if !unknown => board.dealer = Some(value.parse()?),
+                "Vulnerable" if !unknown => board.vulnerable = Some(value.parse()?),
+                "Deal" => deal = Some(value.parse()?),
+                "Board" | "Dealer" | "Vulnerable" => {}
+                _ => board = board.with_tag(&name, &value),
+            }
+        }
+        board.deal = deal.ok_or_else(|| "Board has no Deal tag".to_string())?;
+        Ok(board)
+    }
+}
+
+/// The PBN mandatory tag set, in the order the standard lists it.
+const MANDATORY_TAGS: [&str; 15] = [
+    "Event",
+    "Site",
+    "Date",
+    "Board",
+    "West",
+    "North",
+    "East",
+    "South",
+    "Dealer",
+    "Vulnerable",
+    "Deal",
+    "Scoring",
+    "Declarer",
+    "Contract",
+    "Result",
+];
+
+impl fmt::Display for PbnBoard {
+    /// Writes the board as PBN tag pairs, one per line, with the mandatory
+    /// tags that are present first, in the standard's order.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for name in MANDATORY_TAGS {
+            match name {
+                "Board" => {
+                    if let Some(board) = self.board {
+                        writeln!(f, "[Board \"{}\"]", board)?;
+                    }
+                }
+                "Dealer" => {
+                    if let Some(dealer) = self.dealer {
+                        writeln!(f, "[Dealer \"{}\"]", dealer)?;
+                    }
+                }
+                "Vulnerable" => {
+                    if let Some(vulnerable) = self.vulnerable {
+                        writeln!(f, "[Vulnerable \"{}\"]", vulnerable)?;
+                    }
+                }
+                "Deal" => {
+                    let first = self.dealer.unwrap_or(Direction::North);
+                    writeln!(f, "[Deal \"{}\"]", self.deal.to_deal_string(first))?;
+                }
+                _ => {
+                    if let Some(value) = self.tag(name) {
+                        writeln!(f, "[{} \"{}\"]", name, escape(value))?;
+                    }
+                }
+            }
+        }
+        for (name, value) in &self.tags {
+            if !MANDATORY_TAGS.contains(&name.as_str()) {
+                writeln!(f, "[{} \"{}\"]", name, escape(value))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PbnBoard {
+    type Err = String;
+
+    /// Parses a single board. Fails if the input holds more than one.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut boards = read_pbn(s)?;
+        match boards.len() {
+            1 => Ok(boards.remove(0)),
+            n => Err(format!("Expected 1 board but found {}", n)),
+        }
+    }
+}
+
+/// Reads every board of a PBN file.
+///
+/// Boards are separated by blank lines. `%` lines, `;` comments and `{...}`
+/// commentary are skipped, as are non-tag lines such as auction and play
+/// sections. A tag value of `#` repeats the value from the previous board.
+///
+/// # Errors
+///
+/// Returns an error naming the line if a tag is malformed, a typed tag has an
+/// invalid value, or a board has no `Deal` tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{read_pbn, write_pbn, Direction};
+///
+/// let pbn = "% PBN 2.1\n\
+///            [Board \"1\"]\n\
+///            [Dealer \"N\"]\n\
+///            [Deal \"N:AKQ2.JT9.876.543 JT98.AK2.AK2.762 7654.876.QJT.AKQ 3.Q543.9543.JT98\"]\n\
+///            \n\
+///            [Board \"2\"]\n\
+///            [Dealer \"#\"]\n\
+///            [Deal \"E:- - - AKQJT98765432...\"]\n";
+/// let boards = read_pbn(pbn).unwrap();
+/// assert_eq!(boards.len(), 2);
+/// assert_eq!(boards[1].dealer(), Some(Direction::North));
+/// assert_eq!(boards[1].deal().hand(Direction::North).len(), 13);
+/// assert_eq!(read_pbn(&write_pbn(&boards)).unwrap(), boards);
+/// ```
+pub fn read_pbn(input: &str) -> Result<Vec<PbnBoard>, String> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut boards = Vec::new();
+    let mut previous: Vec<(String, String)> = Vec::new();
+    let mut tags: Vec<(String, String)> = Vec::new();
+    let mut start = 0;
+    let mut in_commentary = false;
+
+    let mut finish = |tags: &mut Vec<(String, String)>, start: usize| -> Result<(), String> {
+        if tags.is_empty() {
+            return Ok(());
+        }
+        for (name, value) in tags.iter_mut() {
+            if value == "#" {
+                if let Some((_, old)) = previous.iter().find(|(tag, _)| tag == name) {
+                    *value = old.clone();
+                }
+            }
+        }
+        let board = PbnBoard::from_tags(tags.clone()).map_err(|e| line_error(start, &e))?;
+        boards.push(board);
+        previous = std::mem::take(tags);
+        Ok(())
+    };
+
+    for (i, line) in input.lines().enumerate() {
+        let number = i + 1;
+        let mut rest = line.trim();
+        if in_commentary {
+            match rest.find('}') {
+                Some(end) => {
+                    rest = rest[end + 1..].trim_start();
+                    in_commentary = false;
+                }
+                None => continue,
+            }
+        }
+        if rest.starts_with('%') {
+            continue;
+        }
+        if line.trim().is_empty() {
+            finish(&mut tags, start)?;
+            continue;
+        }
+        while !rest.is_empty() {
+            if rest.starts_with(';') {
+                break;
+            }
+            if let Some(body) = rest.strip_prefix('{') {
+                match body.find('}') {
+                    Some(end) => rest = body[end + 1..].trim_start(),
+                    None => {
+                        in_commentary = true;
+                        break;
+                    }
+                }
+            } else if rest.starts_with('[') {
+                let (name, value, after) = parse_tag(rest).map_err(|e| line_error(number, &e))?;
+                if tags.is_empty() {
+                    start = number;
+                }
+                tags.push((name, value));
+                rest = after.trim_start();
+            } else {
+                // Section data (auction, play, tables) is not interpreted.
+                break;
+            }
+        }
+    }
+    finish(&mut tags, start)?;
+    Ok(boards)
+}
+
+/// Writes boards as a PBN file, with a `% PBN 2.1` header and a blank line
+/// after each board.
+///
+/// The file is in PBN import format: tags missing from a board are left out
+/// rather than written as `"?"`, so it does not claim export format.
+pub fn write_pbn(boards: &[PbnBoard]) -> String {
+    let mut out = String::from("% PBN 2.1\n\n");
+    for board in boards {
+        out.push_str(&board.to_string());
+        out.push('\n');
+    }
+    out
+}
+
+fn line_error(line: usize, message: &str) -> String {
+    format!("Failed to parse line {}: {}", line, message)
+}
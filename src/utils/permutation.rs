@@ -0,0 +1,373 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Card;
+
+const LIMBS: usize = 4;
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The index of an ordering of distinct cards among all orderings of them.
+///
+/// The index is the Lehmer code of the ordering read as a mixed-radix number,
+/// relative to a reference order (usually the order a [`DeckFactory`](crate::DeckFactory)
+/// generates). The reference order itself has index 0 and the reversed order
+/// has index `n! - 1`, so a shuffled 52-card deck fits in 226 bits (29 bytes).
+///
+/// Indices are stored as 256-bit unsigned integers, enough for every ordering
+/// of the 56 distinct cards the crate can represent. They format as decimal,
+/// convert to and from big-endian bytes and URL-safe base64, and serialize as
+/// base64 strings.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, PermutationIndex, Standard52};
+///
+/// let mut deck = Deck::from_factory(Standard52);
+/// deck.shuffle();
+///
+/// let index = deck.permutation_index(Standard52).unwrap();
+/// let deal_id = index.to_base64();
+/// assert!(deal_id.len() <= 39);
+///
+/// let restored = PermutationIndex::from_base64(&deal_id).unwrap();
+/// assert_eq!(Deck::from_permutation_index(&restored, Standard52).unwrap(), deck);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct PermutationIndex {
+    /// Little-endian 64-bit limbs.
+    limbs: [u64; LIMBS],
+}
+
+impl PermutationIndex {
+    /// Computes the index of `cards` relative to the `reference` ordering.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either slice contains a duplicate card, if the
+    /// lengths differ, or if `cards` holds a card missing from `reference`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Deck, PermutationIndex};
+    ///
+    /// let reference: Vec<_> = "A♠ K♠ Q♠".parse::<Deck>().unwrap().into_iter().collect();
+    /// let cards: Vec<_> = "Q♠ K♠ A♠".parse::<Deck>().unwrap().into_iter().collect();
+    /// let index = PermutationIndex::rank(&cards, &reference).unwrap();
+    /// assert_eq!(index, PermutationIndex::from(5));
+    /// ```
+    pub fn rank(cards: &[Card], reference: &[Card]) -> Result<Self, String> {
+        let positions = reference_positions(reference)?;
+        if cards.len() != reference.len() {
+            return Err(format!(
+                "Expected {} cards but found {}",
+                reference.len(),
+                cards.len()
+            ));
+        }
+        let mut used = vec![false; reference.len()];
+        let mut index = PermutationIndex::default();
+        for (i, card) in cards.iter().enumerate() {
+            let position = *positions
+                .get(card)
+                .ok_or_else(|| format!("Card {} is not in the reference order", card))?;
+            if used[position] {
+                return Err(format!("Duplicate card {}", card));
+            }
+            used[position] = true;
+            let digit = used[..position].iter().filter(|u| !**u).count();
+            // Cannot overflow: the result is below n! and n is at most 56.
+            index.mul_add((cards.len() - i) as u64, digit as u64);
+        }
+        Ok(index)
+    }
+
+    /// Rebuilds the ordering of `reference` this index describes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `reference` contains a duplicate card or if the index
+    /// is not below `reference.len()!`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Deck, PermutationIndex};
+    ///
+    /// let reference: Vec<_> = "A♠ K♠ Q♠".parse::<Deck>().unwrap().into_iter().collect();
+    /// let cards = PermutationIndex::from(5).unrank(&reference).unwrap();
+    /// let deck: Deck = cards.into_iter().collect();
+    /// assert_eq!(deck.to_string(), "Q♠ K♠ A♠");
+    /// assert!(PermutationIndex::from(6).unrank(&reference).is_err());
+    /// ```
+    pub fn unrank(&self, reference: &[Card]) -> Result<Vec<Card>, String> {
+        reference_positions(reference)?;
+        let n = reference.len();
+        let mut rest = *self;
+        let mut digits = vec![0usize; n];
+        for i in (0..n).rev() {
+            digits[i] = rest.div_rem((n - i) as u64) as usize;
+        }
+        if !rest.is_zero() {
+            return Err(format!(
+                "Permutation index {} is out of range for {} cards",
+                self, n
+            ));
+        }
+        let mut remaining = reference.to_vec();
+        Ok(digits
+            .into_iter()
+            .map(|digit| remaining.remove(digit))
+            .collect())
+    }
+
+    /// Returns the number of bytes needed to store any index over `cards` cards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::PermutationIndex;
+    ///
+    /// assert_eq!(PermutationIndex::max_bytes(52), 29);
+    /// assert_eq!(PermutationIndex::max_bytes(1), 1);
+    /// ```
+    pub fn max_bytes(cards: usize) -> usize {
+        let bits: f64 = (2..=cards).map(|k| (k as f64).log2()).sum();
+        (bits.ceil() as usize).div_ceil(8).max(1)
+    }
+
+    /// Returns the index as big-endian bytes without leading zeros.
+    ///
+    /// Zero is encoded as a single zero byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::PermutationIndex;
+    ///
+    /// assert_eq!(PermutationIndex::from(258).to_bytes(), vec![1, 2]);
+    /// assert_eq!(PermutationIndex::default().to_bytes(), vec![0]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let bytes: Vec<u8> = self
+            .limbs
+            .iter()
+            .rev()
+            .flat_map(|limb| limb.to_be_bytes())
+            .skip_while(|byte| *byte == 0)
+            .collect();
+        if bytes.is_empty() {
+            vec![0]
+        } else {
+            bytes
+        }
+    }
+
+    /// Reads an index from big-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value does not fit in 256 bits.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let start = bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(bytes.len());
+        let significant = &bytes[start..];
+        if significant.len() > LIMBS * 8 {
+            return Err("Permutation index is too large".to_string());
+        }
+        let mut index = PermutationIndex::default();
+        for (i, byte) in significant.iter().rev().enumerate() {
+            index.limbs[i / 8] |= (*byte as u64) << (8 * (i % 8));
+        }
+        Ok(index)
+    }
+
+    /// Returns the index's bytes as unpadded URL-safe base64.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::PermutationIndex;
+    ///
+    /// assert_eq!(PermutationIndex::from(258).to_base64(), "AQI");
+    /// ```
+    pub fn to_base64(&self) -> String {
+        let bytes = self.to_bytes();
+        let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let b = [
+                chunk[0],
+                *chunk.get(1).unwrap_or(&0),
+                *chunk.get(2).unwrap_or(&0),
+            ];
+            let group = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+            for i in 0..=chunk.len() {
+                out.push(BASE64[(group >> (18 - 6 * i) & 63) as usize] as char);
+            }
+        }
+        out
+    }
+
+    /// Reads an index from base64.
+    ///
+    /// Accepts both the URL-safe and the standard alphabet, with or without padding.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on characters outside the alphabet, on a truncated
+    /// input, or if the value does not fit in 256 bits.
+    pub fn from_base64(s: &str) -> Result<Self, String> {
+        let s = s.trim().trim_end_matches('=');
+        let mut values = Vec::with_capacity(s.len());
+        for c in s.chars() {
+            let value = match c {
+                '+' => 62,
+                '/' => 63,
+                _ => BASE64
+                    .iter()
+                    .position(|b| *b as char == c)
+                    .ok_or_else(|| format!("Invalid base64 character: {}", c))?
+                    as u32,
+            };
+            values.push(value);
+        }
+        if values.len() % 4 == 1 {
+            return Err("Invalid base64 length".to_string());
+        }
+        let mut bytes = Vec::with_capacity(values.len() * 3 / 4);
+        for chunk in values.chunks(4) {
+            let group = chunk
+                .iter()
+                .chain(std::iter::repeat(&0))
+                .take(4)
+                .fold(0u32, |acc, v| (acc << 6) | v);
+            for i in 0..chunk.len() - 1 {
+                bytes.push((group >> (16 - 8 * i)) as u8);
+            }
+        }
+        Self::from_bytes(&bytes)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|limb| *limb == 0)
+    }
+
+    /// Sets `self = self * mul + add`, returning `false` on overflow.
+    fn mul_add(&mut self, mul: u64, add: u64) -> bool {
+        let mut carry = add as u128;
+        for limb in self.limbs.iter_mut() {
+            let value = *limb as u128 * mul as u128 + carry;
+            *limb = value as u64;
+            carry = value >> 64;
+        }
+        carry == 0
+    }
+
+    /// Divides `self` by `divisor` in place, returning the remainder.
+    fn div_rem(&mut self, divisor: u64) -> u64 {
+        let mut rem: u128 = 0;
+        for limb in self.limbs.iter_mut().rev() {
+            let value = (rem << 64) | *limb as u128;
+            *limb = (value / divisor as u128) as u64;
+            rem = value % divisor as u128;
+        }
+        rem as u64
+    }
+}
+
+/// Maps each reference card to its position, rejecting duplicates.
+fn reference_positions(reference: &[Card]) -> Result<HashMap<Card, usize>, String> {
+    let mut positions = HashMap::with_capacity(reference.len());
+    for (i, card) in reference.iter().enumerate() {
+        if positions.insert(*card, i).is_some() {
+            return Err(format!("Reference order contains duplicate card {}", card));
+        }
+    }
+    Ok(positions)
+}
+
+impl From<u64> for PermutationIndex {
+    fn from(value: u64) -> Self {
+        PermutationIndex {
+            limbs: [value, 0, 0, 0],
+        }
+    }
+}
+
+impl fmt::Display for PermutationIndex {
+    /// Formats the index as a decimal number.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::PermutationIndex;
+    ///
+    /// assert_eq!(PermutationIndex::from(42).to_string(), "42");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut rest = *self;
+        let mut chunks = Vec::new();
+        loop {
+            chunks.push(rest.div_rem(CHUNK));
+            if rest.is_zero() {
+                break;
+            }
+        }
+        let mut digits = chunks.pop().unwrap_or(0).to_string();
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{:019}", chunk));
+        }
+        write!(f, "{}", digits)
+    }
+}
+
+impl FromStr for PermutationIndex {
+    type Err = String;
+
+    /// Parses a decimal index.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crusty_cards::PermutationIndex;
+    ///
+    /// let index: PermutationIndex = "123".parse().unwrap();
+    /// assert_eq!(index, PermutationIndex::from(123));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("Empty permutation index".to_string());
+        }
+        let mut index = PermutationIndex::default();
+        for c in s.chars() {
+            let digit = c
+                .to_digit(10)
+                .ok_or_else(|| format!("Invalid digit in permutation index: {}", c))?;
+            if !index.mul_add(10, digit as u64) {
+                return Err("Permutation index is too large".to_string());
+            }
+        }
+        Ok(index)
+    }
+}
+
+impl From<PermutationIndex> for String {
+    fn from(index: PermutationIndex) -> Self {
+        index.to_base64()
+    }
+}
+
+impl TryFrom<String> for PermutationIndex {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        PermutationIndex::from_base64(&s)
+    }
+}
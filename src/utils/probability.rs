@@ -0,0 +1,240 @@
+use crate::{Card, Deck};
+
+/// A predicate and the minimum number of matching cards required, used by
+/// [`DrawOdds::all_of`].
+pub type DrawRequirement<'a> = (&'a dyn Fn(&Card) -> bool, usize);
+
+/// Exact draw probabilities computed from the composition of a [`Deck`].
+///
+/// All draws are assumed to be taken at random without replacement from the
+/// cards remaining when the `DrawOdds` was created. Single-category questions
+/// use the hypergeometric distribution; [`DrawOdds::all_of`] uses the
+/// multivariate hypergeometric distribution.
+///
+/// Every method caps `draws` at [`remaining`](Self::remaining), so asking
+/// about more cards than are left answers for drawing all of them.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, Deck, DrawOdds, Standard52};
+///
+/// let deck = Deck::from_factory(Standard52);
+/// let odds = DrawOdds::new(&deck);
+///
+/// // At least one Ace in the next 5 cards
+/// let p = odds.at_least(5, 1, Card::is_ace);
+/// assert!((p - 0.341158).abs() < 1e-6);
+///
+/// // Next card is a heart or a face card
+/// let p = odds.next_card(|c| c.suit() == crusty_cards::Suit::Hearts || c.is_face_card());
+/// assert!((p - 22.0 / 52.0).abs() < 1e-12);
+///
+/// // Expected number of tens in 10 draws
+/// let e = odds.expected(10, |c| c.rank() == crusty_cards::Rank::Ten);
+/// assert!((e - 10.0 * 4.0 / 52.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DrawOdds {
+    cards: Vec<Card>,
+}
+
+impl DrawOdds {
+    /// Creates draw odds over the cards currently in `deck`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Deck, DrawOdds, Standard52};
+    ///
+    /// let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    /// assert_eq!(odds.remaining(), 52);
+    /// ```
+    pub fn new(deck: &Deck) -> Self {
+        DrawOdds {
+            cards: deck.iter().copied().collect(),
+        }
+    }
+
+    /// Creates draw odds over a slice of cards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, DrawOdds, Rank, Suit};
+    ///
+    /// let cards = [Card::new(Suit::Hearts, Rank::Ace), Card::new(Suit::Clubs, Rank::Two)];
+    /// let odds = DrawOdds::from_cards(&cards);
+    /// assert_eq!(odds.next_card(Card::is_ace), 0.5);
+    /// ```
+    pub fn from_cards(cards: &[Card]) -> Self {
+        DrawOdds {
+            cards: cards.to_vec(),
+        }
+    }
+
+    /// Returns the number of cards that may still be drawn.
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns the number of remaining cards matching `predicate`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, Deck, DrawOdds, Standard52};
+    ///
+    /// let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    /// assert_eq!(odds.count(Card::is_face_card), 12);
+    /// ```
+    pub fn count<P>(&self, predicate: P) -> usize
+    where
+        P: Fn(&Card) -> bool,
+    {
+        self.cards.iter().filter(|card| predicate(card)).count()
+    }
+
+    /// Returns the probability that the next card drawn matches `predicate`.
+    ///
+    /// Returns 0.0 if there are no cards left.
+    pub fn next_card<P>(&self, predicate: P) -> f64
+    where
+        P: Fn(&Card) -> bool,
+    {
+        if self.cards.is_empty() {
+            return 0.0;
+        }
+        self.count(predicate) as f64 / self.cards.len() as f64
+    }
+
+    /// Returns the probability that exactly `successes` of the next `draws`
+    /// cards match `predicate`. `draws` is capped at the cards remaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, Deck, DrawOdds, Standard52};
+    ///
+    /// let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    /// // Both hole cards are Aces
+    /// let p = odds.exactly(2, 2, Card::is_ace);
+    /// assert!((p - 1.0 / 221.0).abs() < 1e-12);
+    /// ```
+    pub fn exactly<P>(&self, draws: usize, successes: usize, predicate: P) -> f64
+    where
+        P: Fn(&Card) -> bool,
+    {
+        let total = self.cards.len();
+        let draws = draws.min(total);
+        hypergeometric(total, self.count(predicate), draws, successes)
+    }
+
+    /// Returns the probability that at least `min` of the next `draws` cards
+    /// match `predicate`. `draws` is capped at the cards remaining.
+    pub fn at_least<P>(&self, draws: usize, min: usize, predicate: P) -> f64
+    where
+        P: Fn(&Card) -> bool,
+    {
+        let total = self.cards.len();
+        let draws = draws.min(total);
+        let matching = self.count(predicate);
+        (min..=draws)
+            .map(|k| hypergeometric(total, matching, draws, k))
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    /// Returns the probability that at most `max` of the next `draws` cards
+    /// match `predicate`. `draws` is capped at the cards remaining.
+    pub fn at_most<P>(&self, draws: usize, max: usize, predicate: P) -> f64
+    where
+        P: Fn(&Card) -> bool,
+    {
+        let total = self.cards.len();
+        let draws = draws.min(total);
+        let matching = self.count(predicate);
+        (0..=max.min(draws))
+            .map(|k| hypergeometric(total, matching, draws, k))
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    /// Returns the expected number of matching cards among the next `draws`
+    /// cards. `draws` is capped at the cards remaining.
+    pub fn expected<P>(&self, draws: usize, predicate: P) -> f64
+    where
+        P: Fn(&Card) -> bool,
+    {
+        let total = self.cards.len();
+        if total == 0 {
+            return 0.0;
+        }
+        draws.min(total) as f64 * self.count(predicate) as f64 / total as f64
+    }
+
+    /// Returns the probability that the next `draws` cards satisfy every
+    /// requirement at once. `draws` is capped at the cards remaining.
+    ///
+    /// Each requirement is a predicate and a minimum count. Every card is
+    /// assigned to the first requirement whose predicate it matches, so the
+    /// categories never overlap; cards matching no predicate are neutral.
+    /// With "a King" then "a Heart", the King of Hearts is a King and does
+    /// not also satisfy the Heart requirement. Use disjoint predicates, or
+    /// list them in the order cards should be counted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, Deck, DrawOdds, Rank, Standard52};
+    ///
+    /// let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    /// let is_king = |c: &Card| c.rank() == Rank::King;
+    /// // An Ace and a King in two cards
+    /// let p = odds.all_of(2, &[(&Card::is_ace, 1), (&is_king, 1)]);
+    /// assert!((p - 16.0 / 1326.0).abs() < 1e-12);
+    /// ```
+    pub fn all_of(&self, draws: usize, requirements: &[DrawRequirement<'_>]) -> f64 {
+        let total = self.cards.len();
+        let draws = draws.min(total);
+        let mut sizes = vec![0usize; requirements.len()];
+        for card in &self.cards {
+            if let Some(i) = requirements.iter().position(|(pred, _)| pred(card)) {
+                sizes[i] += 1;
+            }
+        }
+        let mins: Vec<usize> = requirements.iter().map(|(_, min)| *min).collect();
+        let rest = total - sizes.iter().sum::<usize>();
+        let ways = multivariate_ways(&sizes, &mins, rest, draws);
+        (ways / choose(total, draws)).min(1.0)
+    }
+}
+
+/// Returns the binomial coefficient C(n, k) as a floating point number.
+fn choose(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    let k = k.min(n - k);
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Probability of exactly `k` successes in `n` draws from `total` cards of
+/// which `matching` are successes.
+fn hypergeometric(total: usize, matching: usize, n: usize, k: usize) -> f64 {
+    if k > n || k > matching || n - k > total - matching {
+        return 0.0;
+    }
+    choose(matching, k) * choose(total - matching, n - k) / choose(total, n)
+}
+
+/// Number of ways to draw `draws` cards taking at least `mins[i]` from each
+/// category of size `sizes[i]` and the remainder from `rest` neutral cards.
+fn multivariate_ways(sizes: &[usize], mins: &[usize], rest: usize, draws: usize) -> f64 {
+    match sizes.split_first() {
+        None => choose(rest, draws),
+        Some((&size, other_sizes)) => (mins[0]..=size.min(draws))
+            .map(|k| choose(size, k) * multivariate_ways(other_sizes, &mins[1..], rest, draws - k))
+            .sum(),
+    }
+}
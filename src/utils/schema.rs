@@ -0,0 +1,154 @@
+use serde_json::{json, Value};
+
+use crate::ENVELOPE_VERSION;
+
+/// The JSON Schema dialect every schema in this module declares.
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Returns the JSON Schema for a serialized [`Card`](crate::Card).
+///
+/// A card is an object with a `suit` (`"Hearts"`, `"Diamonds"`, `"Clubs"` or
+/// `"Spades"`) and a `rank` (`"Two"` through `"Ace"`, or `"Joker"`).
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::card_schema;
+///
+/// let schema = card_schema();
+/// assert_eq!(schema["required"], serde_json::json!(["suit", "rank"]));
+/// ```
+pub fn card_schema() -> Value {
+    let mut schema = card_definition();
+    schema["$schema"] = json!(DIALECT);
+    schema["title"] = json!("Card");
+    schema
+}
+
+/// Returns the JSON Schema for a [`Deck`](crate::Deck) as written by
+/// [`Deck::to_json`](crate::Deck::to_json).
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::deck_schema;
+///
+/// let schema = deck_schema();
+/// assert_eq!(schema["properties"]["cards"]["type"], "array");
+/// ```
+pub fn deck_schema() -> Value {
+    json!({
+        "$schema": DIALECT,
+        "title": "Deck",
+        "type": "object",
+        "properties": {
+            "cards": {
+                "type": "array",
+                "items": { "$ref": "#/$defs/card" }
+            }
+        },
+        "required": ["cards"],
+        "additionalProperties": false,
+        "$defs": { "card": card_definition() }
+    })
+}
+
+/// Returns the JSON Schema for a serialized [`PackedDeck`](crate::PackedDeck).
+///
+/// A packed deck is an array of card bytes (`suit * 14 + rank`, 0 to 55).
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::packed_deck_schema;
+///
+/// let schema = packed_deck_schema();
+/// assert_eq!(schema["items"]["maximum"], 55);
+/// ```
+pub fn packed_deck_schema() -> Value {
+    json!({
+        "$schema": DIALECT,
+        "title": "PackedDeck",
+        "type": "array",
+        "items": { "type": "integer", "minimum": 0, "maximum": 55 }
+    })
+}
+
+/// Returns the JSON Schema for a serialized
+/// [`PermutationIndex`](crate::PermutationIndex).
+///
+/// A permutation index is a base64 string. URL-safe unpadded base64 is
+/// written, and the standard alphabet and padding are accepted on input.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::permutation_index_schema;
+///
+/// let schema = permutation_index_schema();
+/// assert_eq!(schema["type"], "string");
+/// ```
+pub fn permutation_index_schema() -> Value {
+    json!({
+        "$schema": DIALECT,
+        "title": "PermutationIndex",
+        "type": "string",
+        "pattern": "^[A-Za-z0-9_+/-]*={0,2}$"
+    })
+}
+
+/// Returns the JSON Schema for a [`DeckEnvelope`](crate::DeckEnvelope).
+///
+/// The schema checks the envelope's shape only. The card count and checksum
+/// are checked by [`DeckEnvelope::validate`](crate::DeckEnvelope::validate).
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{deck_envelope_schema, ENVELOPE_VERSION};
+///
+/// let schema = deck_envelope_schema();
+/// assert_eq!(schema["properties"]["format_version"]["const"], ENVELOPE_VERSION);
+/// ```
+pub fn deck_envelope_schema() -> Value {
+    let mut deck = deck_schema();
+    let defs = deck["$defs"].take();
+    if let Value::Object(deck) = &mut deck {
+        deck.remove("$schema");
+        deck.remove("$defs");
+    }
+    json!({
+        "$schema": DIALECT,
+        "title": "DeckEnvelope",
+        "type": "object",
+        "properties": {
+            "format_version": { "const": ENVELOPE_VERSION },
+            "factory": { "type": "string" },
+            "seed": { "type": "integer", "minimum": 0 },
+            "created_at": { "type": "string" },
+            "card_count": { "type": "integer", "minimum": 0 },
+            "checksum": { "type": "string", "pattern": "^[0-9a-fA-F]{8}$" },
+            "deck": deck
+        },
+        "required": ["format_version", "card_count", "checksum", "deck"],
+        "additionalProperties": false,
+        "$defs": defs
+    })
+}
+
+fn card_definition() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "suit": { "enum": ["Hearts", "Diamonds", "Clubs", "Spades"] },
+            "rank": {
+                "enum": [
+                    "Two", "Three", "Four", "Five", "Six", "Seven", "Eight",
+                    "Nine", "Ten", "Jack", "Queen", "King", "Ace", "Joker"
+                ]
+            }
+        },
+        "required": ["suit", "rank"],
+        "additionalProperties": false
+    })
+}
@@ -0,0 +1,410 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{
+    Card, Deck, DeckFactory, Doppelkopf48, Euchre24, Rank, Schafkopf24, Skat32, Standard52,
+    Standard54, Suit,
+};
+
+/// Builds decks from a compact text description.
+///
+/// A spec combines terms with `+` (add cards) and `-` (remove every copy of
+/// the named cards), using [`Deck`]'s `Add` and `Sub` operators. A term can
+/// be repeated with a count in front (`2x standard52`, `4 jokers`) or a
+/// multiplier after it (`standard52 * 6`), using `Mul<usize>`. Parentheses
+/// group terms. Cards end up in the order those operators leave them. The
+/// terms are:
+///
+/// | Term                           | Cards                                          |
+/// |--------------------------------|------------------------------------------------|
+/// | `standard52`, `euchre24`       | the registered factory of that name            |
+/// | `ranks 9..A of all suits`      | a rank range (or comma list) in some suits     |
+/// | `ranks 2..6`                   | the same, in all four suits                    |
+/// | `joker red`, `joker black`     | the red (♥) or black (♠) joker                 |
+/// | `joker`, `jokers`              | jokers alternating red and black               |
+/// | `2C`, `A♠`, `10H`              | a single card, parsed with [`Card`]'s `FromStr`|
+///
+/// Suits after `of` are `all`, `red`, `black` or a comma list of suit names,
+/// optionally followed by the word `suits`. Words are case-insensitive.
+/// Further factories can be registered with [`DeckSpec::with_factory`].
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Card, DeckSpec, Rank, Suit};
+///
+/// let spec = DeckSpec::new();
+/// assert_eq!(spec.build("standard52 * 6").unwrap().len(), 312);
+/// assert_eq!(spec.build("ranks 9..A of all suits * 2").unwrap().len(), 48);
+/// assert_eq!(spec.build("standard52 - ranks 2..6").unwrap().len(), 32);
+///
+/// let deck = spec.build("2x standard52 + 4 jokers - 2C").unwrap();
+/// assert_eq!(deck.len(), 106);
+/// assert!(!deck.contains(&Card::new(Suit::Clubs, Rank::Two)));
+/// ```
+pub struct DeckSpec {
+    factories: Vec<(String, Box<dyn DeckFactory>)>,
+}
+
+impl DeckSpec {
+    /// Creates a spec builder that knows `standard52`, `standard54`,
+    /// `euchre24`, `skat32`, `schafkopf24` and `doppelkopf48`.
+    pub fn new() -> Self {
+        let mut spec = DeckSpec {
+            factories: Vec::new(),
+        };
+        spec.register("standard52", Standard52);
+        spec.register("standard54", Standard54);
+        spec.register("euchre24", Euchre24);
+        spec.register("skat32", Skat32);
+        spec.register("schafkopf24", Schafkopf24);
+        spec.register("doppelkopf48", Doppelkopf48);
+        spec
+    }
+
+    /// Registers a factory under `name`, replacing any factory already
+    /// registered under that name. Names are case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` could not be referenced in a spec: if it is
+    /// not a single word, contains one of `+ - * ( ) ,`, parses as a card or
+    /// a count, or is one of the words `rank`, `ranks`, `joker`, `jokers` or
+    /// `x`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crusty_cards::{Card, DeckFactory, DeckSpec, Rank, Suit};
+    /// use std::collections::VecDeque;
+    ///
+    /// struct Aces;
+    ///
+    /// impl DeckFactory for Aces {
+    ///     fn generate(&self) -> VecDeque<Card> {
+    ///         Suit::ALL.iter().map(|&suit| Card::new(suit, Rank::Ace)).collect()
+    ///     }
+    /// }
+    ///
+    /// let spec = DeckSpec::new().with_factory("four_aces", Aces).unwrap();
+    /// assert_eq!(spec.build("four_aces * 2 + joker").unwrap().len(), 9);
+    ///
+    /// // "aces" reads as the ace of spades.
+    /// assert!(DeckSpec::new().with_factory("aces", Aces).is_err());
+    /// ```
+    pub fn with_factory<F>(mut self, name: &str, factory: F) -> Result<Self, String>
+    where
+        F: DeckFactory + 'static,
+    {
+        check_factory_name(name)?;
+        self.register(name, factory);
+        Ok(self)
+    }
+
+    fn register<F>(&mut self, name: &str, factory: F)
+    where
+        F: DeckFactory + 'static,
+    {
+        let name = name.to_lowercase();
+        self.factories.retain(|(existing, _)| *existing != name);
+        self.factories.push((name, Box::new(factory)));
+    }
+
+    /// Returns the registered factory names, in registration order.
+    pub fn factory_names(&self) -> impl Iterator<Item = &str> {
+        self.factories.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Builds the deck described by `spec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first problem found, such as an
+    /// unknown word, a bad rank range or a missing operand.
+    pub fn build(&self, spec: &str) -> Result<Deck, String> {
+        let tokens = tokenize(spec);
+        let mut parser = Parser {
+            spec: self,
+            tokens: &tokens,
+            pos: 0,
+        };
+        let deck = parser.expr()?;
+        match parser.peek() {
+            None => Ok(deck),
+            Some(token) => Err(format!("Unexpected '{}'", token)),
+        }
+    }
+
+    fn factory(&self, name: &str) -> Option<&dyn DeckFactory> {
+        self.factories
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, factory)| factory.as_ref())
+    }
+}
+
+impl Default for DeckSpec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for DeckSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeckSpec")
+            .field("factories", &self.factory_names().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Joker colors in the order uncolored jokers are handed out: red, then black.
+const JOKER_SUITS: [Suit; 2] = [Suit::Hearts, Suit::Spades];
+
+/// Words with a meaning of their own in a spec, which a factory name would
+/// shadow or be shadowed by.
+const RESERVED_WORDS: [&str; 5] = ["rank", "ranks", "joker", "jokers", "x"];
+
+/// Checks that `name` can be referenced as a factory in a spec.
+fn check_factory_name(name: &str) -> Result<(), String> {
+    if tokenize(name) != [name] {
+        return Err(format!(
+            "Invalid factory name '{}': names must be a single word",
+            name
+        ));
+    }
+    let lower = name.to_lowercase();
+    let digits = lower.strip_suffix('x').unwrap_or(&lower);
+    if RESERVED_WORDS.contains(&lower.as_str()) || digits.parse::<usize>().is_ok() {
+        return Err(format!(
+            "Invalid factory name '{}': the word is reserved",
+            name
+        ));
+    }
+    if Card::from_str(name).is_ok() {
+        return Err(format!(
+            "Invalid factory name '{}': the name is a card",
+            name
+        ));
+    }
+    Ok(())
+}
+
+/// Splits a spec into words and the single-character symbols `+ - * ( ) ,`.
+fn tokenize(spec: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    for c in spec.chars() {
+        if c.is_whitespace() || "+-*(),".contains(c) {
+            if !word.is_empty() {
+                tokens.push(std::mem::take(&mut word));
+            }
+            if !c.is_whitespace() {
+                tokens.push(c.to_string());
+            }
+        } else {
+            word.push(c);
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+struct Parser<'a> {
+    spec: &'a DeckSpec,
+    tokens: &'a [String],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn peek_lower(&self) -> Option<String> {
+        self.peek().map(str::to_lowercase)
+    }
+
+    fn next(&mut self) -> Result<&str, String> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .ok_or_else(|| "Unexpected end of deck spec".to_string())?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, word: &str) -> bool {
+        if self.peek_lower().as_deref() == Some(word) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// expr := term (('+' | '-') term)*
+    fn expr(&mut self) -> Result<Deck, String> {
+        let mut deck = self.term()?;
+        loop {
+            if self.eat("+") {
+                deck += self.term()?;
+            } else if self.eat("-") {
+                deck -= self.term()?;
+            } else {
+                return Ok(deck);
+            }
+        }
+    }
+
+    /// term := [count ['x']] atom ('*' count)*
+    fn term(&mut self) -> Result<Deck, String> {
+        let mut times = 1;
+        if let Some(token) = self.peek() {
+            let digits = token
+                .strip_suffix(['x', 'X'])
+                .filter(|digits| !digits.is_empty())
+                .unwrap_or(token);
+            if let Ok(count) = digits.parse::<usize>() {
+                let bare = digits.len() == token.len();
+                self.pos += 1;
+                if bare {
+                    self.eat("x");
+                }
+                times = count;
+            }
+        }
+        let mut deck = if self.uncolored_jokers() {
+            // "4 jokers" means two of each color rather than four red jokers.
+            let count = std::mem::replace(&mut times, 1);
+            (0..count)
+                .map(|i| Card::new(JOKER_SUITS[i % 2], Rank::Joker))
+                .collect()
+        } else {
+            self.atom()?
+        };
+        while self.eat("*") {
+            let count = self.next()?;
+            times *= count
+                .parse::<usize>()
+                .map_err(|_| format!("Expected a count after '*' but found '{}'", count))?;
+        }
+        if times != 1 {
+            deck *= times;
+        }
+        Ok(deck)
+    }
+
+    /// Consumes `joker` or `jokers` if no color follows it.
+    fn uncolored_jokers(&mut self) -> bool {
+        let is_joker = matches!(self.peek_lower().as_deref(), Some("joker" | "jokers"));
+        let color = self
+            .tokens
+            .get(self.pos + 1)
+            .map(|token| token.to_lowercase());
+        if is_joker && !matches!(color.as_deref(), Some("red" | "black")) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn atom(&mut self) -> Result<Deck, String> {
+        let token = self.next()?.to_string();
+        let word = token.to_lowercase();
+        match word.as_str() {
+            "(" => {
+                let deck = self.expr()?;
+                if !self.eat(")") {
+                    return Err("Expected ')'".to_string());
+                }
+                Ok(deck)
+            }
+            "ranks" | "rank" => {
+                let ranks = self.ranks()?;
+                let suits = if self.eat("of") {
+                    self.suits()?
+                } else {
+                    Suit::ALL.to_vec()
+                };
+                Ok(ranks
+                    .iter()
+                    .flat_map(|&rank| suits.iter().map(move |&suit| Card::new(suit, rank)))
+                    .collect())
+            }
+            "joker" | "jokers" => {
+                let suit = if self.eat("black") {
+                    Suit::Spades
+                } else {
+                    self.eat("red");
+                    Suit::Hearts
+                };
+                Ok(std::iter::once(Card::new(suit, Rank::Joker)).collect())
+            }
+            _ => {
+                if let Some(factory) = self.spec.factory(&word) {
+                    return Ok(factory.generate().into_iter().collect());
+                }
+                Card::from_str(&token)
+                    .map(|card| std::iter::once(card).collect())
+                    .map_err(|_| format!("Unknown deck term '{}'", token))
+            }
+        }
+    }
+
+    /// ranks := rank_item (',' rank_item)*, where rank_item is `R` or `R..R`.
+    fn ranks(&mut self) -> Result<Vec<Rank>, String> {
+        let mut ranks = Vec::new();
+        loop {
+            let item = self.next()?.to_string();
+            match item.split_once("..") {
+                Some((low, high)) => {
+                    let (low, high) = (parse_rank(low)?, parse_rank(high)?);
+                    if low.value() > high.value() {
+                        return Err(format!("Invalid rank range '{}'", item));
+                    }
+                    ranks.extend(
+                        Rank::STANDARD
+                            .iter()
+                            .filter(|rank| (low.value()..=high.value()).contains(&rank.value())),
+                    );
+                }
+                None => ranks.push(parse_rank(&item)?),
+            }
+            if !self.eat(",") {
+                return Ok(ranks);
+            }
+        }
+    }
+
+    /// suits := ('all' | 'red' | 'black' | suit (',' suit)*) ['suits']
+    fn suits(&mut self) -> Result<Vec<Suit>, String> {
+        let suits = if self.eat("all") {
+            Suit::ALL.to_vec()
+        } else if self.eat("red") {
+            Suit::RED.to_vec()
+        } else if self.eat("black") {
+            Suit::BLACK.to_vec()
+        } else {
+            let mut suits = Vec::new();
+            loop {
+                let name = self.next()?;
+                suits.push(Suit::from_str(name).map_err(|_| format!("Unknown suit '{}'", name))?);
+                if !self.eat(",") {
+                    break suits;
+                }
+            }
+        };
+        self.eat("suits");
+        Ok(suits)
+    }
+}
+
+fn parse_rank(s: &str) -> Result<Rank, String> {
+    match Rank::from_str(s) {
+        Ok(Rank::Joker) | Err(_) => Err(format!("Unknown rank '{}'", s)),
+        Ok(rank) => Ok(rank),
+    }
+}
@@ -0,0 +1,218 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::{Card, DealPattern, DealTarget, Deck, DeckFactory};
+
+/// Builds a [`Deck`] stacked so that dealing it hands out chosen cards.
+///
+/// Given a number of players and a [`DealPattern`], the builder places the
+/// requested cards at the positions [`Deck::deal_to`] will deal to each seat
+/// and to the kitty. Cards requested with [`board`](StackedDeckBuilder::board)
+/// come right after the dealt cards, in order. All other positions are filled
+/// with the factory's remaining cards, in factory order or shuffled.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{DealPattern, Deck, Standard52, StackedDeckBuilder};
+///
+/// let pattern = DealPattern::round_robin(2);
+/// let mut deck = StackedDeckBuilder::new(Standard52)
+///     .players(4)
+///     .pattern(pattern.clone())
+///     .seat(2, "AS AH")
+///     .board("KD 7C 2H")
+///     .fill_random()
+///     .build()
+///     .unwrap();
+///
+/// let deal = deck.deal_to(4, &pattern).unwrap();
+/// let hand: Deck = deal.hand(2).unwrap().iter().copied().collect();
+/// assert_eq!(hand.to_string(), "A♠ A♥");
+///
+/// let board: Deck = deck.deal_n(3).unwrap().into_iter().collect();
+/// assert_eq!(board.to_string(), "K♦ 7♣ 2♥");
+/// ```
+#[derive(Debug, Clone)]
+pub struct StackedDeckBuilder {
+    cards: Vec<Card>,
+    players: usize,
+    pattern: DealPattern,
+    seats: BTreeMap<usize, Vec<Card>>,
+    kitty: Vec<Card>,
+    board: Vec<Card>,
+    shuffle_rest: bool,
+    errors: Vec<String>,
+}
+
+impl StackedDeckBuilder {
+    /// Starts a builder over the cards generated by `factory`.
+    ///
+    /// Defaults to one player, an empty pattern and factory order for the rest.
+    pub fn new<F>(factory: F) -> Self
+    where
+        F: DeckFactory,
+    {
+        StackedDeckBuilder {
+            cards: factory.generate().into_iter().collect(),
+            players: 1,
+            pattern: DealPattern::default(),
+            seats: BTreeMap::new(),
+            kitty: Vec::new(),
+            board: Vec::new(),
+            shuffle_rest: false,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Sets the number of players the deck will be dealt to.
+    pub fn players(mut self, players: usize) -> Self {
+        self.players = players;
+        self
+    }
+
+    /// Sets the pattern the deck will be dealt with.
+    pub fn pattern(mut self, pattern: DealPattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    /// Requests that `seat` receives `cards` (space separated, e.g. "AS AH"),
+    /// in the order it is dealt them.
+    pub fn seat(mut self, seat: usize, cards: &str) -> Self {
+        let cards = self.parse(cards);
+        self.seats.entry(seat).or_default().extend(cards);
+        self
+    }
+
+    /// Requests that the kitty receives `cards`, in the order they are dealt.
+    pub fn kitty(mut self, cards: &str) -> Self {
+        let cards = self.parse(cards);
+        self.kitty.extend(cards);
+        self
+    }
+
+    /// Requests that `cards` are the next cards on top of the deck after dealing.
+    pub fn board(mut self, cards: &str) -> Self {
+        let cards = self.parse(cards);
+        self.board.extend(cards);
+        self
+    }
+
+    /// Fills the remaining positions with the factory's leftover cards in random order.
+    pub fn fill_random(mut self) -> Self {
+        self.shuffle_rest = true;
+        self
+    }
+
+    /// Fills the remaining positions with the factory's leftover cards in factory order.
+    ///
+    /// This is the default.
+    pub fn fill_in_order(mut self) -> Self {
+        self.shuffle_rest = false;
+        self
+    }
+
+    fn parse(&mut self, cards: &str) -> Vec<Card> {
+        match Deck::from_str(cards) {
+            Ok(deck) => deck.into_iter().collect(),
+            Err(e) => {
+                self.errors.push(e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Builds the stacked deck using the thread-local random number generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a card string failed to parse, if a requested card is
+    /// not available in the factory (or requested more often than it occurs),
+    /// if a seat or the kitty is asked to receive more cards than the pattern
+    /// deals it, or if the factory has too few cards for the pattern and board.
+    pub fn build(&self) -> Result<Deck, String> {
+        self.build_with_rng(&mut rand::rng())
+    }
+
+    /// Builds the stacked deck, shuffling leftover cards with `rng` if requested.
+    ///
+    /// # Errors
+    ///
+    /// See [`StackedDeckBuilder::build`].
+    pub fn build_with_rng<R>(&self, rng: &mut R) -> Result<Deck, String>
+    where
+        R: Rng + ?Sized,
+    {
+        if let Some(e) = self.errors.first() {
+            return Err(e.clone());
+        }
+        let schedule = self.pattern.schedule(self.players);
+        if schedule.len() + self.board.len() > self.cards.len() {
+            return Err(format!(
+                "Factory has {} cards but the deal and board need {}",
+                self.cards.len(),
+                schedule.len() + self.board.len()
+            ));
+        }
+
+        let mut slots: Vec<Option<Card>> = vec![None; self.cards.len()];
+        let mut seat_queues: BTreeMap<usize, std::slice::Iter<'_, Card>> = self
+            .seats
+            .iter()
+            .map(|(seat, cards)| (*seat, cards.iter()))
+            .collect();
+        let mut kitty_queue = self.kitty.iter();
+        for (position, target) in schedule.iter().enumerate() {
+            let next = match target {
+                DealTarget::Seat(seat) => seat_queues.get_mut(seat).and_then(Iterator::next),
+                DealTarget::Kitty => kitty_queue.next(),
+            };
+            slots[position] = next.copied();
+        }
+        for (seat, mut queue) in seat_queues {
+            if queue.next().is_some() {
+                return Err(format!(
+                    "Seat {} is dealt {} cards but {} were requested",
+                    seat,
+                    schedule
+                        .iter()
+                        .filter(|t| **t == DealTarget::Seat(seat))
+                        .count(),
+                    self.seats[&seat].len()
+                ));
+            }
+        }
+        if kitty_queue.next().is_some() {
+            return Err(format!(
+                "The kitty is dealt {} cards but {} were requested",
+                schedule.iter().filter(|t| **t == DealTarget::Kitty).count(),
+                self.kitty.len()
+            ));
+        }
+        for (offset, card) in self.board.iter().enumerate() {
+            slots[schedule.len() + offset] = Some(*card);
+        }
+
+        let mut rest = self.cards.clone();
+        for card in slots.iter().flatten() {
+            match rest.iter().position(|c| c == card) {
+                Some(index) => {
+                    rest.remove(index);
+                }
+                None => return Err(format!("Card {} is not available in this deck", card)),
+            }
+        }
+        if self.shuffle_rest {
+            rest.shuffle(rng);
+        }
+
+        let mut rest = rest.into_iter();
+        Ok(slots
+            .into_iter()
+            .flat_map(|slot| slot.or_else(|| rest.next()))
+            .collect())
+    }
+}
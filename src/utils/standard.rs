@@ -50,3 +50,95 @@ impl DeckFactory for Standard54 {
         cards
     }
 }
+
+/// Factory for a 24-card Euchre deck.
+///
+/// Generates the Nine through Ace of each suit, ordered by rank, then by suit
+/// within each rank.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, Euchre24};
+///
+/// let deck = Deck::from_factory(Euchre24);
+/// assert_eq!(deck.len(), 24);
+/// ```
+pub struct Euchre24;
+
+impl DeckFactory for Euchre24 {
+    fn generate(&self) -> VecDeque<Card> {
+        Rank::STANDARD[7..]
+            .iter()
+            .flat_map(|&rank| Suit::ALL.iter().map(move |&suit| Card::new(suit, rank)))
+            .collect()
+    }
+}
+
+/// Factory for a 32-card Skat deck.
+///
+/// Generates the Seven through Ace of each suit, ordered by rank, then by
+/// suit within each rank. The same cards make up the long Schafkopf deck.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, Skat32};
+///
+/// let deck = Deck::from_factory(Skat32);
+/// assert_eq!(deck.len(), 32);
+/// ```
+pub struct Skat32;
+
+impl DeckFactory for Skat32 {
+    fn generate(&self) -> VecDeque<Card> {
+        Rank::STANDARD[5..]
+            .iter()
+            .flat_map(|&rank| Suit::ALL.iter().map(move |&suit| Card::new(suit, rank)))
+            .collect()
+    }
+}
+
+/// Factory for a 24-card short Schafkopf deck.
+///
+/// Generates the Nine through Ace of each suit, the same cards as
+/// [`Euchre24`]. Use [`Skat32`] for the long 32-card deck.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, Schafkopf24};
+///
+/// let deck = Deck::from_factory(Schafkopf24);
+/// assert_eq!(deck.len(), 24);
+/// ```
+pub struct Schafkopf24;
+
+impl DeckFactory for Schafkopf24 {
+    fn generate(&self) -> VecDeque<Card> {
+        Euchre24.generate()
+    }
+}
+
+/// Factory for a 48-card Doppelkopf deck.
+///
+/// Generates two copies of the Nine through Ace of each suit, as two
+/// [`Euchre24`] decks one after the other.
+///
+/// # Examples
+///
+/// ```rust
+/// use crusty_cards::{Deck, Doppelkopf48};
+///
+/// let deck = Deck::from_factory(Doppelkopf48);
+/// assert_eq!(deck.len(), 48);
+/// ```
+pub struct Doppelkopf48;
+
+impl DeckFactory for Doppelkopf48 {
+    fn generate(&self) -> VecDeque<Card> {
+        let mut cards = Euchre24.generate();
+        cards.extend(Euchre24.generate());
+        cards
+    }
+}
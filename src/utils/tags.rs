@@ -0,0 +1,40 @@
+/// Parses `[Name "Value"]`, returning the name, the unescaped value and the
+/// text after the closing bracket.
+pub(crate) fn parse_tag(s: &str) -> Result<(String, String, &str), String> {
+    let body = s[1..].trim_start();
+    let name_end = body
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(body.len());
+    let name = &body[..name_end];
+    if name.is_empty() {
+        return Err(format!("Missing tag name: {}", s));
+    }
+    let rest = body[name_end..].trim_start();
+    let quoted = rest
+        .strip_prefix('"')
+        .ok_or_else(|| format!("Missing quoted value for tag {}", name))?;
+    let mut value = String::new();
+    let mut chars = quoted.char_indices();
+    let close = loop {
+        match chars.next() {
+            Some((_, '\\')) => match chars.next() {
+                Some((_, c)) => value.push(c),
+                None => break None,
+            },
+            Some((i, '"')) => break Some(i),
+            Some((_, c)) => value.push(c),
+            None => break None,
+        }
+    };
+    let close = close.ok_or_else(|| format!("Unterminated value for tag {}", name))?;
+    let after = quoted[close + 1..].trim_start();
+    let after = after
+        .strip_prefix(']')
+        .ok_or_else(|| format!("Missing ']' after tag {}", name))?;
+    Ok((name.to_string(), value, after))
+}
+
+/// Escapes backslashes and quotes for a tag value.
+pub(crate) fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
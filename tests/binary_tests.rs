@@ -0,0 +1,170 @@
+use crusty_cards::{
+    Deck, DeckReader, DeckWriter, PackedDeck, Standard52, Standard54, BINARY_MAGIC, BINARY_VERSION,
+};
+use std::io::{self, ErrorKind};
+
+#[test]
+fn test_binary_layout() {
+    let deck: Deck = "A♠ 2♥".parse().unwrap();
+    let bytes = deck.to_binary();
+    assert_eq!(&bytes[..4], &BINARY_MAGIC);
+    assert_eq!(bytes[4], BINARY_VERSION);
+    assert_eq!(bytes[5], 0);
+    assert_eq!(&bytes[6..10], &2u32.to_le_bytes());
+    assert_eq!(&bytes[10..12], &[54, 0]);
+    assert_eq!(bytes.len(), 16);
+}
+
+/// Bitwise CRC-32 (IEEE), independent of the table-driven implementation.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+#[test]
+fn test_binary_checksum_is_crc32() {
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    let bytes = Deck::from_factory(Standard52).to_binary();
+    let (body, checksum) = bytes.split_at(bytes.len() - 4);
+    assert_eq!(checksum, &crc32(body).to_le_bytes());
+}
+
+#[test]
+fn test_binary_round_trip() {
+    let mut deck = Deck::from_factory(Standard54);
+    deck.shuffle();
+    assert_eq!(Deck::from_binary(&deck.to_binary()).unwrap(), deck);
+    assert_eq!(
+        Deck::from_binary(&Deck::default().to_binary()).unwrap(),
+        Deck::default()
+    );
+}
+
+#[test]
+fn test_binary_stream_of_records() {
+    let mut writer = DeckWriter::new(Vec::new());
+    let mut decks = Vec::new();
+    for _ in 0..10 {
+        let mut deck = Deck::from_factory(Standard52);
+        deck.shuffle();
+        writer.write_deck(&deck).unwrap();
+        decks.push(deck);
+    }
+    writer.flush().unwrap();
+    let bytes = writer.into_inner();
+
+    let mut reader = DeckReader::new(bytes.as_slice());
+    for deck in &decks {
+        let record = reader.read_record().unwrap().unwrap();
+        assert_eq!(record.deck(), deck);
+        assert_eq!(record.factory_id(), None);
+    }
+    assert!(reader.read_record().unwrap().is_none());
+    assert!(reader.into_inner().is_empty());
+}
+
+#[test]
+fn test_binary_factory_id() {
+    let mut writer = DeckWriter::new(Vec::new()).with_factory_id("standard54");
+    writer.write_deck(&Deck::from_factory(Standard54)).unwrap();
+    let bytes = writer.into_inner();
+    assert_eq!(bytes[5], 1);
+    assert_eq!(bytes[6], 10);
+    assert_eq!(&bytes[7..17], b"standard54");
+
+    let record = DeckReader::new(bytes.as_slice()).next().unwrap().unwrap();
+    assert_eq!(record.factory_id(), Some("standard54"));
+    assert_eq!(record.clone().into_deck(), Deck::from_factory(Standard54));
+    assert_eq!(Deck::from_binary(&bytes).unwrap().len(), 54);
+
+    let mut too_long = DeckWriter::new(Vec::new()).with_factory_id(&"x".repeat(256));
+    let err = too_long.write_deck(&Deck::default()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn test_binary_packed_deck() {
+    let mut packed = PackedDeck::from_factory(Standard52);
+    packed.deal_n(2).unwrap();
+    let mut writer = DeckWriter::new(Vec::new());
+    writer.write_packed(&packed).unwrap();
+    let deck = Deck::from_binary(&writer.into_inner()).unwrap();
+    assert_eq!(deck, packed.to_deck());
+}
+
+fn error_kind(bytes: &[u8]) -> (ErrorKind, String) {
+    let err: io::Error = Deck::from_binary(bytes).unwrap_err();
+    (err.kind(), err.to_string())
+}
+
+#[test]
+fn test_binary_corruption_detected() {
+    let deck = Deck::from_factory(Standard52);
+    let mut bytes = deck.to_binary();
+    bytes[20] ^= 0x01;
+    assert_eq!(
+        error_kind(&bytes),
+        (ErrorKind::InvalidData, "Checksum mismatch".to_string())
+    );
+
+    let mut bytes = deck.to_binary();
+    bytes[0] = b'X';
+    assert_eq!(
+        error_kind(&bytes),
+        (ErrorKind::InvalidData, "Invalid magic bytes".to_string())
+    );
+
+    let mut bytes = deck.to_binary();
+    bytes[4] = 9;
+    assert_eq!(
+        error_kind(&bytes),
+        (
+            ErrorKind::InvalidData,
+            "Unsupported binary deck version 9".to_string()
+        )
+    );
+
+    let mut bytes = deck.to_binary();
+    bytes[5] = 0x80;
+    assert_eq!(error_kind(&bytes).0, ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_binary_truncation_and_trailing_bytes() {
+    let bytes = Deck::from_factory(Standard52).to_binary();
+    for len in [0, 2, 5, 9, 30, bytes.len() - 1] {
+        assert_eq!(error_kind(&bytes[..len]).0, ErrorKind::UnexpectedEof);
+    }
+    let mut extra = bytes.clone();
+    extra.push(0);
+    assert_eq!(
+        error_kind(&extra),
+        (
+            ErrorKind::InvalidData,
+            "Trailing bytes after deck record".to_string()
+        )
+    );
+}
+
+#[test]
+fn test_binary_invalid_card_byte() {
+    let mut bytes = Deck::from_factory(Standard52).to_binary();
+    bytes[10] = 99;
+    let len = bytes.len();
+    let checksum = crc32(&bytes[..len - 4]);
+    bytes[len - 4..].copy_from_slice(&checksum.to_le_bytes());
+    assert_eq!(
+        error_kind(&bytes),
+        (ErrorKind::InvalidData, "Invalid card value: 99".to_string())
+    );
+}
@@ -490,6 +490,7 @@ fn test_card_from_str() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_card_as_csv_row() {
     let card = Card::new(Suit::Hearts, Rank::Two);
     assert_eq!(card.as_csv_row(), "2,♥");
@@ -33,6 +33,7 @@ fn test_color_equality() {
 }
 
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn test_color_clone_copy() {
     let color = Color::Red;
     let cloned = color.clone();
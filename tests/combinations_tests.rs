@@ -0,0 +1,151 @@
+use crusty_cards::{
+    binomial, Card, CombinationIndexer, Combinations, Deck, Rank, Standard52, Suit,
+};
+use std::collections::HashSet;
+
+mod common;
+
+use common::cards;
+
+#[test]
+fn test_binomial() {
+    assert_eq!(binomial(0, 0), 1);
+    assert_eq!(binomial(5, 0), 1);
+    assert_eq!(binomial(5, 5), 1);
+    assert_eq!(binomial(52, 2), 1326);
+    assert_eq!(binomial(52, 7), 133_784_560);
+    assert_eq!(binomial(56, 28), 7_648_690_600_760_440);
+    assert_eq!(binomial(200, 100), u64::MAX);
+}
+
+#[test]
+fn test_combinations_small() {
+    let all: Vec<Vec<Card>> = Combinations::new(&cards("A♠ K♠ Q♠ J♠"), 2).collect();
+    let shown: Vec<String> = all
+        .iter()
+        .map(|c| c.iter().map(Card::to_string).collect::<Vec<_>>().join(" "))
+        .collect();
+    assert_eq!(
+        shown,
+        vec!["A♠ K♠", "A♠ Q♠", "K♠ Q♠", "A♠ J♠", "K♠ J♠", "Q♠ J♠"]
+    );
+}
+
+#[test]
+fn test_combinations_edge_cases() {
+    let source = cards("A♠ K♠ Q♠");
+    assert_eq!(
+        Combinations::new(&source, 0).collect::<Vec<_>>(),
+        vec![Vec::<Card>::new()]
+    );
+    assert_eq!(Combinations::new(&source, 4).count(), 0);
+    assert_eq!(Combinations::new(&source, 3).count(), 1);
+    assert_eq!(Combinations::new(&[], 0).count(), 1);
+}
+
+#[test]
+fn test_combinations_count_and_size_hint() {
+    let deck = Deck::from_factory(Standard52);
+    let mut hands = deck.combinations(3);
+    assert_eq!(hands.size_hint(), (22100, Some(22100)));
+    hands.next();
+    assert_eq!(hands.remaining(), 22099);
+    assert_eq!(hands.count(), 22099);
+
+    let distinct: HashSet<Vec<Card>> = deck.combinations(2).collect();
+    assert_eq!(distinct.len(), 1326);
+}
+
+#[test]
+fn test_combinations_for_each_slice() {
+    let deck = Deck::from_factory(Standard52);
+    let mut count = 0u64;
+    let mut flushes = 0u64;
+    deck.combinations(5).for_each_slice(|hand| {
+        count += 1;
+        if hand.iter().all(|c| c.suit() == hand[0].suit()) {
+            flushes += 1;
+        }
+    });
+    assert_eq!(count, 2_598_960);
+    assert_eq!(flushes, 5148);
+}
+
+#[test]
+fn test_combinations_excluding_dead_cards() {
+    let deck = Deck::from_factory(Standard52);
+    let dead = cards("A♠ A♥ K♦");
+    let boards = deck.combinations_excluding(3, &dead);
+    assert_eq!(boards.remaining(), binomial(49, 3));
+    let mut seen_dead = false;
+    boards.for_each_slice(|board| seen_dead |= board.iter().any(|c| dead.contains(c)));
+    assert!(!seen_dead);
+
+    let doubled: Vec<Card> = (deck.clone() * 2).into_iter().collect();
+    assert_eq!(Combinations::excluding(&doubled, 1, &dead).count(), 104 - 6);
+}
+
+#[test]
+fn test_indexer_matches_iteration_order() {
+    let reference = cards("A♠ K♠ Q♠ J♠ T♠ 9♠");
+    let indexer = CombinationIndexer::new(&reference, 3).unwrap();
+    assert_eq!(indexer.count(), 20);
+    for (i, combination) in Combinations::new(&reference, 3).enumerate() {
+        assert_eq!(indexer.index_of(&combination).unwrap(), i as u64);
+        assert_eq!(indexer.combination_at(i as u64).unwrap(), combination);
+    }
+    assert_eq!(indexer.combination_at(20), None);
+}
+
+#[test]
+fn test_indexer_is_order_insensitive() {
+    let indexer = CombinationIndexer::from_factory(Standard52, 2).unwrap();
+    let a = indexer.index_of(&cards("A♠ K♥")).unwrap();
+    let b = indexer.index_of(&cards("K♥ A♠")).unwrap();
+    assert_eq!(a, b);
+    assert!(a < 1326);
+}
+
+#[test]
+fn test_indexer_dense_over_five_card_hands() {
+    let indexer = CombinationIndexer::from_factory(Standard52, 5).unwrap();
+    let mut next = 0u64;
+    let mut dense = true;
+    Deck::from_factory(Standard52)
+        .combinations(5)
+        .for_each_slice(|hand| {
+            dense &= indexer.index_of(hand).unwrap() == next;
+            next += 1;
+        });
+    assert!(dense);
+    assert_eq!(next, indexer.count());
+}
+
+#[test]
+fn test_indexer_errors() {
+    assert_eq!(
+        CombinationIndexer::new(&cards("A♠ A♠"), 1).unwrap_err(),
+        "Reference cards contain duplicate card A♠"
+    );
+    let indexer = CombinationIndexer::new(&cards("A♠ K♠ Q♠"), 2).unwrap();
+    assert_eq!(
+        indexer.index_of(&cards("A♠")).unwrap_err(),
+        "Expected 2 cards but found 1"
+    );
+    assert_eq!(
+        indexer.index_of(&cards("A♠ A♠")).unwrap_err(),
+        "Duplicate card A♠"
+    );
+    let joker = Card::new(Suit::Hearts, Rank::Joker);
+    assert_eq!(
+        indexer
+            .index_of(&[joker, Card::new(Suit::Spades, Rank::Ace)])
+            .unwrap_err(),
+        format!("Card {} is not in the reference cards", joker)
+    );
+
+    let empty = CombinationIndexer::new(&[], 0).unwrap();
+    assert_eq!(empty.count(), 1);
+    assert_eq!(empty.combination_at(0), Some(vec![]));
+    assert_eq!(empty.index_of(&[]), Ok(0));
+}
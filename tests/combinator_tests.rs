@@ -0,0 +1,106 @@
+use crusty_cards::{
+    AceLowComparator, BridgeComparator, Card, CardComparator, Deck, FnComparator, PackedDeck, Rank,
+    StandardComparator, Suit, SuitFirst, TrumpComparator,
+};
+use std::cmp::Ordering;
+
+fn sorted<C: CardComparator + ?Sized>(cards: &str, cmp: &C) -> String {
+    let mut deck: Deck = cards.parse().unwrap();
+    deck.sort_by_comparator(cmp);
+    deck.to_string()
+}
+
+#[test]
+fn test_reversed() {
+    let cmp = StandardComparator.reversed();
+    assert_eq!(sorted("2♥ A♠ 10♦", &cmp), "A♠ T♦ 2♥");
+    assert_eq!(cmp.rank_value(Rank::Ace), -12);
+    assert_eq!(
+        sorted("2♥ A♠ 10♦", &cmp.reversed()),
+        sorted("2♥ A♠ 10♦", &StandardComparator)
+    );
+}
+
+#[test]
+fn test_then_by_breaks_ties() {
+    let cmp = StandardComparator.then_by(BridgeComparator);
+    assert_eq!(sorted("A♠ A♣ K♥ A♥ A♦", &cmp), "K♥ A♣ A♦ A♥ A♠");
+    let ace_spades = Card::new(Suit::Spades, Rank::Ace);
+    assert_eq!(cmp.compare(&ace_spades, &ace_spades), Ordering::Equal);
+    assert_eq!(cmp.first().rank_value(Rank::Ace), 12);
+}
+
+#[test]
+fn test_suit_first() {
+    let cmp = AceLowComparator.suit_first();
+    assert_eq!(sorted("K♠ A♥ A♠ 5♥ 2♦", &cmp), "A♥ 5♥ 2♦ A♠ K♠");
+
+    let order = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+    let cmp = SuitFirst::new(StandardComparator.reversed()).with_suit_order(order);
+    assert_eq!(sorted("2♠ A♥ 3♣ K♥ 10♣", &cmp), "T♣ 3♣ A♥ K♥ 2♠");
+    assert_eq!(cmp.suit_order(), order);
+}
+
+#[test]
+fn test_color_grouped() {
+    let cmp = StandardComparator.then_by(BridgeComparator).color_grouped();
+    assert_eq!(sorted("A♠ 2♣ 3♥ 3♦ K♣", &cmp), "3♦ 3♥ 2♣ K♣ A♠");
+}
+
+#[test]
+fn test_jokers_high_and_low() {
+    let cards = "U♥ 2♥ A♠ U♠";
+    let high = AceLowComparator.jokers_high();
+    assert_eq!(sorted(cards, &high), "A♠ 2♥ U♥ U♠");
+    assert_eq!(high.rank_value(Rank::Joker), i32::MAX);
+
+    let low = StandardComparator.then_by(BridgeComparator).jokers_low();
+    assert_eq!(sorted(cards, &low), "U♥ U♠ 2♥ A♠");
+    assert_eq!(low.rank_value(Rank::Joker), i32::MIN);
+
+    // Reversing a jokers-high comparator puts the jokers first.
+    assert!(sorted(cards, &high.reversed()).starts_with("U"));
+}
+
+#[test]
+fn test_reversed_joker_values_do_not_overflow() {
+    let low = StandardComparator.jokers_low().reversed();
+    assert_eq!(low.rank_value(Rank::Joker), i32::MAX);
+    assert!(low.rank_value(Rank::Joker) > low.rank_value(Rank::Two));
+
+    let high = StandardComparator.jokers_high().reversed();
+    assert_eq!(high.rank_value(Rank::Joker), -i32::MAX);
+    assert!(high.rank_value(Rank::Joker) < high.rank_value(Rank::Two));
+}
+
+#[test]
+fn test_fn_comparator() {
+    let by_suit = FnComparator::new(|a: &Card, b: &Card| a.suit().value().cmp(&b.suit().value()));
+    assert_eq!(sorted("2♠ 3♥ 4♣ 5♦", &by_suit), "3♥ 5♦ 4♣ 2♠");
+    let cmp = by_suit.then_by(StandardComparator.reversed());
+    assert_eq!(sorted("2♠ 3♥ A♠ 9♥", &cmp), "9♥ 3♥ A♠ 2♠");
+}
+
+#[test]
+fn test_references_and_trait_objects() {
+    let trump = TrumpComparator::new(Suit::Clubs);
+    let by_ref = (&trump).then_by(BridgeComparator);
+    assert_eq!(sorted("A♠ 2♣ A♥", &by_ref), "A♥ A♠ 2♣");
+
+    let boxed: Vec<Box<dyn CardComparator>> = vec![
+        Box::new(StandardComparator),
+        Box::new(AceLowComparator.reversed()),
+    ];
+    assert_eq!(sorted("A♠ 2♣ K♥", boxed[0].as_ref()), "2♣ K♥ A♠");
+    assert_eq!(sorted("A♠ 2♣ K♥", boxed[1].as_ref()), "K♥ 2♣ A♠");
+
+    let chained = StandardComparator.then_by(boxed.into_iter().nth(1).unwrap());
+    assert_eq!(sorted("K♥ K♠ 2♣", &chained), "2♣ K♥ K♠");
+}
+
+#[test]
+fn test_packed_deck_accepts_adapters() {
+    let mut packed = PackedDeck::from("K♥ 2♠ A♥".parse::<Deck>().unwrap());
+    packed.sort_by_comparator(&StandardComparator.suit_first().reversed());
+    assert_eq!(Deck::from(packed).to_string(), "2♠ A♥ K♥");
+}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+use crusty_cards::{Card, Deck};
+
+pub fn card(s: &str) -> Card {
+    s.parse().unwrap()
+}
+
+pub fn cards(s: &str) -> Vec<Card> {
+    s.parse::<Deck>().unwrap().into_iter().collect()
+}
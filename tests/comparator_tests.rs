@@ -1,6 +1,7 @@
 use crusty_cards::{
-    AceLowComparator, BridgeComparator, Card, CardComparator, Rank, StandardComparator, Suit,
-    TrumpComparator,
+    AceLowComparator, BridgeComparator, Card, CardComparator, Deck, Doppelkopf48,
+    DoppelkopfComparator, DoppelkopfGame, Euchre24, EuchreComparator, Rank, SchafkopfComparator,
+    SchafkopfGame, Skat32, SkatComparator, SkatGame, StandardComparator, Suit, TrumpComparator,
 };
 use std::cmp::Ordering;
 
@@ -142,7 +143,7 @@ fn test_comparator_max_min() {
 #[test]
 fn test_sorting_with_comparator() {
     let cmp = StandardComparator;
-    let mut cards = vec![
+    let mut cards = [
         Card::new(Suit::Hearts, Rank::King),
         Card::new(Suit::Spades, Rank::Two),
         Card::new(Suit::Clubs, Rank::Ace),
@@ -158,7 +159,7 @@ fn test_sorting_with_comparator() {
 #[test]
 fn test_sorting_with_ace_low() {
     let cmp = AceLowComparator;
-    let mut cards = vec![
+    let mut cards = [
         Card::new(Suit::Hearts, Rank::King),
         Card::new(Suit::Spades, Rank::Two),
         Card::new(Suit::Clubs, Rank::Ace),
@@ -170,3 +171,138 @@ fn test_sorting_with_ace_low() {
     assert_eq!(cards[1].rank(), Rank::Two);
     assert_eq!(cards[2].rank(), Rank::King);
 }
+
+#[test]
+fn test_default_effective_suit() {
+    let card = Card::new(Suit::Diamonds, Rank::Jack);
+    assert_eq!(StandardComparator.effective_suit(&card), Suit::Diamonds);
+    assert_eq!(
+        TrumpComparator::new(Suit::Hearts).effective_suit(&card),
+        Suit::Diamonds
+    );
+}
+
+#[test]
+fn test_euchre_bowers() {
+    let cmp = EuchreComparator::new(Suit::Spades);
+    let right = Card::new(Suit::Spades, Rank::Jack);
+    let left = Card::new(Suit::Clubs, Rank::Jack);
+    let jack_hearts = Card::new(Suit::Hearts, Rank::Jack);
+
+    assert!(cmp.is_right_bower(&right));
+    assert!(cmp.is_left_bower(&left));
+    assert!(!cmp.is_left_bower(&right));
+    assert!(!cmp.is_left_bower(&jack_hearts));
+    assert!(cmp.is_trump(&left));
+    assert!(!cmp.is_trump(&jack_hearts));
+    assert_eq!(cmp.effective_suit(&left), Suit::Spades);
+    assert_eq!(cmp.effective_suit(&jack_hearts), Suit::Hearts);
+    assert_eq!(cmp.trump(), Suit::Spades);
+    assert_eq!(cmp.led(), None);
+}
+
+#[test]
+fn test_euchre_ordering() {
+    let cmp = EuchreComparator::new(Suit::Diamonds).with_led(Suit::Clubs);
+    let mut deck = Deck::from_factory(Euchre24);
+    deck.sort_by_comparator(&cmp.reversed());
+    let top: Vec<String> = deck.iter().take(8).map(|c| c.to_string()).collect();
+    assert_eq!(top, ["J♦", "J♥", "A♦", "K♦", "Q♦", "T♦", "9♦", "A♣"]);
+    assert_eq!(deck.len(), 24);
+    assert_eq!(deck.iter().last().unwrap().rank(), Rank::Nine);
+
+    // Without a led suit, off-suit cards compare by rank alone.
+    let cmp = EuchreComparator::new(Suit::Diamonds);
+    let ace_clubs = Card::new(Suit::Clubs, Rank::Ace);
+    let ace_spades = Card::new(Suit::Spades, Rank::Ace);
+    assert_eq!(cmp.compare(&ace_clubs, &ace_spades), Ordering::Equal);
+}
+
+#[test]
+fn test_euchre_suit_first_groups_left_bower_with_trump() {
+    let mut deck: Deck = "JD AH 9D JH KC".parse().unwrap();
+    deck.sort_by_comparator(&EuchreComparator::new(Suit::Hearts).suit_first());
+    assert_eq!(deck.to_string(), "A♥ J♦ J♥ 9♦ K♣");
+}
+
+fn sorted_desc<C: CardComparator>(cmp: C, deck: Deck, n: usize) -> Vec<String> {
+    let mut deck = deck;
+    deck.sort_by_comparator(&cmp.reversed());
+    deck.iter().take(n).map(|c| c.to_string()).collect()
+}
+
+#[test]
+fn test_skat_orders() {
+    let grand = SkatComparator::new(SkatGame::Grand);
+    assert_eq!(grand.game(), SkatGame::Grand);
+    assert_eq!(
+        sorted_desc(grand, Deck::from_factory(Skat32), 4),
+        ["J♣", "J♠", "J♥", "J♦"]
+    );
+    let ace = Card::new(Suit::Clubs, Rank::Ace);
+    assert!(!grand.is_trump(&ace));
+    assert!(grand.is_trump(&Card::new(Suit::Diamonds, Rank::Jack)));
+
+    let hearts = SkatComparator::new(SkatGame::Trump(Suit::Hearts));
+    assert_eq!(
+        sorted_desc(hearts, Deck::from_factory(Skat32), 11),
+        ["J♣", "J♠", "J♥", "J♦", "A♥", "T♥", "K♥", "Q♥", "9♥", "8♥", "7♥"]
+    );
+
+    let ten = Card::new(Suit::Spades, Rank::Ten);
+    let king = Card::new(Suit::Spades, Rank::King);
+    let jack = Card::new(Suit::Spades, Rank::Jack);
+    assert!(hearts.is_greater(&ten, &king));
+    let null = SkatComparator::new(SkatGame::Null);
+    assert!(null.is_less(&ten, &jack));
+    assert!(null.is_greater(&king, &ten));
+    assert!(!null.is_trump(&jack));
+    assert_eq!(null.effective_suit(&jack), Suit::Spades);
+}
+
+#[test]
+fn test_schafkopf_orders() {
+    let normal = SchafkopfComparator::default();
+    assert_eq!(normal.game(), SchafkopfGame::Trump(Suit::Hearts));
+    assert_eq!(
+        sorted_desc(normal, Deck::from_factory(Skat32), 14),
+        [
+            "Q♣", "Q♠", "Q♥", "Q♦", "J♣", "J♠", "J♥", "J♦", "A♥", "T♥", "K♥", "9♥", "8♥", "7♥"
+        ]
+    );
+    let queen = Card::new(Suit::Clubs, Rank::Queen);
+    assert_eq!(normal.effective_suit(&queen), Suit::Hearts);
+
+    let wenz = SchafkopfComparator::new(SchafkopfGame::Wenz);
+    assert!(!wenz.is_trump(&queen));
+    assert_eq!(
+        sorted_desc(wenz, Deck::from_factory(Skat32), 4),
+        ["J♣", "J♠", "J♥", "J♦"]
+    );
+    let geier = SchafkopfComparator::new(SchafkopfGame::Geier);
+    assert!(!geier.is_trump(&Card::new(Suit::Clubs, Rank::Jack)));
+    assert!(geier.is_trump(&queen));
+}
+
+#[test]
+fn test_doppelkopf_orders() {
+    let normal = DoppelkopfComparator::default();
+    let top = sorted_desc(normal, Deck::from_factory(Doppelkopf48), 24);
+    assert_eq!(&top[..4], ["T♥", "T♥", "Q♣", "Q♣"]);
+    assert_eq!(&top[18..], ["A♦", "A♦", "T♦", "T♦", "K♦", "K♦"]);
+    let trumps = Deck::from_factory(Doppelkopf48)
+        .iter()
+        .filter(|c| normal.is_trump(c))
+        .count();
+    assert_eq!(trumps, 26);
+
+    let queens = DoppelkopfComparator::new(DoppelkopfGame::QueenSolo);
+    assert!(!queens.is_trump(&Card::new(Suit::Hearts, Rank::Ten)));
+    let jacks = DoppelkopfComparator::new(DoppelkopfGame::JackSolo);
+    assert!(jacks.is_trump(&Card::new(Suit::Hearts, Rank::Jack)));
+    let none = DoppelkopfComparator::new(DoppelkopfGame::NoTrump);
+    assert!(Deck::from_factory(Doppelkopf48)
+        .iter()
+        .all(|c| !none.is_trump(c)));
+    assert_eq!(none.game(), DoppelkopfGame::NoTrump);
+}
@@ -0,0 +1,169 @@
+use crusty_cards::{
+    Card, Composition, Deck, DeckFactory, EnforcedDeck, Rank, Standard52, Standard54, Suit,
+};
+
+fn ace_spades() -> Card {
+    Card::new(Suit::Spades, Rank::Ace)
+}
+
+#[test]
+fn test_composition_counts() {
+    let deck: Deck = "A♠ K♥ A♠".parse().unwrap();
+    let composition = deck.composition();
+    assert_eq!(composition.count(&ace_spades()), 2);
+    assert_eq!(composition.count(&Card::new(Suit::Hearts, Rank::King)), 1);
+    assert_eq!(composition.count(&Card::new(Suit::Clubs, Rank::Two)), 0);
+    assert_eq!(composition.len(), 3);
+    assert_eq!(composition.distinct(), 2);
+    assert_eq!(composition.iter().count(), 2);
+    assert!(!composition.is_empty());
+    assert!(Deck::default().composition().is_empty());
+}
+
+#[test]
+fn test_composition_from_factory() {
+    let composition = Composition::from_factory(Standard52);
+    assert_eq!(composition.len(), 52);
+    assert_eq!(composition.distinct(), 52);
+    assert!(composition.iter().all(|(_, &count)| count == 1));
+}
+
+#[test]
+fn test_composition_mul() {
+    let composition = Composition::from_factory(Standard52) * 2;
+    assert_eq!(composition.len(), 104);
+    assert_eq!(composition.count(&ace_spades()), 2);
+    assert_eq!(
+        composition,
+        (Deck::from_factory(Standard52) * 2).composition()
+    );
+    let times = 0;
+    assert!((Composition::from_factory(Standard52) * times).is_empty());
+}
+
+#[test]
+fn test_validate_valid_shuffled_shoe() {
+    let mut shoe = Deck::from_factory(Standard52) * 2;
+    shoe.shuffle();
+    let report = (Composition::from_factory(Standard52) * 2).validate(&shoe);
+    assert!(report.is_valid());
+    assert_eq!(report.to_string(), "valid");
+}
+
+#[test]
+fn test_validate_reports_missing_duplicated_foreign() {
+    let mut deck = Deck::from_factory(Standard52);
+    let two_hearts = Card::new(Suit::Hearts, Rank::Two);
+    let joker = Card::new(Suit::Spades, Rank::Joker);
+    deck -= ace_spades();
+    deck.add_card(two_hearts);
+    deck.add_card(two_hearts);
+    deck.add_card(joker);
+
+    let report = deck.validate_against(Standard52);
+    assert!(!report.is_valid());
+    assert_eq!(report.missing(), &[(ace_spades(), 1)]);
+    assert_eq!(report.duplicated(), &[(two_hearts, 2)]);
+    assert_eq!(report.foreign(), &[(joker, 1)]);
+    assert_eq!(
+        report.to_string(),
+        "missing: A♠; duplicated: 2♥x2; foreign: U♠"
+    );
+}
+
+#[test]
+fn test_validate_against_other_factory() {
+    let deck = Deck::from_factory(Standard52);
+    let report = deck.validate_against(Standard54);
+    assert_eq!(report.missing().len(), 2);
+    assert!(report.duplicated().is_empty());
+    assert!(report.foreign().is_empty());
+    assert_eq!(report.to_string(), "missing: U♥ U♠");
+}
+
+#[test]
+fn test_contains_all() {
+    let full = Composition::from_factory(Standard52);
+    let hand: Deck = "A♠ K♠".parse().unwrap();
+    assert!(full.contains_all(&hand.composition()));
+    let pair: Deck = "A♠ A♠".parse().unwrap();
+    assert!(!full.contains_all(&pair.composition()));
+}
+
+#[test]
+fn test_same_cards_as() {
+    let deck = Deck::from_factory(Standard52);
+    let mut shuffled = deck.clone();
+    shuffled.reverse();
+    assert!(deck.same_cards_as(&shuffled));
+    assert_ne!(deck, shuffled);
+
+    let mut short = shuffled.clone();
+    short.deal();
+    assert!(!deck.same_cards_as(&short));
+
+    let mut swapped = shuffled.clone();
+    swapped[0] = swapped[1];
+    assert!(!deck.same_cards_as(&swapped));
+}
+
+#[test]
+fn test_enforced_deck_refuses_extra_copies() {
+    let mut shoe = EnforcedDeck::new(
+        Deck::from_factory(Standard52),
+        Composition::from_factory(Standard52),
+    )
+    .unwrap();
+    let card = shoe.deal().unwrap();
+    assert_eq!(shoe.len(), 51);
+    assert!(shoe.add_card(card).is_ok());
+    assert!(shoe.add_card(card).is_err());
+    assert!(shoe.add_card_bottom(card).is_err());
+    assert_eq!(shoe.len(), 52);
+
+    let joker = Card::new(Suit::Hearts, Rank::Joker);
+    let err = shoe.add_card(joker).unwrap_err();
+    assert!(err.contains("does not belong"));
+}
+
+#[test]
+fn test_enforced_deck_add_cards_all_or_nothing() {
+    let mut shoe = EnforcedDeck::new(
+        Deck::from_factory(Standard52),
+        Composition::from_factory(Standard52),
+    )
+    .unwrap();
+    let hand = shoe.deal_n(2).unwrap();
+    assert!(shoe.add_cards(vec![hand[0], hand[0]]).is_err());
+    assert_eq!(shoe.len(), 50);
+    assert!(shoe.add_cards(hand.clone()).is_ok());
+    assert_eq!(shoe.peek(), Some(&hand[0]));
+
+    let bottom = shoe.deal_bottom().unwrap();
+    assert!(shoe.add_card_bottom(bottom).is_ok());
+    assert!(shoe.validate_against(Standard52).is_valid());
+}
+
+#[test]
+fn test_enforced_deck_shuffle_and_cut() {
+    let mut shoe = EnforcedDeck::new(
+        Deck::from_factory(Standard52),
+        Composition::from_factory(Standard52),
+    )
+    .unwrap();
+    shoe.shuffle();
+    assert!(shoe.cut(10));
+    assert_eq!(shoe.composition(), &Composition::from_factory(Standard52));
+    let deck = shoe.into_inner();
+    assert!(deck.same_cards_as(&Deck::from_factory(Standard52)));
+}
+
+#[test]
+fn test_enforced_deck_rejects_invalid_start() {
+    let deck = Deck::from_factory(Standard52) + ace_spades();
+    let err = EnforcedDeck::new(deck, Composition::from_factory(Standard52)).unwrap_err();
+    assert!(err.contains("duplicated: A♠"));
+
+    let partial = Deck::new(Standard52.generate().into_iter().take(10).collect());
+    assert!(EnforcedDeck::new(partial, Composition::from_factory(Standard52)).is_ok());
+}
@@ -0,0 +1,196 @@
+use crusty_cards::{
+    Card, CsvColumn, CsvFormat, Deck, Rank, RankStyle, Standard52, Standard54, Suit, SuitStyle,
+};
+
+mod common;
+
+use common::cards;
+
+#[test]
+fn test_csv_default_format() {
+    let deck: Deck = "T♠ A♥ U♦".parse().unwrap();
+    let csv = CsvFormat::new().write_deck(&deck);
+    assert_eq!(csv, "Rank,Suit\n10,S\nA,H\nU,D\n");
+    assert_eq!(CsvFormat::new().read_deck(&csv).unwrap(), deck);
+}
+
+#[test]
+fn test_csv_styles() {
+    let deck: Deck = "T♣".parse().unwrap();
+    let symbol = CsvFormat::new()
+        .header(false)
+        .rank_style(RankStyle::Symbol)
+        .suit_style(SuitStyle::Symbol);
+    assert_eq!(symbol.write_deck(&deck), "T,♣\n");
+    let names = CsvFormat::new()
+        .header(false)
+        .rank_style(RankStyle::Name)
+        .suit_style(SuitStyle::Name)
+        .crlf(true);
+    assert_eq!(names.write_deck(&deck), "Ten,Clubs\r\n");
+    assert_eq!(names.read_deck("Ten,Clubs\r\n").unwrap(), deck);
+}
+
+#[test]
+fn test_csv_all_columns_round_trip() {
+    let deck = Deck::from_factory(Standard54);
+    let format = CsvFormat::new().all_columns();
+    let csv = format.write_deck(&deck);
+    assert!(csv.starts_with("Hand,Position,Rank,Suit,Color,Value\n1,1,2,H,Red,0\n"));
+    assert_eq!(format.read_deck(&csv).unwrap(), deck);
+    assert_eq!(CsvFormat::new().read_deck(&csv).unwrap(), deck);
+}
+
+#[test]
+fn test_csv_value_column_only() {
+    let csv = "value\n0\n55\n";
+    let deck = CsvFormat::new().read_deck(csv).unwrap();
+    assert_eq!(
+        deck.iter().copied().collect::<Vec<_>>(),
+        vec![
+            Card::new(Suit::Hearts, Rank::Two),
+            Card::new(Suit::Spades, Rank::Joker)
+        ]
+    );
+    let err = CsvFormat::new().read_deck("Value\n0\n56\n").unwrap_err();
+    assert_eq!(err.line(), 3);
+    assert_eq!(err.message(), "Invalid card value: 56");
+}
+
+#[test]
+fn test_csv_tolerant_parsing() {
+    let csv = "\u{feff}\"Suit\",\"Rank\"\r\n\r\n\"S\",\"A\"\r\n  h , 10 \r\nClubs,\"queen\"";
+    let deck = CsvFormat::new().read_deck(csv).unwrap();
+    assert_eq!(deck.to_string(), "A♠ T♥ Q♣");
+
+    let headerless = CsvFormat::new().read_deck("A,♠\nK,♥\n").unwrap();
+    assert_eq!(headerless.to_string(), "A♠ K♥");
+}
+
+#[test]
+fn test_csv_skips_unknown_header_columns() {
+    let csv = "hand,position,rank,suit,notes\nN,2,K,S,\"led, then lost\"\nN,1,A,H,\n";
+    let hands = CsvFormat::new().read_hands(csv).unwrap();
+    assert_eq!(hands.len(), 1);
+    assert_eq!(hands[0].0, "N");
+    assert_eq!(hands[0].1.to_string(), "A♥ K♠");
+
+    let deck = CsvFormat::new()
+        .read_deck("Dealt,Rank,Source,Suit\nyes,Q,shoe,D\n")
+        .unwrap();
+    assert_eq!(deck.to_string(), "Q♦");
+}
+
+#[test]
+fn test_csv_line_numbered_errors() {
+    let err = CsvFormat::new()
+        .read_deck("Rank,Suit\nA,S\n\nX,H\n")
+        .unwrap_err();
+    assert_eq!(err.line(), 4);
+    assert_eq!(
+        err.to_string(),
+        "Failed to parse line 4: Invalid rank string: X"
+    );
+
+    let err = CsvFormat::new()
+        .read_deck("Rank,Suit\nA,S,extra\n")
+        .unwrap_err();
+    assert_eq!(err.line(), 2);
+    assert_eq!(err.message(), "Expected 2 fields but found 3");
+
+    let err = CsvFormat::new()
+        .read_deck("Rank,Suit\nA,S\n\"K,H\n")
+        .unwrap_err();
+    assert_eq!(err.line(), 3);
+    assert_eq!(err.message(), "Unterminated quoted field");
+
+    let err = CsvFormat::new()
+        .read_deck("Hand,Color\n1,Red\n")
+        .unwrap_err();
+    assert_eq!(err.line(), 1);
+
+    // The header's own line is reported, after a BOM and blank lines.
+    let err = CsvFormat::new()
+        .read_deck("\u{feff}\n\r\nHand,Color\n1,Red\n")
+        .unwrap_err();
+    assert_eq!(err.line(), 3);
+    assert_eq!(
+        err.message(),
+        "Table needs rank and suit columns or a value column"
+    );
+
+    let err = CsvFormat::new()
+        .columns(&[CsvColumn::Position, CsvColumn::Rank, CsvColumn::Suit])
+        .read_deck("x,A,S\n")
+        .unwrap_err();
+    assert_eq!(err.message(), "Invalid position: x");
+}
+
+#[test]
+fn test_csv_multi_line_quoted_field_tracks_lines() {
+    let csv = "Hand,Rank,Suit\n\"two\nlines\",A,S\n1,X,S\n";
+    let err = CsvFormat::new().read_hands(csv).unwrap_err();
+    assert_eq!(err.line(), 4);
+}
+
+#[test]
+fn test_csv_hands_table() {
+    let north = cards("A♠ K♠");
+    let south = cards("2♣");
+    let format = CsvFormat::new().columns(&[
+        CsvColumn::Hand,
+        CsvColumn::Position,
+        CsvColumn::Rank,
+        CsvColumn::Suit,
+    ]);
+    let csv = format.write_hands(&[("North \"N\"", &north), ("South, S", &south)]);
+    assert_eq!(
+        csv,
+        "Hand,Position,Rank,Suit\n\"North \"\"N\"\"\",1,A,S\n\"North \"\"N\"\"\",2,K,S\n\"South, S\",1,2,C\n"
+    );
+    let hands = format.read_hands(&csv).unwrap();
+    assert_eq!(hands.len(), 2);
+    assert_eq!(hands[0].0, "North \"N\"");
+    assert_eq!(hands[0].1.to_string(), "A♠ K♠");
+    assert_eq!(hands[1].0, "South, S");
+    assert_eq!(hands[1].1.to_string(), "2♣");
+}
+
+#[test]
+fn test_csv_hands_without_hand_column() {
+    let hands = CsvFormat::new().read_hands("Rank,Suit\nA,S\n").unwrap();
+    assert_eq!(hands.len(), 1);
+    assert_eq!(hands[0].0, "1");
+}
+
+#[test]
+fn test_csv_column_names() {
+    for column in CsvColumn::ALL {
+        assert_eq!(
+            column.name().to_lowercase().parse::<CsvColumn>(),
+            Ok(column)
+        );
+    }
+    assert!("Card".parse::<CsvColumn>().is_err());
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deck_from_csv_uses_tolerant_reader() {
+    let deck = Deck::from_factory(Standard52);
+    assert_eq!(Deck::from_csv(&deck.to_csv()).unwrap(), deck);
+    assert_eq!(
+        Deck::from_csv(&CsvFormat::new().write_deck(&deck)).unwrap(),
+        deck
+    );
+    assert_eq!(
+        Deck::from_csv("Rank,Suit\r\n\"A\",\"♠\"\r\n")
+            .unwrap()
+            .to_string(),
+        "A♠"
+    );
+    assert_eq!(
+        Deck::from_csv("Rank,Suit\nA,♠\nX,♦\n").unwrap_err(),
+        "Failed to parse line 3: Invalid rank string: X"
+    );
+}
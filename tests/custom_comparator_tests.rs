@@ -0,0 +1,96 @@
+use crusty_cards::{CardComparator, CustomComparator, Deck, Rank, Standard54, Suit};
+use std::cmp::Ordering;
+
+mod common;
+
+use common::card;
+
+fn sorted(cards: &str, cmp: &CustomComparator) -> String {
+    let mut deck: Deck = cards.parse().unwrap();
+    deck.sort_by_comparator(cmp);
+    deck.to_string()
+}
+
+#[test]
+fn test_builder_orders_and_ties() {
+    let cmp = CustomComparator::new()
+        .with_rank_groups(&[&[Rank::Two], &[Rank::Ten, Rank::Jack], &[Rank::Ace]])
+        .with_suit_order(&[Suit::Spades, Suit::Hearts]);
+    assert_eq!(cmp.rank_value(Rank::Two), 0);
+    assert_eq!(cmp.rank_value(Rank::Jack), 1);
+    assert_eq!(cmp.rank_value(Rank::King), -1);
+    assert_eq!(cmp.suit_value(Suit::Hearts), 1);
+    assert_eq!(cmp.suit_value(Suit::Clubs), -1);
+
+    assert_eq!(cmp.compare(&card("TH"), &card("JH")), Ordering::Equal);
+    assert!(cmp.is_greater(&card("TH"), &card("JS")));
+    assert!(cmp.is_less(&card("KH"), &card("2S")));
+    assert_eq!(sorted("AH KD 2S TH JS", &cmp), "K♦ 2♠ J♠ T♥ A♥");
+}
+
+#[test]
+fn test_duplicates_keep_first_level() {
+    let cmp = CustomComparator::new().with_rank_groups(&[&[Rank::Ace, Rank::Two], &[Rank::Ace]]);
+    assert_eq!(cmp.rank_order(), &[vec![Rank::Ace, Rank::Two]]);
+}
+
+#[test]
+fn test_excluded_cards_sort_lowest() {
+    let cmp = CustomComparator::new()
+        .with_rank_order(&Rank::STANDARD)
+        .with_excluded(&[card("AS"), card("U♥")]);
+    assert!(cmp.is_excluded(&card("AS")));
+    assert!(cmp.is_less(&card("AS"), &card("2C")));
+    assert_eq!(sorted("3C AS 2C U♥", &cmp), "U♥ A♠ 2♣ 3♣");
+}
+
+#[test]
+fn test_load_from_yaml() {
+    let yaml = "\
+rank_order: [Two, Three, Four, Five, Six, Seven, Eight, Nine, [Ten, Jack, Queen, King], Ace]
+suit_order: [Clubs, Diamonds, Hearts, Spades]
+excluded:
+  - { suit: Hearts, rank: Joker }
+  - { suit: Spades, rank: Joker }
+";
+    let cmp = CustomComparator::from_yaml(yaml).unwrap();
+    assert_eq!(cmp.rank_order().len(), 10);
+    assert_eq!(cmp.excluded().len(), 2);
+    assert_eq!(cmp.compare(&card("KS"), &card("TS")), Ordering::Equal);
+
+    let mut deck = Deck::from_factory(Standard54);
+    deck.sort_by_comparator(&cmp);
+    assert!(deck[0].is_joker());
+    assert_eq!(deck[53], card("AS"));
+
+    let round_trip = CustomComparator::from_yaml(&cmp.to_yaml().unwrap()).unwrap();
+    assert_eq!(round_trip, cmp);
+}
+
+#[test]
+fn test_load_from_json() {
+    let cmp = CustomComparator::from_json(r#"{"suit_order": [["Hearts", "Diamonds"], "Spades"]}"#)
+        .unwrap();
+    assert!(cmp.rank_order().is_empty());
+    assert_eq!(cmp.compare(&card("2H"), &card("AD")), Ordering::Equal);
+    assert!(cmp.is_greater(&card("2S"), &card("AH")));
+
+    let json = cmp.to_json().unwrap();
+    assert_eq!(json, r#"{"suit_order":[["Hearts","Diamonds"],"Spades"]}"#);
+    assert_eq!(CustomComparator::from_json(&json).unwrap(), cmp);
+    assert_eq!(
+        CustomComparator::from_json("{}").unwrap(),
+        CustomComparator::new()
+    );
+}
+
+#[test]
+fn test_invalid_config() {
+    let err =
+        CustomComparator::from_json(r#"{"rank_order": ["Ace", ["Two", "Ace"]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Ace appears more than once in rank_order"));
+    assert!(CustomComparator::from_json(r#"{"rank_orde": []}"#).is_err());
+    assert!(CustomComparator::from_yaml("suit_order: [Stars]").is_err());
+}
@@ -0,0 +1,155 @@
+use crusty_cards::{Card, Deal, DealPattern, DealStep, DealTarget, Deck, Standard52};
+
+fn numbered_deck(n: usize) -> Deck {
+    (0..n).map(|i| Card::try_from(i).unwrap()).collect()
+}
+
+#[test]
+fn test_round_robin_deals_one_at_a_time() {
+    let mut deck = numbered_deck(8);
+    let deal = deck.deal_to(4, &DealPattern::round_robin(2)).unwrap();
+    let expected: Vec<Vec<Card>> = (0..4usize)
+        .map(|seat| {
+            vec![
+                Card::try_from(seat).unwrap(),
+                Card::try_from(seat + 4).unwrap(),
+            ]
+        })
+        .collect();
+    assert_eq!(deal.hands(), expected.as_slice());
+    assert!(deal.kitty().is_empty());
+    assert!(deck.is_empty());
+}
+
+#[test]
+fn test_full_deck_round_robin() {
+    let mut deck = Deck::from_factory(Standard52);
+    let deal = deck.deal_to(4, &DealPattern::round_robin(13)).unwrap();
+    assert_eq!(deal.hands().len(), 4);
+    for hand in deal.hands() {
+        assert_eq!(hand.len(), 13);
+    }
+    assert!(deck.is_empty());
+}
+
+#[test]
+fn test_dealer_relative_start() {
+    let mut deck = numbered_deck(4);
+    let deal = deck
+        .deal_to(4, &DealPattern::round_robin(1).dealer(1))
+        .unwrap();
+    assert_eq!(deal.hand(2), Some(&[Card::try_from(0usize).unwrap()][..]));
+    assert_eq!(deal.hand(3), Some(&[Card::try_from(1usize).unwrap()][..]));
+    assert_eq!(deal.hand(0), Some(&[Card::try_from(2usize).unwrap()][..]));
+    assert_eq!(deal.hand(1), Some(&[Card::try_from(3usize).unwrap()][..]));
+    assert_eq!(deal.hand(4), None);
+}
+
+#[test]
+fn test_dealer_wraps_around_table() {
+    let pattern = DealPattern::round_robin(1).dealer(3);
+    assert_eq!(
+        pattern.schedule(4),
+        vec![
+            DealTarget::Seat(0),
+            DealTarget::Seat(1),
+            DealTarget::Seat(2),
+            DealTarget::Seat(3),
+        ]
+    );
+    let pattern = DealPattern::round_robin(1).start_at(7);
+    assert_eq!(pattern.schedule(3)[0], DealTarget::Seat(1));
+}
+
+#[test]
+fn test_euchre_packets() {
+    let pattern = DealPattern::euchre();
+    let schedule = pattern.schedule(4);
+    assert_eq!(schedule.len(), 20);
+    let seats: Vec<usize> = schedule
+        .iter()
+        .map(|target| match target {
+            DealTarget::Seat(seat) => *seat,
+            DealTarget::Kitty => panic!("euchre deals no kitty"),
+        })
+        .collect();
+    assert_eq!(
+        seats,
+        vec![0, 0, 0, 1, 1, 2, 2, 2, 3, 3, 0, 0, 1, 1, 1, 2, 2, 3, 3, 3]
+    );
+
+    let mut deck = numbered_deck(24);
+    let deal = deck.deal_to(4, &pattern).unwrap();
+    assert!(deal.hands().iter().all(|hand| hand.len() == 5));
+    assert_eq!(deck.len(), 4);
+}
+
+#[test]
+fn test_skat_kitty() {
+    let mut deck = numbered_deck(32);
+    let deal = deck.deal_to(3, &DealPattern::skat()).unwrap();
+    assert!(deal.hands().iter().all(|hand| hand.len() == 10));
+    assert_eq!(
+        deal.kitty(),
+        &[
+            Card::try_from(9usize).unwrap(),
+            Card::try_from(10usize).unwrap()
+        ]
+    );
+    assert!(deck.is_empty());
+}
+
+#[test]
+fn test_deal_to_not_enough_cards() {
+    let mut deck = numbered_deck(10);
+    assert_eq!(deck.deal_to(4, &DealPattern::round_robin(3)), None);
+    assert_eq!(deck.len(), 10);
+}
+
+#[test]
+fn test_deal_to_zero_players() {
+    let mut deck = numbered_deck(10);
+    assert_eq!(deck.deal_to(0, &DealPattern::round_robin(1)), None);
+    assert_eq!(deck.len(), 10);
+    assert!(DealPattern::round_robin(1).schedule(0).is_empty());
+}
+
+#[test]
+fn test_custom_packets_cycle() {
+    let pattern = DealPattern::new(vec![
+        DealStep::Packets(vec![1, 2, 3]),
+        DealStep::Packets(vec![]),
+        DealStep::Kitty(1),
+    ]);
+    assert_eq!(pattern.cards_needed(4), 1 + 2 + 3 + 1 + 1);
+    assert_eq!(pattern.schedule(4).len(), 8);
+    assert_eq!(pattern.steps().len(), 3);
+
+    let mut deck = numbered_deck(8);
+    let deal = deck.deal_to(4, &pattern).unwrap();
+    let sizes: Vec<usize> = deal.hands().iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![1, 2, 3, 1]);
+    assert_eq!(deal.kitty().len(), 1);
+}
+
+#[test]
+fn test_deal_into_parts() {
+    let hands = vec![vec![Card::try_from(0u8).unwrap()], vec![]];
+    let kitty = vec![Card::try_from(1u8).unwrap()];
+    let deal = Deal::new(hands.clone(), kitty.clone());
+    assert_eq!(deal.into_parts(), (hands, kitty));
+}
+
+#[test]
+fn test_deal_serialization() {
+    let mut deck = numbered_deck(12);
+    let pattern = DealPattern::new(vec![DealStep::Round(2), DealStep::Kitty(2)]).dealer(2);
+    let deal = deck.deal_to(3, &pattern).unwrap();
+    let json = serde_json::to_string(&deal).unwrap();
+    let restored: Deal = serde_json::from_str(&json).unwrap();
+    assert_eq!(deal, restored);
+
+    let json = serde_json::to_string(&pattern).unwrap();
+    let restored: DealPattern = serde_json::from_str(&json).unwrap();
+    assert_eq!(pattern, restored);
+}
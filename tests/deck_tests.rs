@@ -41,7 +41,7 @@ fn test_deck_cut() {
 
     let mut empty_deck = Deck::default();
     assert!(empty_deck.is_empty());
-    assert_eq!(empty_deck.cut(0), false);
+    assert!(!empty_deck.cut(0));
 }
 
 #[test]
@@ -84,8 +84,7 @@ fn test_deck_shuffle() {
             return;
         }
     }
-    assert!(
-        false,
+    panic!(
         "Congratulations, you just shuffled the deck randomly 100 times without changing its order. You should go buy a lottery ticket."
     );
 }
@@ -349,6 +348,7 @@ fn test_deck_mul() {
 }
 
 #[test]
+#[allow(clippy::erasing_op)]
 fn test_deck_mul_zero() {
     let cards = VecDeque::from(vec![
         Card::new(Suit::Hearts, Rank::Ace),
@@ -680,6 +680,19 @@ fn test_deck_empty_json_roundtrip() {
     assert!(restored.is_empty());
 }
 
+#[test]
+fn test_deck_plain_json_roundtrip_matches_serde() {
+    let deck = Deck::from_str("A♠ U♥ A♠ 10♦").unwrap();
+
+    let json = deck.to_json().unwrap();
+    let restored = Deck::from_json(&json).unwrap();
+
+    assert_eq!(restored, deck);
+    assert_eq!(restored, serde_json::from_str::<Deck>(&json).unwrap());
+    assert!(Deck::from_json(r#"{"cards": [{"suit": "Hearts"}]}"#).is_err());
+    assert!(Deck::from_json("not json").is_err());
+}
+
 #[test]
 fn test_deck_empty_yaml_roundtrip() {
     let deck = Deck::new(VecDeque::new());
@@ -691,6 +704,7 @@ fn test_deck_empty_yaml_roundtrip() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_deck_to_and_from_csv() {
     let cards = VecDeque::from(vec![
         Card::new(Suit::Hearts, Rank::Ace),
@@ -1002,6 +1016,7 @@ fn test_deck_yaml_roundtrip() {
 }
 
 #[test]
+#[allow(deprecated)]
 fn test_deck_csv_roundtrip() {
     let mut cards = VecDeque::new();
     for suit in Suit::ALL {
@@ -1116,7 +1131,7 @@ fn test_deck_overhand_shuffle_times() {
             return;
         }
     }
-    assert!(false, "Congratulations, you just shuffled the deck randomly 100 times without changing its order. You should go buy a lottery ticket")
+    panic!("Congratulations, you just shuffled the deck randomly 100 times without changing its order. You should go buy a lottery ticket")
 }
 
 #[test]
@@ -1305,3 +1320,121 @@ fn test_deck_deal_from_n() {
     let dealt_cards_invalid = deck.deal_n_from(5, 2);
     assert!(dealt_cards_invalid.is_none());
 }
+
+#[test]
+fn test_deck_mul_preserves_top_insertion_order() {
+    let deck: Deck = "A♠ K♠ Q♠".parse().unwrap();
+    let mut expected = Deck::default();
+    for _ in 0..3 {
+        for card in &deck {
+            expected.add_card(*card);
+        }
+    }
+    assert_eq!(deck.clone() * 3, expected);
+    let mut assigned = deck.clone();
+    assigned *= 3;
+    assert_eq!(assigned, expected);
+    assert_eq!((deck.clone() * 1).to_string(), "Q♠ K♠ A♠");
+}
+
+#[test]
+fn test_deck_sub_deck_removes_all_copies() {
+    let deck: Deck = "A♠ K♠ A♠ Q♠".parse().unwrap();
+    let removed: Deck = "A♠ J♠".parse().unwrap();
+    assert_eq!((deck - removed).to_string(), "K♠ Q♠");
+}
+
+#[test]
+fn test_deck_partial_shuffle_is_seedable() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut a = Deck::from_factory(Standard52);
+    let mut b = Deck::from_factory(Standard52);
+    a.partial_shuffle_with_rng(5, &mut StdRng::seed_from_u64(42));
+    b.partial_shuffle_with_rng(5, &mut StdRng::seed_from_u64(42));
+    assert_eq!(a, b);
+    assert!(a.same_cards_as(&Deck::from_factory(Standard52)));
+
+    let mut small: Deck = "A♠ K♠".parse().unwrap();
+    small.partial_shuffle(10);
+    assert_eq!(small.len(), 2);
+}
+
+#[test]
+fn test_deck_partial_shuffle_covers_whole_deck() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let original = Deck::from_factory(Standard52);
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut seen = std::collections::HashSet::new();
+    for _ in 0..2000 {
+        let mut deck = original.clone();
+        deck.partial_shuffle_with_rng(1, &mut rng);
+        seen.insert(*deck.peek().unwrap());
+    }
+    assert_eq!(seen.len(), 52);
+}
+
+#[test]
+fn test_deck_draw_random() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut deck: Deck = "A♠ K♠ Q♠".parse().unwrap();
+    let card = deck
+        .draw_random_with_rng(&mut StdRng::seed_from_u64(3))
+        .unwrap();
+    assert_eq!(deck.len(), 2);
+    assert!(!deck.contains(&card));
+    assert!(deck.draw_random().is_some());
+    assert!(deck.draw_random().is_some());
+    assert_eq!(deck.draw_random(), None);
+}
+
+#[test]
+fn test_deck_sample_does_not_remove() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let deck = Deck::from_factory(Standard52);
+    let sample = deck
+        .sample_with_rng(5, &mut StdRng::seed_from_u64(9))
+        .unwrap();
+    assert_eq!(sample.len(), 5);
+    let distinct: std::collections::HashSet<Card> = sample.iter().copied().collect();
+    assert_eq!(distinct.len(), 5);
+    assert_eq!(
+        deck.sample_with_rng(5, &mut StdRng::seed_from_u64(9)),
+        Some(sample)
+    );
+    assert_eq!(deck.len(), 52);
+    assert_eq!(deck.sample(53), None);
+    assert_eq!(deck.sample(52).unwrap().len(), 52);
+}
+
+#[test]
+fn test_deck_take_random_keeps_remaining_order() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let original = Deck::from_factory(Standard52);
+    let mut deck = original.clone();
+    let taken = deck
+        .take_random_with_rng(5, &mut StdRng::seed_from_u64(5))
+        .unwrap();
+    assert_eq!(taken.len(), 5);
+    assert_eq!(deck.len(), 47);
+    let expected: Deck = original
+        .iter()
+        .filter(|card| !taken.contains(card))
+        .copied()
+        .collect();
+    assert_eq!(deck, expected);
+
+    assert_eq!(deck.take_random(48), None);
+    assert_eq!(deck.len(), 47);
+    assert_eq!(deck.take_random(47).unwrap().len(), 47);
+    assert!(deck.is_empty());
+}
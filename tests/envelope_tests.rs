@@ -0,0 +1,182 @@
+use crusty_cards::{
+    card_schema, deck_envelope_schema, deck_schema, packed_deck_schema, permutation_index_schema,
+    Deck, DeckEnvelope, PackedDeck, Standard52, Standard54, ENVELOPE_VERSION,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde_json::Value;
+
+fn shuffled(seed: u64) -> Deck {
+    let mut deck = Deck::from_factory(Standard54);
+    deck.partial_shuffle_with_rng(54, &mut StdRng::seed_from_u64(seed));
+    deck
+}
+
+#[test]
+fn test_envelope_round_trip() {
+    let deck = shuffled(42);
+    let envelope = DeckEnvelope::new(&deck)
+        .with_factory("standard54")
+        .with_seed(42)
+        .with_created_at("2024-05-01T20:00:00Z");
+    assert_eq!(envelope.format_version(), ENVELOPE_VERSION);
+    assert_eq!(envelope.card_count(), 54);
+    assert_eq!(envelope.checksum().len(), 8);
+
+    let json = envelope.to_json_pretty().unwrap();
+    let parsed = DeckEnvelope::from_json(&json).unwrap();
+    assert_eq!(parsed, envelope);
+    assert_eq!(parsed.factory(), Some("standard54"));
+    assert_eq!(parsed.seed(), Some(42));
+    assert_eq!(parsed.created_at(), Some("2024-05-01T20:00:00Z"));
+    assert_eq!(Deck::from_json(&json).unwrap(), deck);
+}
+
+#[test]
+fn test_envelope_wraps_plain_deck_json() {
+    let deck = Deck::from_factory(Standard52);
+    let envelope: Value =
+        serde_json::from_str(&DeckEnvelope::new(&deck).to_json().unwrap()).unwrap();
+    let plain: Value = serde_json::from_str(&deck.to_json().unwrap()).unwrap();
+    assert_eq!(envelope["deck"], plain);
+    assert!(envelope.get("factory").is_none());
+    assert!(envelope.get("seed").is_none());
+
+    // Plain decks still load.
+    assert_eq!(Deck::from_json(&deck.to_json().unwrap()).unwrap(), deck);
+}
+
+#[test]
+fn test_checksum_depends_on_order() {
+    let deck = Deck::from_factory(Standard52);
+    let a = DeckEnvelope::new(&deck);
+    let b = DeckEnvelope::new(&shuffled(1));
+    assert_ne!(a.checksum(), b.checksum());
+    assert_eq!(a.checksum(), DeckEnvelope::new(&deck).checksum());
+    assert_eq!(DeckEnvelope::new(&Deck::default()).checksum(), "00000000");
+}
+
+#[test]
+fn test_validation_errors() {
+    let deck = shuffled(7);
+    let json = DeckEnvelope::new(&deck).to_json().unwrap();
+    let mut value: Value = serde_json::from_str(&json).unwrap();
+
+    let mut tampered = value.clone();
+    tampered["deck"]["cards"].as_array_mut().unwrap().swap(0, 1);
+    let err = Deck::from_json(&tampered.to_string()).unwrap_err();
+    assert!(err.to_string().starts_with("Checksum mismatch"));
+
+    let mut short = value.clone();
+    short["card_count"] = 53.into();
+    let err = DeckEnvelope::from_json(&short.to_string()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Card count mismatch: envelope says 53 but deck has 54"
+    );
+
+    value["format_version"] = 99.into();
+    let err = Deck::from_json(&value.to_string()).unwrap_err();
+    assert_eq!(err.to_string(), "Unsupported deck envelope version 99");
+
+    assert!(Deck::from_json(r#"{"format_version": 1}"#).is_err());
+}
+
+#[test]
+fn test_schemas_describe_serialized_values() {
+    let card = card_schema();
+    let suits = card["properties"]["suit"]["enum"].as_array().unwrap();
+    let ranks = card["properties"]["rank"]["enum"].as_array().unwrap();
+    let deck: Value =
+        serde_json::from_str(&Deck::from_factory(Standard54).to_json().unwrap()).unwrap();
+    for card in deck["cards"].as_array().unwrap() {
+        assert!(suits.contains(&card["suit"]));
+        assert!(ranks.contains(&card["rank"]));
+    }
+
+    assert_eq!(deck_schema()["required"], serde_json::json!(["cards"]));
+    assert_eq!(
+        deck_envelope_schema()["properties"]["deck"]["properties"]["cards"]["items"]["$ref"],
+        "#/$defs/card"
+    );
+
+    let packed: Value =
+        serde_json::to_value(PackedDeck::from(Deck::from_factory(Standard54))).unwrap();
+    let max = packed_deck_schema()["items"]["maximum"].as_u64().unwrap();
+    assert!(packed
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|b| b.as_u64().unwrap() <= max));
+
+    let index = shuffled(3).permutation_index(Standard54).unwrap();
+    let index: Value = serde_json::to_value(index).unwrap();
+    assert!(index.is_string());
+    assert_eq!(permutation_index_schema()["type"], "string");
+    for schema in [card, deck_schema(), deck_envelope_schema()] {
+        assert_eq!(
+            schema["$schema"],
+            "https://json-schema.org/draft/2020-12/schema"
+        );
+    }
+}
+
+fn assert_valid(schema: &Value, instance: &Value) {
+    let validator = jsonschema::validator_for(schema).unwrap();
+    let errors: Vec<String> = validator
+        .iter_errors(instance)
+        .map(|e| e.to_string())
+        .collect();
+    assert!(
+        errors.is_empty(),
+        "{} failed: {:?}",
+        schema["title"],
+        errors
+    );
+}
+
+#[test]
+fn test_serialized_values_validate_against_schemas() {
+    for deck in [Deck::default(), Deck::from_factory(Standard52), shuffled(5)] {
+        let json: Value = serde_json::from_str(&deck.to_json().unwrap()).unwrap();
+        assert_valid(&deck_schema(), &json);
+        for card in json["cards"].as_array().unwrap() {
+            assert_valid(&card_schema(), card);
+        }
+
+        let packed = serde_json::to_value(PackedDeck::from(deck.clone())).unwrap();
+        assert_valid(&packed_deck_schema(), &packed);
+
+        for envelope in [
+            DeckEnvelope::new(&deck),
+            DeckEnvelope::new(&deck)
+                .with_factory("standard54")
+                .with_seed(u64::MAX)
+                .with_created_at("2024-05-01T20:00:00Z"),
+        ] {
+            let json: Value = serde_json::from_str(&envelope.to_json().unwrap()).unwrap();
+            assert_valid(&deck_envelope_schema(), &json);
+        }
+    }
+
+    let index = shuffled(9).permutation_index(Standard54).unwrap();
+    assert_valid(
+        &permutation_index_schema(),
+        &serde_json::to_value(index).unwrap(),
+    );
+}
+
+#[test]
+fn test_schemas_reject_other_shapes() {
+    let deck: Value = serde_json::from_str(&shuffled(2).to_json().unwrap()).unwrap();
+    let validator = jsonschema::validator_for(&deck_schema()).unwrap();
+    let mut extra = deck.clone();
+    extra["name"] = "mine".into();
+    assert!(!validator.is_valid(&extra));
+    let mut bad_rank = deck;
+    bad_rank["cards"][0]["rank"] = "One".into();
+    assert!(!validator.is_valid(&bad_rank));
+
+    let packed = jsonschema::validator_for(&packed_deck_schema()).unwrap();
+    assert!(!packed.is_valid(&serde_json::json!([56])));
+}
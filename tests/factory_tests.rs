@@ -1,4 +1,6 @@
-use crusty_cards::{DeckFactory, Rank, Standard52, Standard54};
+use crusty_cards::{
+    DeckFactory, Doppelkopf48, Euchre24, Rank, Schafkopf24, Skat32, Standard52, Standard54,
+};
 
 #[test]
 fn test_standard_52_deck() {
@@ -16,3 +18,36 @@ fn test_standard_54_deck() {
         .count();
     assert_eq!(joker_count, 2);
 }
+
+#[test]
+fn test_euchre_24_deck() {
+    let deck = Euchre24.generate();
+    assert_eq!(deck.len(), 24);
+    assert!(deck
+        .iter()
+        .all(|card| card.rank().value() >= Rank::Nine.value()));
+    assert!(deck.iter().all(|card| !card.is_joker()));
+}
+
+#[test]
+fn test_skat_32_deck() {
+    let deck = Skat32.generate();
+    assert_eq!(deck.len(), 32);
+    assert!(deck
+        .iter()
+        .all(|card| card.rank().value() >= Rank::Seven.value()));
+}
+
+#[test]
+fn test_schafkopf_24_deck() {
+    assert_eq!(Schafkopf24.generate(), Euchre24.generate());
+}
+
+#[test]
+fn test_doppelkopf_48_deck() {
+    let deck = Doppelkopf48.generate();
+    assert_eq!(deck.len(), 48);
+    for card in Euchre24.generate() {
+        assert_eq!(deck.iter().filter(|&&c| c == card).count(), 2);
+    }
+}
@@ -0,0 +1,174 @@
+#![cfg(any(
+    feature = "toml",
+    feature = "msgpack",
+    feature = "cbor",
+    feature = "bincode"
+))]
+
+use crusty_cards::{Deck, Standard54};
+
+fn shuffled() -> Deck {
+    let mut deck = Deck::from_factory(Standard54);
+    deck.shuffle();
+    deck
+}
+
+#[cfg(feature = "toml")]
+mod toml_format {
+    use super::*;
+    use crusty_cards::{Card, PackedDeck, PermutationIndex, Rank, Suit};
+    use serde::{Deserialize, Serialize};
+
+    #[test]
+    fn test_deck_toml_round_trip() {
+        let deck = shuffled();
+        let toml = deck.to_toml().unwrap();
+        assert_eq!(Deck::from_toml(&toml).unwrap(), deck);
+        assert_eq!(Deck::from_toml("cards = []").unwrap(), Deck::default());
+        assert!(Deck::from_toml("cards = 1").is_err());
+    }
+
+    #[test]
+    fn test_card_toml_round_trip() {
+        let card = Card::new(Suit::Clubs, Rank::Ten);
+        let toml = toml::to_string(&card).unwrap();
+        assert_eq!(toml, "suit = \"Clubs\"\nrank = \"Ten\"\n");
+        assert_eq!(toml::from_str::<Card>(&toml).unwrap(), card);
+    }
+
+    #[test]
+    fn test_compact_toml_round_trip() {
+        // A TOML document is a table, so the compact forms go in fields.
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Saved {
+            packed: PackedDeck,
+            index: PermutationIndex,
+        }
+
+        let deck = shuffled();
+        let saved = Saved {
+            packed: PackedDeck::from(deck.clone()),
+            index: deck.permutation_index(Standard54).unwrap(),
+        };
+        let toml = toml::to_string(&saved).unwrap();
+        let restored: Saved = toml::from_str(&toml).unwrap();
+        assert_eq!(restored, saved);
+        assert_eq!(
+            Deck::from_permutation_index(&restored.index, Standard54).unwrap(),
+            deck
+        );
+    }
+}
+
+#[cfg(feature = "msgpack")]
+mod msgpack_format {
+    use super::*;
+    use crusty_cards::{Card, PackedDeck, PermutationIndex, Rank, Standard52, Suit};
+
+    #[test]
+    fn test_deck_msgpack_round_trip() {
+        let deck = shuffled();
+        assert_eq!(
+            Deck::from_msgpack(&deck.to_msgpack().unwrap()).unwrap(),
+            deck
+        );
+        assert!(Deck::from_msgpack(&[0xc1]).is_err());
+    }
+
+    #[test]
+    fn test_card_and_compact_msgpack_round_trip() {
+        let card = Card::new(Suit::Hearts, Rank::Joker);
+        let bytes = rmp_serde::to_vec_named(&card).unwrap();
+        assert_eq!(rmp_serde::from_slice::<Card>(&bytes).unwrap(), card);
+
+        let packed = PackedDeck::from_factory(Standard52);
+        let bytes = rmp_serde::to_vec(&packed).unwrap();
+        assert_eq!(rmp_serde::from_slice::<PackedDeck>(&bytes).unwrap(), packed);
+
+        let index = Deck::from_factory(Standard52)
+            .permutation_index(Standard52)
+            .unwrap();
+        let bytes = rmp_serde::to_vec(&index).unwrap();
+        assert_eq!(
+            rmp_serde::from_slice::<PermutationIndex>(&bytes).unwrap(),
+            index
+        );
+    }
+}
+
+#[cfg(feature = "cbor")]
+mod cbor_format {
+    use super::*;
+    use crusty_cards::{Card, PackedDeck, PermutationIndex, Rank, Standard52, Suit};
+
+    #[test]
+    fn test_deck_cbor_round_trip() {
+        let deck = shuffled();
+        assert_eq!(Deck::from_cbor(&deck.to_cbor().unwrap()).unwrap(), deck);
+        assert!(Deck::from_cbor(&[0xff]).is_err());
+    }
+
+    #[test]
+    fn test_card_and_compact_cbor_round_trip() {
+        let card = Card::new(Suit::Spades, Rank::Ace);
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&card, &mut bytes).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<Card, _>(bytes.as_slice()).unwrap(),
+            card
+        );
+
+        let mut packed = PackedDeck::from_factory(Standard52);
+        packed.shuffle();
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&packed, &mut bytes).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<PackedDeck, _>(bytes.as_slice()).unwrap(),
+            packed
+        );
+
+        let index = shuffled().permutation_index(Standard54).unwrap();
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&index, &mut bytes).unwrap();
+        assert_eq!(
+            ciborium::from_reader::<PermutationIndex, _>(bytes.as_slice()).unwrap(),
+            index
+        );
+    }
+}
+
+#[cfg(feature = "bincode")]
+mod bincode_format {
+    use super::*;
+    use crusty_cards::{Card, PackedDeck, PermutationIndex, Rank, Standard52, Suit};
+
+    #[test]
+    fn test_deck_bincode_round_trip() {
+        let deck = shuffled();
+        assert_eq!(
+            Deck::from_bincode(&deck.to_bincode().unwrap()).unwrap(),
+            deck
+        );
+        assert!(Deck::from_bincode(&[1]).is_err());
+    }
+
+    #[test]
+    fn test_card_and_compact_bincode_round_trip() {
+        let card = Card::new(Suit::Diamonds, Rank::Queen);
+        let bytes = bincode::serialize(&card).unwrap();
+        assert_eq!(bincode::deserialize::<Card>(&bytes).unwrap(), card);
+
+        let packed = PackedDeck::from_factory(Standard52);
+        let bytes = bincode::serialize(&packed).unwrap();
+        // Eight-byte length prefix, then one byte per card.
+        assert_eq!(bytes.len(), 8 + 52);
+        assert_eq!(bincode::deserialize::<PackedDeck>(&bytes).unwrap(), packed);
+
+        let index = shuffled().permutation_index(Standard54).unwrap();
+        let bytes = bincode::serialize(&index).unwrap();
+        assert_eq!(
+            bincode::deserialize::<PermutationIndex>(&bytes).unwrap(),
+            index
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crusty_cards::{Card, Deck, GameRecord, Move, MoveKind, Rank, Standard52, Suit, DECK_ZONE};
+
+const RECORD: &str = "\
+[Game \"Crazy Eights\"]
+[Players \"alice, bob\"]
+[Date \"2024.05.01\"]
+[Seed \"42\"]
+[Factory \"standard52\"]
+[Result \"alice\"]
+
+Deck: 7♠ 5♥ 8♣ 2♦
+
+1. alice: 7♠ deck -> hand:alice
+2. bob: 5♥ deck -> hand:bob
+3. 8♣ deck -> pile
+4. alice: 7♠ hand:alice -> pile ; matches the eight by suit
+5. bob: pass
+6. bob: declare hearts now
+";
+
+#[test]
+fn test_parse_tags_deck_and_moves() {
+    let record: GameRecord = RECORD.parse().unwrap();
+    assert_eq!(record.game(), Some("Crazy Eights"));
+    assert_eq!(record.players(), &["alice".to_string(), "bob".to_string()]);
+    assert_eq!(record.date(), Some("2024.05.01"));
+    assert_eq!(record.seed(), Some(42));
+    assert_eq!(record.factory(), Some("standard52"));
+    assert_eq!(record.tag("Result"), Some("alice"));
+    assert_eq!(record.deck().to_string(), "7♠ 5♥ 8♣ 2♦");
+
+    let moves = record.moves();
+    assert_eq!(moves.len(), 6);
+    assert_eq!(moves[2].player(), None);
+    assert_eq!(
+        moves[3].kind(),
+        &MoveKind::Transfer {
+            cards: vec![Card::new(Suit::Spades, Rank::Seven)],
+            from: "hand:alice".to_string(),
+            to: "pile".to_string(),
+        }
+    );
+    assert_eq!(
+        moves[5].kind(),
+        &MoveKind::Action {
+            name: "declare".to_string(),
+            args: vec!["hearts".to_string(), "now".to_string()],
+        }
+    );
+}
+
+#[test]
+fn test_write_matches_canonical_text() {
+    let record: GameRecord = RECORD.parse().unwrap();
+    let written = record.to_string();
+    assert_eq!(written, RECORD.replace(" ; matches the eight by suit", ""));
+    assert_eq!(written.parse::<GameRecord>().unwrap(), record);
+}
+
+#[test]
+fn test_builder_round_trip() {
+    let mut deck = Deck::from_factory(Standard52);
+    deck.shuffle();
+    let mut record = GameRecord::new(deck.clone())
+        .with_game("Gin \"Rummy\"")
+        .with_seed(7)
+        .with_tag("Variant", "Oklahoma");
+    let top: Vec<Card> = deck.iter().take(3).copied().collect();
+    record.push_move(Move::transfer(&top, DECK_ZONE, "hand:ann").by("ann"));
+    record.push_move(Move::action("knock", &[]).by("ann"));
+
+    let parsed: GameRecord = record.to_string().parse().unwrap();
+    assert_eq!(parsed, record);
+    assert_eq!(parsed.game(), Some("Gin \"Rummy\""));
+    assert_eq!(parsed.tags().len(), 1);
+}
+
+#[test]
+fn test_empty_transfer_round_trip() {
+    let empty = Move::transfer(&[], DECK_ZONE, "pile").by("bob");
+    assert_eq!(empty.to_string(), "bob: deck -> pile");
+    assert_eq!(empty.to_string().parse::<Move>().unwrap(), empty);
+
+    let mut record = GameRecord::new(Deck::from_factory(Standard52));
+    record.push_move(empty);
+    let parsed: GameRecord = record.to_string().parse().unwrap();
+    assert_eq!(parsed, record);
+    assert_eq!(parsed.zones().unwrap()["pile"].len(), 0);
+}
+
+#[test]
+fn test_zones_replay_transfers() {
+    let record: GameRecord = RECORD.parse().unwrap();
+    let zones = record.zones().unwrap();
+    assert_eq!(zones[DECK_ZONE].len(), 1);
+    assert!(zones["hand:alice"].is_empty());
+    assert_eq!(zones["hand:bob"], vec![Card::new(Suit::Hearts, Rank::Five)]);
+    assert_eq!(zones["pile"].len(), 2);
+
+    let mut bad = record.clone();
+    bad.push_move(Move::transfer(
+        &[Card::new(Suit::Hearts, Rank::Five)],
+        "pile",
+        "hand:alice",
+    ));
+    assert_eq!(
+        bad.zones().unwrap_err(),
+        "Move 7: card 5♥ is not in zone pile"
+    );
+}
+
+#[test]
+fn test_unnumbered_moves_and_empty_deck() {
+    let record: GameRecord = "Deck:\nalice: pass\nbob: bid 2".parse().unwrap();
+    assert!(record.deck().is_empty());
+    assert_eq!(record.moves().len(), 2);
+    assert_eq!(
+        record.to_string(),
+        "Deck:\n\n1. alice: pass\n2. bob: bid 2\n"
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let err = "[Game \"x\"]\n".parse::<GameRecord>().unwrap_err();
+    assert_eq!(err, "Game record has no deck line");
+    let err = "Deck: 2♥\n2. alice: pass"
+        .parse::<GameRecord>()
+        .unwrap_err();
+    assert_eq!(err, "Failed to parse line 2: Expected move 1 but found 2");
+    let err = "1. alice: pass\nDeck: 2♥"
+        .parse::<GameRecord>()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        "Failed to parse line 1: Moves must come after the deck"
+    );
+    let err = "[Seed \"x\"]\nDeck:".parse::<GameRecord>().unwrap_err();
+    assert_eq!(err, "Failed to parse line 1: Invalid seed: x");
+    assert!("Deck: 2♥\n[Game \"x\"]".parse::<GameRecord>().is_err());
+    assert!("Deck: 2♥\nDeck: 3♥".parse::<GameRecord>().is_err());
+    assert!("Deck: 2X".parse::<GameRecord>().is_err());
+    assert!("Deck:\n1. 2♥ deck -> a b".parse::<GameRecord>().is_err());
+    assert!("Deck:\n1. zz deck -> pile".parse::<GameRecord>().is_err());
+}
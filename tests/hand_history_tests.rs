@@ -0,0 +1,166 @@
+use crusty_cards::{read_hand_histories, ActionKind, HandHistory, Street};
+
+mod common;
+
+use common::cards;
+
+const SHOWDOWN_HAND: &str = "\
+PokerStars Hand #230000000001: Hold'em No Limit ($0.05/$0.10 USD) - 2024/05/01 20:00:00 ET
+Table 'Vega II' 6-max Seat #3 is the button
+Seat 1: Big Al ($10.00 in chips)
+Seat 2: bob ($4.25 in chips)
+Seat 3: carol ($12.40 in chips) is sitting out
+Seat 4: Big ($1,000.00 in chips)
+Big Al: posts small blind $0.05
+bob: posts big blind $0.10
+Big: posts small & big blinds $0.15
+*** HOLE CARDS ***
+Dealt to bob [Qs Qd]
+Big: folds
+Big Al: raises $0.20 to $0.30
+bob: raises $3.95 to $4.25 and is all-in
+Big Al said, \"nice hand\"
+Big Al: calls $3.95
+*** FLOP *** [2c 7h Td]
+*** TURN *** [2c 7h Td] [Js]
+*** RIVER *** [2c 7h Td Js] [3s]
+*** SHOW DOWN ***
+Big Al: shows [Ah Kd] (high card Ace)
+bob: shows [Qs Qd] (a pair of Queens)
+bob collected $8.35 from pot
+*** SUMMARY ***
+Total pot $8.65 | Rake $0.30
+Board [2c 7h Td Js 3s]
+Seat 1: Big Al (small blind) showed [Ah Kd] and lost with high card Ace
+Seat 2: bob (big blind) showed [Qs Qd] and won ($8.35) with a pair of Queens
+";
+
+#[test]
+fn test_header_table_and_seats() {
+    let hand: HandHistory = SHOWDOWN_HAND.parse().unwrap();
+    assert_eq!(hand.site(), "PokerStars");
+    assert_eq!(hand.hand_id(), "230000000001");
+    assert_eq!(hand.game(), "Hold'em No Limit");
+    assert_eq!(hand.small_blind(), Some(5));
+    assert_eq!(hand.big_blind(), Some(10));
+    assert_eq!(hand.table(), Some("Vega II"));
+    assert_eq!(hand.max_seats(), Some(6));
+    assert_eq!(hand.button(), Some(3));
+
+    assert_eq!(hand.seats().len(), 4);
+    let seat = hand.seat("Big Al").unwrap();
+    assert_eq!(seat.number(), 1);
+    assert_eq!(seat.stack(), 1000);
+    assert!(hand.seat("carol").unwrap().is_sitting_out());
+    assert_eq!(hand.seat("Big").unwrap().stack(), 100_000);
+}
+
+#[test]
+fn test_actions_per_street() {
+    let hand: HandHistory = SHOWDOWN_HAND.parse().unwrap();
+    let preflop: Vec<_> = hand.actions_on(Street::Preflop).collect();
+    assert_eq!(preflop.len(), 7);
+    assert_eq!(preflop[0].player(), "Big Al");
+    assert_eq!(preflop[0].kind(), &ActionKind::SmallBlind(5));
+    assert_eq!(preflop[2].player(), "Big");
+    assert_eq!(preflop[2].kind(), &ActionKind::Post(15));
+    assert_eq!(preflop[3].kind(), &ActionKind::Fold);
+    assert_eq!(preflop[5].kind(), &ActionKind::Raise { by: 395, to: 425 });
+    assert!(preflop[5].is_all_in());
+    assert_eq!(preflop[6].kind(), &ActionKind::Call(395));
+    assert!(!preflop[6].is_all_in());
+    assert_eq!(hand.actions_on(Street::Flop).count(), 0);
+    assert_eq!(hand.actions_on(Street::Showdown).count(), 3);
+}
+
+#[test]
+fn test_cards_showdown_and_pots() {
+    let hand: HandHistory = SHOWDOWN_HAND.parse().unwrap();
+    assert_eq!(hand.board(), cards("2c 7h Td Js 3s").as_slice());
+    assert_eq!(hand.hole_cards("bob"), Some(cards("Qs Qd").as_slice()));
+    assert_eq!(hand.hole_cards("Big Al"), Some(cards("Ah Kd").as_slice()));
+    assert_eq!(hand.hole_cards("Big"), None);
+
+    let showdown = hand.showdown();
+    assert_eq!(showdown.len(), 2);
+    assert_eq!(showdown[1].0, "bob");
+
+    assert_eq!(hand.total_pot(), Some(865));
+    assert_eq!(hand.pots(), &[865]);
+    assert_eq!(hand.rake(), Some(30));
+    assert_eq!(hand.winnings("bob"), 835);
+    assert_eq!(hand.winnings("Big Al"), 0);
+}
+
+#[test]
+fn test_tournament_hand_with_side_pots() {
+    let text = "\
+PokerStars Hand #9: Tournament #77, $1.00+$0.10 USD Hold'em No Limit - Level II (15/30) - 2024/05/01
+Table '77 1' 9-max Seat #1 is the button
+Seat 1: ann (1500 in chips)
+Seat 2: ben (400 in chips)
+Seat 3: cat (900 in chips)
+ann: posts the ante 5
+ben: posts the ante 5
+cat: posts the ante 5
+ben: posts small blind 15
+cat: posts big blind 30
+*** HOLE CARDS ***
+ann: raises 1465 to 1495 and is all-in
+ben: calls 380 and is all-in
+cat: calls 865 and is all-in
+Uncalled bet (600) returned to ann
+*** FLOP *** [8h 8d 2s]
+*** TURN *** [8h 8d 2s] [Kc]
+*** RIVER *** [8h 8d 2s Kc] [4h]
+*** SHOW DOWN ***
+ann: shows [Ac Ad]
+cat: shows [Kh Ks]
+cat collected 1000 from side pot
+ben: shows [7c 2d]
+ann collected 1200 from main pot
+*** SUMMARY ***
+Total pot 2200 Main pot 1200. Side pot 1000. | Rake 0
+Board [8h 8d 2s Kc 4h]
+";
+    let hand: HandHistory = text.parse().unwrap();
+    assert_eq!(
+        hand.game(),
+        "Tournament #77, $1.00+$0.10 USD Hold'em No Limit"
+    );
+    assert_eq!(hand.small_blind(), Some(1500));
+    assert_eq!(hand.big_blind(), Some(3000));
+    assert_eq!(hand.actions()[0].kind(), &ActionKind::Ante(500));
+    assert_eq!(hand.actions()[8].kind(), &ActionKind::Return(60_000));
+    assert_eq!(hand.pots(), &[120_000, 100_000]);
+    assert_eq!(hand.winnings("cat"), 100_000);
+    assert_eq!(hand.showdown().len(), 3);
+}
+
+#[test]
+fn test_read_multiple_hands() {
+    let second = SHOWDOWN_HAND.replace("#230000000001", "#230000000002");
+    let file = format!("\u{feff}{}\n\n\n{}", SHOWDOWN_HAND, second);
+    let hands = read_hand_histories(&file).unwrap();
+    assert_eq!(hands.len(), 2);
+    assert_eq!(hands[1].hand_id(), "230000000002");
+    assert!(read_hand_histories("").unwrap().is_empty());
+    assert!(file.parse::<HandHistory>().is_err());
+}
+
+#[test]
+fn test_parse_errors_name_the_line() {
+    let err = read_hand_histories("Table 'x' 6-max").unwrap_err();
+    assert_eq!(err, "Failed to parse line 1: Expected a hand header");
+
+    let bad_card = SHOWDOWN_HAND.replace("[Qs Qd]", "[Qs Qx]");
+    let err = bad_card.parse::<HandHistory>().unwrap_err();
+    assert!(err.starts_with("Failed to parse line 11: Invalid card string"));
+
+    let bad_amount = SHOWDOWN_HAND.replace("calls $3.95", "calls $3.955");
+    let err = bad_amount.parse::<HandHistory>().unwrap_err();
+    assert_eq!(err, "Failed to parse line 16: Invalid amount: $3.955");
+
+    let bad_seat = SHOWDOWN_HAND.replace("Seat 2: bob", "Seat two: bob");
+    assert!(bad_seat.parse::<HandHistory>().is_err());
+}
@@ -0,0 +1,148 @@
+use crusty_cards::{
+    is_isomorphic, CanonicalHand, Card, Deck, IsomorphismClasses, Rank, Standard52, Standard54,
+    Suit, SuitPermutation,
+};
+
+mod common;
+
+use common::cards;
+
+#[test]
+fn test_suit_permutations() {
+    let all = SuitPermutation::all();
+    assert_eq!(all.len(), 24);
+    assert_eq!(all[0], SuitPermutation::identity());
+    assert_eq!(SuitPermutation::default(), SuitPermutation::identity());
+    let distinct: std::collections::HashSet<_> = all.iter().collect();
+    assert_eq!(distinct.len(), 24);
+    for permutation in &all {
+        for suit in Suit::ALL {
+            assert_eq!(
+                permutation.inverse().map_suit(permutation.map_suit(suit)),
+                suit
+            );
+        }
+    }
+}
+
+#[test]
+fn test_suit_permutation_validation_and_display() {
+    assert!(SuitPermutation::new([Suit::Hearts; 4]).is_err());
+    let swap =
+        SuitPermutation::new([Suit::Spades, Suit::Diamonds, Suit::Clubs, Suit::Hearts]).unwrap();
+    assert_eq!(swap.to_string(), "♥→♠ ♦→♦ ♣→♣ ♠→♥");
+    let joker = Card::new(Suit::Hearts, Rank::Joker);
+    assert_eq!(swap.apply(&joker), joker);
+}
+
+#[test]
+fn test_canonical_preflop() {
+    assert_eq!(
+        CanonicalHand::of(&cards("A♥ K♥")),
+        CanonicalHand::of(&cards("A♠ K♠"))
+    );
+    assert_ne!(
+        CanonicalHand::of(&cards("A♥ K♥")),
+        CanonicalHand::of(&cards("A♠ K♣"))
+    );
+    assert_eq!(CanonicalHand::of(&cards("2♣ A♠")).to_string(), "A♥ 2♦");
+    assert_eq!(CanonicalHand::of(&cards("Q♦ Q♠")).to_string(), "Q♥ Q♦");
+}
+
+#[test]
+fn test_canonical_flop_given_hole_cards() {
+    let hole = cards("A♠ K♠");
+    let same_suit_flop = CanonicalHand::new(&[&hole, &cards("Q♠ J♠ 2♠")]);
+    let other_suit_flop = CanonicalHand::new(&[&hole, &cards("Q♥ J♥ 2♥")]);
+    assert_eq!(same_suit_flop.to_string(), "A♥ K♥ | Q♥ J♥ 2♥");
+    assert_eq!(other_suit_flop.to_string(), "A♥ K♥ | Q♦ J♦ 2♦");
+    assert!(is_isomorphic(
+        &[&cards("A♦ K♦"), &cards("Q♣ J♣ 2♣")],
+        &[&hole, &cards("Q♥ J♥ 2♥")]
+    ));
+    assert!(!is_isomorphic(
+        &[&hole, &cards("Q♠ J♠ 2♠")],
+        &[&hole, &cards("Q♥ J♥ 2♥")]
+    ));
+}
+
+#[test]
+fn test_canonical_permutation_maps_original() {
+    let hole = cards("7♣ 7♠");
+    let flop = cards("K♦ 2♣ 9♠");
+    let canonical = CanonicalHand::new(&[&hole, &flop]);
+    let permutation = canonical.permutation();
+    let mut mapped = permutation.apply_all(&flop);
+    mapped.sort_by_key(|c| std::cmp::Reverse(c.rank().value()));
+    assert_eq!(mapped, canonical.group(1).unwrap());
+    assert_eq!(canonical.group(2), None);
+    assert_eq!(canonical.cards().len(), 5);
+
+    let back: Vec<Card> = permutation.inverse().apply_all(canonical.group(0).unwrap());
+    assert!(back.iter().all(|c| hole.contains(c)));
+}
+
+#[test]
+fn test_deck_canonical_form() {
+    let deck: Deck = "T♣ 9♣ 8♦".parse().unwrap();
+    assert_eq!(deck.canonical_form().to_string(), "T♥ 9♥ 8♦");
+}
+
+#[test]
+fn test_preflop_classes() {
+    let classes = IsomorphismClasses::from_factory(Standard52, &[2]);
+    assert_eq!(classes.len(), 169);
+    assert!(!classes.is_empty());
+    assert_eq!(classes.group_sizes(), &[2]);
+    assert_eq!(classes.iter().map(|(_, size)| size).sum::<usize>(), 1326);
+
+    let suited = classes.index_of(&[&cards("A♣ K♣")]).unwrap();
+    let offsuit = classes.index_of(&[&cards("A♣ K♦")]).unwrap();
+    let pair = classes.index_of(&[&cards("7♣ 7♦")]).unwrap();
+    assert_eq!(classes.class_size(suited), Some(4));
+    assert_eq!(classes.class_size(offsuit), Some(12));
+    assert_eq!(classes.class_size(pair), Some(6));
+    assert_eq!(classes.class_size(169), None);
+    assert_eq!(classes.representative(169), None);
+
+    for index in 0..classes.len() {
+        let representative = classes.representative(index).unwrap();
+        let group = representative.group(0).unwrap();
+        assert_eq!(classes.index_of(&[group]), Some(index));
+    }
+    assert_eq!(classes.index_of(&[&cards("A♣ K♣ Q♣")]), None);
+}
+
+#[test]
+fn test_flop_classes() {
+    let classes = IsomorphismClasses::from_factory(Standard52, &[3]);
+    assert_eq!(classes.len(), 1755);
+    assert_eq!(classes.iter().map(|(_, size)| size).sum::<usize>(), 22100);
+}
+
+#[test]
+fn test_multi_group_classes() {
+    let small: Vec<Card> = Deck::from_factory(Standard52)
+        .into_iter()
+        .filter(|c| c.rank().value() >= Rank::Queen.value())
+        .collect();
+    let classes = IsomorphismClasses::new(&small, &[2, 1]);
+    let total: usize = classes.iter().map(|(_, size)| size).sum();
+    assert_eq!(total, 66 * 10);
+    let index = classes.index_of(&[&cards("A♠ A♥"), &cards("K♠")]).unwrap();
+    assert_eq!(
+        classes.index_of(&[&cards("A♣ A♦"), &cards("K♦")]),
+        Some(index)
+    );
+    assert_ne!(
+        classes.index_of(&[&cards("A♣ A♦"), &cards("K♥")]),
+        Some(index)
+    );
+}
+
+#[test]
+fn test_jokers_are_fixed() {
+    let with_joker = IsomorphismClasses::from_factory(Standard54, &[1]);
+    // Every suited rank collapses to one class; the two jokers stay distinct.
+    assert_eq!(with_joker.len(), 13 + 2);
+}
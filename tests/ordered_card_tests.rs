@@ -0,0 +1,106 @@
+use crusty_cards::{
+    AceLowComparator, Card, CardComparator, Deck, OrderedCard, Rank, SchafkopfComparator,
+    Standard52, StandardComparator, Suit, TrumpComparator,
+};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, BinaryHeap, HashSet};
+
+mod common;
+
+use common::{card, cards};
+
+#[test]
+fn test_binary_heap_pops_highest() {
+    let mut heap: BinaryHeap<OrderedCard> = cards("9H AS 2C KD")
+        .into_iter()
+        .map(OrderedCard::new)
+        .collect();
+    let order: Vec<String> = std::iter::from_fn(|| heap.pop())
+        .map(|c| c.to_string())
+        .collect();
+    assert_eq!(order, ["A♠", "K♦", "9♥", "2♣"]);
+}
+
+#[test]
+fn test_btree_set_uses_comparator_and_keeps_ties() {
+    let set: BTreeSet<OrderedCard<AceLowComparator>> = cards("KH AS AH 2C")
+        .into_iter()
+        .map(OrderedCard::from)
+        .collect();
+    // Both aces are kept even though the comparator ranks them equal.
+    assert_eq!(set.len(), 4);
+    let order: Vec<Card> = set.into_iter().map(Card::from).collect();
+    assert_eq!(
+        order[..2].iter().map(|c| c.rank()).collect::<Vec<_>>(),
+        [Rank::Ace, Rank::Ace]
+    );
+    assert_eq!(order[3], card("KH"));
+}
+
+#[test]
+fn test_stateful_comparator_through_unit_struct() {
+    #[derive(Default)]
+    struct SpadesTrump;
+
+    impl CardComparator for SpadesTrump {
+        fn rank_value(&self, rank: Rank) -> i32 {
+            rank.value() as i32
+        }
+
+        fn compare(&self, a: &Card, b: &Card) -> Ordering {
+            TrumpComparator::new(Suit::Spades).compare(a, b)
+        }
+    }
+
+    let set: BTreeSet<OrderedCard<SpadesTrump>> = cards("AH 2S KD")
+        .into_iter()
+        .map(OrderedCard::new)
+        .collect();
+    assert_eq!(set.last().unwrap().card(), card("2S"));
+
+    let set: BTreeSet<OrderedCard<SchafkopfComparator>> = cards("AH QD JC")
+        .into_iter()
+        .map(OrderedCard::new)
+        .collect();
+    assert_eq!(set.last().unwrap().card(), card("QD"));
+}
+
+#[test]
+fn test_wrapper_traits() {
+    let ordered: OrderedCard = OrderedCard::new(card("QS"));
+    let copy = ordered;
+    assert_eq!(copy, ordered);
+    assert_eq!(ordered.rank(), Rank::Queen);
+    assert_eq!(ordered.to_string(), "Q♠");
+    assert_eq!(
+        format!("{:?}", ordered),
+        format!("OrderedCard({:?})", card("QS"))
+    );
+    assert_eq!(ordered.into_card(), card("QS"));
+    let set: HashSet<OrderedCard> = [ordered, copy].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert_eq!(
+        std::mem::size_of::<OrderedCard>(),
+        std::mem::size_of::<Card>()
+    );
+}
+
+#[test]
+fn test_max_min_and_sorted() {
+    let hand = cards("7D AS KH 7C");
+    assert_eq!(StandardComparator.max_of(&hand), Some(&card("AS")));
+    assert_eq!(AceLowComparator.max_of(&hand), Some(&card("KH")));
+    assert_eq!(AceLowComparator.min_of(hand.iter()), Some(&card("AS")));
+    // The first of equal cards wins.
+    assert_eq!(StandardComparator.min_of(&hand), Some(&card("7D")));
+    assert_eq!(StandardComparator.max_of(&[]), None);
+
+    let deck: Deck = hand.iter().copied().collect();
+    let sorted = StandardComparator.sorted(deck);
+    assert_eq!(sorted, cards("7D 7C KH AS"));
+    let sorted = StandardComparator
+        .reversed()
+        .sorted(Deck::from_factory(Standard52));
+    assert_eq!(sorted[0].rank(), Rank::Ace);
+    assert_eq!(sorted[51].rank(), Rank::Two);
+}
@@ -0,0 +1,200 @@
+use crusty_cards::{
+    Card, Deck, PackedDeck, Rank, Standard52, Standard54, StandardComparator, Suit,
+};
+
+#[test]
+fn test_packed_deck_from_factory() {
+    let packed = PackedDeck::from_factory(Standard54);
+    assert_eq!(packed.len(), 54);
+    assert_eq!(packed.to_deck(), Deck::from_factory(Standard54));
+    assert_eq!(packed.as_bytes().len(), 54);
+}
+
+#[test]
+fn test_packed_deck_deal_and_reset() {
+    let mut packed = PackedDeck::from_factory(Standard52);
+    let deck = Deck::from_factory(Standard52);
+
+    assert_eq!(packed.deal(), deck.peek().copied());
+    assert_eq!(packed.deal_bottom(), deck.peek_bottom().copied());
+    assert_eq!(packed.deal_n(3).unwrap().len(), 3);
+    assert_eq!(packed.len(), 47);
+    assert_eq!(packed.dealt(), 5);
+    assert_eq!(packed.deal_n(48), None);
+    assert_eq!(packed.len(), 47);
+
+    packed.reset();
+    assert_eq!(packed.len(), 52);
+    assert_eq!(packed.dealt(), 0);
+    assert_eq!(packed.to_deck(), deck);
+}
+
+#[test]
+fn test_packed_deck_empty() {
+    let mut packed = PackedDeck::default();
+    assert!(packed.is_empty());
+    assert_eq!(packed.deal(), None);
+    assert_eq!(packed.deal_bottom(), None);
+    assert_eq!(packed.peek(), None);
+    assert_eq!(packed.peek_bottom(), None);
+    assert_eq!(packed.deal_n(0), Some(vec![]));
+    packed.shuffle();
+    assert!(!packed.cut(0));
+}
+
+#[test]
+fn test_packed_deck_reset_and_shuffle_keeps_cards() {
+    let mut packed = PackedDeck::from_factory(Standard52);
+    packed.deal_n(10).unwrap();
+    packed.reset_and_shuffle();
+    assert_eq!(packed.len(), 52);
+    assert!(packed
+        .to_deck()
+        .same_cards_as(&Deck::from_factory(Standard52)));
+}
+
+#[test]
+fn test_packed_deck_shuffle_only_live_cards() {
+    let mut packed = PackedDeck::from_factory(Standard52);
+    let dealt = packed.deal_n(5).unwrap();
+    packed.shuffle();
+    packed.reset();
+    assert_eq!(packed.deal_n(5).unwrap(), dealt);
+}
+
+#[test]
+fn test_packed_deck_add_cards() {
+    let ace = Card::new(Suit::Spades, Rank::Ace);
+    let king = Card::new(Suit::Hearts, Rank::King);
+    let mut packed = PackedDeck::default();
+    packed.add_card(ace);
+    packed.add_card(king);
+    packed.add_card_bottom(ace);
+    assert_eq!(packed.to_string(), "K♥ A♠ A♠");
+
+    let top = packed.deal().unwrap();
+    packed.add_card(top);
+    assert_eq!(packed.to_string(), "K♥ A♠ A♠");
+    let bottom = packed.deal_bottom().unwrap();
+    packed.add_card_bottom(bottom);
+    assert_eq!(packed.len(), 3);
+    assert_eq!(packed.count(&ace), 2);
+}
+
+#[test]
+fn test_packed_deck_lookup() {
+    let deck: Deck = "A♠ K♥ Q♦ A♠".parse().unwrap();
+    let packed = PackedDeck::from(&deck);
+    let ace = Card::new(Suit::Spades, Rank::Ace);
+    assert!(packed.contains(&ace));
+    assert!(!packed.contains(&Card::new(Suit::Clubs, Rank::Two)));
+    assert_eq!(
+        packed.find(&Card::new(Suit::Diamonds, Rank::Queen)),
+        Some(2)
+    );
+    assert_eq!(packed.find(&Card::new(Suit::Clubs, Rank::Two)), None);
+    assert_eq!(packed.count(&ace), 2);
+    assert_eq!(packed.peek_at(1), Some(Card::new(Suit::Hearts, Rank::King)));
+    assert_eq!(packed.peek_at(4), None);
+    assert_eq!(packed.peek_bottom(), Some(ace));
+}
+
+#[test]
+fn test_packed_deck_reorder() {
+    let deck: Deck = "A♠ K♠ Q♠ J♠".parse().unwrap();
+    let mut packed = PackedDeck::from(deck.clone());
+    assert!(packed.cut(2));
+    assert_eq!(packed.to_string(), "Q♠ J♠ A♠ K♠");
+    assert!(!packed.cut(4));
+    packed.reverse();
+    assert_eq!(packed.to_string(), "K♠ A♠ J♠ Q♠");
+    packed.sort_by_comparator(&StandardComparator);
+    assert_eq!(packed.to_string(), "J♠ Q♠ K♠ A♠");
+    packed.clear();
+    assert!(packed.is_empty());
+    packed.reset();
+    assert!(packed.is_empty());
+}
+
+#[test]
+fn test_packed_deck_equality_ignores_dealt_cards() {
+    let mut a = PackedDeck::from_factory(Standard52);
+    a.deal();
+    let b: PackedDeck = Deck::from_factory(Standard52).into_iter().skip(1).collect();
+    assert_eq!(a, b);
+
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let hash = |deck: &PackedDeck| {
+        let mut hasher = DefaultHasher::new();
+        deck.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&a), hash(&b));
+}
+
+#[test]
+fn test_packed_deck_conversions() {
+    let deck = Deck::from_factory(Standard52);
+    let packed = PackedDeck::from(&deck);
+    let bytes: Vec<u8> = packed.clone().into();
+    assert_eq!(bytes.len(), 52);
+    assert_eq!(PackedDeck::try_from(bytes).unwrap(), packed);
+    assert_eq!(
+        PackedDeck::try_from(vec![0, 56]).unwrap_err(),
+        "Invalid card value: 56"
+    );
+    assert_eq!(Deck::from(packed.clone()), deck);
+    assert_eq!(
+        PackedDeck::new(&[Card::new(Suit::Hearts, Rank::Two)]).as_bytes(),
+        &[0]
+    );
+}
+
+#[test]
+fn test_packed_deck_serialization() {
+    let mut packed = PackedDeck::from_factory(Standard52);
+    packed.deal_n(50).unwrap();
+    let json = serde_json::to_string(&packed).unwrap();
+    let expected: Vec<u8> = packed.iter().map(u8::from).collect();
+    assert_eq!(json, serde_json::to_string(&expected).unwrap());
+    let restored: PackedDeck = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, packed);
+    assert!(serde_json::from_str::<PackedDeck>("[99]").is_err());
+}
+
+#[test]
+fn test_packed_deck_random_sampling() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut packed = PackedDeck::from_factory(Standard52);
+    let full = Deck::from_factory(Standard52);
+
+    let card = packed.draw_random_with_rng(&mut rng).unwrap();
+    assert_eq!(packed.len(), 51);
+    assert!(!packed.contains(&card));
+
+    let sample = packed.sample_with_rng(5, &mut rng).unwrap();
+    assert_eq!(sample.len(), 5);
+    assert!(sample.iter().all(|c| packed.contains(c)));
+    assert_eq!(packed.sample(52), None);
+    assert_eq!(packed.len(), 51);
+
+    let hand = packed.take_random_with_rng(5, &mut rng).unwrap();
+    assert_eq!(packed.len(), 46);
+    assert!(hand.iter().all(|c| !packed.contains(c)));
+    assert_eq!(packed.take_random(47), None);
+
+    packed.reset();
+    assert!(packed.to_deck().same_cards_as(&full));
+    packed.partial_shuffle(3);
+    packed.shuffle_with_rng(&mut rng);
+    assert!(packed.to_deck().same_cards_as(&full));
+
+    let mut empty = PackedDeck::default();
+    assert_eq!(empty.draw_random(), None);
+    assert_eq!(empty.take_random(0), Some(vec![]));
+    assert_eq!(empty.sample(0), Some(vec![]));
+}
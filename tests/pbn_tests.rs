@@ -0,0 +1,211 @@
+use crusty_cards::{
+    read_pbn, write_pbn, BridgeComparator, Card, CardComparator, Deal, DealPattern, Deck,
+    Direction, PbnBoard, PbnDeal, Rank, Standard52, Suit, Vulnerability,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::cmp::Reverse;
+
+const DEAL: &str = "N:AKQ2.JT9.876.543 JT98.AK2.AK2.762 7654.876.QJT.AKQ 3.Q543.9543.JT98";
+
+#[test]
+fn test_direction_parse_and_display() {
+    assert_eq!("n".parse::<Direction>().unwrap(), Direction::North);
+    assert_eq!("West".parse::<Direction>().unwrap(), Direction::West);
+    assert!("X".parse::<Direction>().is_err());
+    assert_eq!(Direction::South.to_string(), "S");
+    assert_eq!(Direction::West.next(), Direction::North);
+    assert_eq!(Direction::dealer_for_board(4), Direction::West);
+    assert_eq!(Direction::dealer_for_board(5), Direction::North);
+}
+
+#[test]
+fn test_vulnerability_parse_and_schedule() {
+    assert_eq!(
+        "love".parse::<Vulnerability>().unwrap(),
+        Vulnerability::None
+    );
+    assert_eq!("-".parse::<Vulnerability>().unwrap(), Vulnerability::None);
+    assert_eq!(
+        "Both".parse::<Vulnerability>().unwrap(),
+        Vulnerability::Both
+    );
+    assert_eq!(Vulnerability::Both.to_string(), "All");
+    assert!("NE".parse::<Vulnerability>().is_err());
+
+    let schedule: Vec<&str> = (1..=16)
+        .map(|b| Vulnerability::for_board(b).name())
+        .collect();
+    assert_eq!(
+        schedule.join(" "),
+        "None NS EW All NS EW All None EW All None NS All None NS EW"
+    );
+    assert!(Vulnerability::NorthSouth.is_vulnerable(Direction::South));
+    assert!(!Vulnerability::NorthSouth.is_vulnerable(Direction::East));
+}
+
+#[test]
+fn test_deal_parse_assigns_hands_clockwise() {
+    let deal: PbnDeal = "E:AKQ2.JT9.876.543 JT98.AK2.AK2.762 7654.876.QJT.AKQ 3.Q543.9543.JT98"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        deal.hand(Direction::East)[0],
+        Card::new(Suit::Spades, Rank::Ace)
+    );
+    assert_eq!(
+        deal.hand(Direction::North)[0],
+        Card::new(Suit::Spades, Rank::Three)
+    );
+    assert_eq!(
+        deal.to_string(),
+        "N:3.Q543.9543.JT98 AKQ2.JT9.876.543 JT98.AK2.AK2.762 7654.876.QJT.AKQ"
+    );
+}
+
+#[test]
+fn test_deal_emit_orders_suits_for_bridge() {
+    let mut hands: [Vec<Card>; 4] = Default::default();
+    hands[0] = "2♣ A♥ T♠ 3♦ K♠"
+        .parse::<Deck>()
+        .unwrap()
+        .into_iter()
+        .collect();
+    let deal = PbnDeal::new(hands).unwrap();
+    assert_eq!(deal.to_string(), "N:KT.A.3.2 - - -");
+    assert_eq!(deal.to_string().parse::<PbnDeal>().unwrap(), deal);
+}
+
+#[test]
+fn test_deal_parse_errors() {
+    assert!("AKQ2.JT9.876.543".parse::<PbnDeal>().is_err());
+    assert!("N:AKQ2.JT9.876.543 - -".parse::<PbnDeal>().is_err());
+    assert!("N:AKQ2.JT9.876 - - -".parse::<PbnDeal>().is_err());
+    assert!("N:AKX2.JT9.876.543 - - -".parse::<PbnDeal>().is_err());
+    assert!("N:AKU2.JT9.876.543 - - -".parse::<PbnDeal>().is_err());
+    let err = "N:A... A... - -".parse::<PbnDeal>().unwrap_err();
+    assert_eq!(err, "Duplicate card A♠");
+}
+
+#[test]
+fn test_deal_converts_to_and_from_deal() {
+    let mut rng = StdRng::seed_from_u64(39);
+    let mut deck = Deck::from_factory(Standard52);
+    deck.partial_shuffle_with_rng(52, &mut rng);
+    let deal = deck.deal_to(4, &DealPattern::round_robin(13)).unwrap();
+
+    let pbn = PbnDeal::from_deal(&deal).unwrap();
+    assert_eq!(pbn.to_string().parse::<PbnDeal>().unwrap(), pbn);
+    for direction in Direction::ALL {
+        let mut expected = deal.hand(direction.index()).unwrap().to_vec();
+        expected.sort_by(|a, b| BridgeComparator.compare(b, a));
+        expected.sort_by_key(|card| Reverse(BridgeComparator.suit_value(card.suit())));
+        assert_eq!(pbn.hand(direction), expected.as_slice());
+    }
+
+    let back: Deal = pbn.clone().into();
+    assert_eq!(PbnDeal::from_deal(&back).unwrap(), pbn);
+    assert!(PbnDeal::from_deal(&Deal::new(vec![Vec::new(); 3], Vec::new())).is_err());
+}
+
+#[test]
+fn test_board_emits_common_tags() {
+    let board = PbnBoard::new(DEAL.parse().unwrap())
+        .with_board(7)
+        .with_dealer(Direction::South)
+        .with_vulnerable(Vulnerability::Both)
+        .with_tag("Event", "Tuesday \"open\" pairs");
+    assert_eq!(
+        board.to_string(),
+        "[Event \"Tuesday \\\"open\\\" pairs\"]\n\
+         [Board \"7\"]\n\
+         [Dealer \"S\"]\n\
+         [Vulnerable \"All\"]\n\
+         [Deal \"S:7654.876.QJT.AKQ 3.Q543.9543.JT98 AKQ2.JT9.876.543 JT98.AK2.AK2.762\"]\n"
+    );
+    assert_eq!(board.to_string().parse::<PbnBoard>().unwrap(), board);
+}
+
+#[test]
+fn test_board_writes_mandatory_tags_in_standard_order() {
+    let board = PbnBoard::new(DEAL.parse().unwrap())
+        .with_tag("Result", "9")
+        .with_tag("Auction", "N")
+        .with_tag("Contract", "3NT")
+        .with_tag("North", "Smith")
+        .with_tag("Site", "Club")
+        .with_dealer(Direction::North)
+        .with_board(1)
+        .with_tag("Declarer", "S")
+        .with_tag("Event", "Pairs");
+    let names: Vec<String> = board
+        .to_string()
+        .lines()
+        .map(|line| line[1..].split(' ').next().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "Event", "Site", "Board", "North", "Dealer", "Deal", "Declarer", "Contract", "Result",
+            "Auction"
+        ]
+    );
+
+    let written = write_pbn(&[board]);
+    assert!(written.starts_with("% PBN 2.1\n\n[Event"));
+    assert!(!written.contains("% EXPORT"));
+}
+
+#[test]
+fn test_read_pbn_multiple_boards() {
+    let pbn = "\u{feff}% PBN 2.1\n\
+               % EXPORT\n\
+               {Session one}\n\
+               [Event \"Club pairs\"]\n\
+               [Board \"1\"]\n\
+               [Dealer \"N\"]\n\
+               [Vulnerable \"None\"]\n\
+               [Deal \"N:AKQ2.JT9.876.543 JT98.AK2.AK2.762 7654.876.QJT.AKQ 3.Q543.9543.JT98\"]\n\
+               [Auction \"N\"]\n\
+               1C Pass 1S Pass\n\
+               \n\
+               [Event \"#\"]\n\
+               [Board \"2\"] ; second board\n\
+               [Dealer \"E\"]\n\
+               [Vulnerable \"NS\"]\n\
+               {multi-line\n\
+               commentary}\n\
+               [Deal \"E:- - - AKQJT98765432...\"]\n";
+    let boards = read_pbn(pbn).unwrap();
+    assert_eq!(boards.len(), 2);
+    assert_eq!(boards[0].board(), Some(1));
+    assert_eq!(boards[0].tag("Auction"), Some("N"));
+    assert_eq!(boards[1].tag("Event"), Some("Club pairs"));
+    assert_eq!(boards[1].dealer(), Some(Direction::East));
+    assert_eq!(boards[1].vulnerable(), Some(Vulnerability::NorthSouth));
+    assert_eq!(boards[1].deal().hand(Direction::North).len(), 13);
+    assert!(boards[1].deal().hand(Direction::East).is_empty());
+
+    let written = write_pbn(&boards);
+    assert!(written.starts_with("% PBN 2.1\n"));
+    assert_eq!(read_pbn(&written).unwrap(), boards);
+}
+
+#[test]
+fn test_read_pbn_unknown_values_and_errors() {
+    let boards = read_pbn(&format!("[Dealer \"?\"]\n[Deal \"{}\"]\n", DEAL)).unwrap();
+    assert_eq!(boards[0].dealer(), None);
+    assert!(read_pbn("").unwrap().is_empty());
+
+    let err = read_pbn("[Board \"1\"]\n[Dealer \"N\"]\n").unwrap_err();
+    assert_eq!(err, "Failed to parse line 1: Board has no Deal tag");
+    let err = read_pbn(&format!("\n[Deal \"{}\"]\n[Board \"x\"]\n", DEAL)).unwrap_err();
+    assert_eq!(err, "Failed to parse line 2: Invalid board number: x");
+    let err = read_pbn("[Deal N:- - - -]\n").unwrap_err();
+    assert_eq!(
+        err,
+        "Failed to parse line 1: Missing quoted value for tag Deal"
+    );
+    assert!(read_pbn("[Deal \"N:- - - -\"\n").is_err());
+    assert!("".parse::<PbnBoard>().is_err());
+}
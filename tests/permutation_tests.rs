@@ -0,0 +1,174 @@
+use crusty_cards::{Card, Deck, PermutationIndex, Rank, Standard52, Standard54, Suit};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+
+use common::cards;
+
+#[test]
+fn test_permutation_index_enumerates_all_orders() {
+    let reference = cards("A♠ K♠ Q♠ J♠");
+    let mut seen = std::collections::HashSet::new();
+    for i in 0..24u64 {
+        let order = PermutationIndex::from(i).unrank(&reference).unwrap();
+        assert_eq!(
+            PermutationIndex::rank(&order, &reference).unwrap(),
+            PermutationIndex::from(i)
+        );
+        seen.insert(order);
+    }
+    assert_eq!(seen.len(), 24);
+    assert!(PermutationIndex::from(24).unrank(&reference).is_err());
+}
+
+#[test]
+fn test_permutation_index_lexicographic_order() {
+    let reference = cards("A♠ K♠ Q♠");
+    let order = |i: u64| {
+        let deck: Deck = PermutationIndex::from(i)
+            .unrank(&reference)
+            .unwrap()
+            .into_iter()
+            .collect();
+        deck.to_string()
+    };
+    assert_eq!(order(0), "A♠ K♠ Q♠");
+    assert_eq!(order(1), "A♠ Q♠ K♠");
+    assert_eq!(order(2), "K♠ A♠ Q♠");
+    assert_eq!(order(5), "Q♠ K♠ A♠");
+}
+
+#[test]
+fn test_deck_permutation_round_trip() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..20 {
+        let mut deck = Deck::from_factory(Standard54);
+        deck.partial_shuffle_with_rng(54, &mut rng);
+        let index = deck.permutation_index(Standard54).unwrap();
+        assert!(index.to_bytes().len() <= PermutationIndex::max_bytes(54));
+        assert_eq!(
+            Deck::from_permutation_index(&index, Standard54).unwrap(),
+            deck
+        );
+    }
+}
+
+#[test]
+fn test_reversed_deck_has_largest_index() {
+    let mut deck = Deck::from_factory(Standard52);
+    deck.reverse();
+    let index = deck.permutation_index(Standard52).unwrap();
+    assert_eq!(
+        index.to_string(),
+        "80658175170943878571660636856403766975289505440883277823999999999999"
+    );
+    assert_eq!(index.to_bytes().len(), 29);
+    assert_eq!(PermutationIndex::max_bytes(52), 29);
+}
+
+#[test]
+fn test_permutation_index_errors() {
+    let mut deck = Deck::from_factory(Standard52);
+    deck.add_card(Card::new(Suit::Spades, Rank::Ace));
+    assert_eq!(
+        deck.permutation_index(Standard52).unwrap_err(),
+        "Deck contains duplicate card A♠"
+    );
+
+    let short = Deck::from_factory(Standard52) - Card::new(Suit::Spades, Rank::Ace);
+    assert_eq!(
+        short.permutation_index(Standard52).unwrap_err(),
+        "Expected 52 cards but found 51"
+    );
+
+    let foreign = cards("A♠ U♠");
+    assert_eq!(
+        PermutationIndex::rank(&foreign, &cards("A♠ K♠")).unwrap_err(),
+        "Card U♠ is not in the reference order"
+    );
+    assert_eq!(
+        PermutationIndex::rank(&cards("A♠ A♠"), &cards("A♠ K♠")).unwrap_err(),
+        "Duplicate card A♠"
+    );
+    assert_eq!(
+        PermutationIndex::default()
+            .unrank(&cards("A♠ A♠"))
+            .unwrap_err(),
+        "Reference order contains duplicate card A♠"
+    );
+}
+
+#[test]
+fn test_permutation_index_bytes() {
+    let index = PermutationIndex::from(0x0102_0304_0506_0708);
+    let bytes = index.to_bytes();
+    assert_eq!(bytes, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(PermutationIndex::from_bytes(&bytes).unwrap(), index);
+    assert_eq!(
+        PermutationIndex::from_bytes(&[0, 0, 1, 2]).unwrap(),
+        PermutationIndex::from(258)
+    );
+    assert_eq!(
+        PermutationIndex::from_bytes(&[]).unwrap(),
+        PermutationIndex::default()
+    );
+    assert!(PermutationIndex::from_bytes(&[1; 33]).is_err());
+    assert!(PermutationIndex::from_bytes(&[0xff; 32]).is_ok());
+}
+
+#[test]
+fn test_permutation_index_base64() {
+    for i in [0u64, 1, 255, 256, 65535, 1 << 40, u64::MAX] {
+        let index = PermutationIndex::from(i);
+        assert_eq!(
+            PermutationIndex::from_base64(&index.to_base64()).unwrap(),
+            index
+        );
+    }
+    assert_eq!(PermutationIndex::from(0xfbff).to_base64(), "-_8");
+    assert_eq!(
+        PermutationIndex::from_base64("+/8=").unwrap(),
+        PermutationIndex::from(0xfbff)
+    );
+    assert_eq!(
+        PermutationIndex::from_base64("A*").unwrap_err(),
+        "Invalid base64 character: *"
+    );
+    assert_eq!(
+        PermutationIndex::from_base64("AAAAA").unwrap_err(),
+        "Invalid base64 length"
+    );
+}
+
+#[test]
+fn test_permutation_index_decimal() {
+    let mut deck = Deck::from_factory(Standard52);
+    deck.shuffle();
+    let index = deck.permutation_index(Standard52).unwrap();
+    let parsed: PermutationIndex = index.to_string().parse().unwrap();
+    assert_eq!(parsed, index);
+    assert_eq!(PermutationIndex::default().to_string(), "0");
+    assert_eq!(
+        "10000000000000000000"
+            .parse::<PermutationIndex>()
+            .unwrap()
+            .to_bytes(),
+        vec![0x8a, 0xc7, 0x23, 0x04, 0x89, 0xe8, 0x00, 0x00]
+    );
+    assert!("".parse::<PermutationIndex>().is_err());
+    assert!("12a".parse::<PermutationIndex>().is_err());
+    assert!("9".repeat(80).parse::<PermutationIndex>().is_err());
+}
+
+#[test]
+fn test_permutation_index_serialization() {
+    let index = PermutationIndex::from(258);
+    let json = serde_json::to_string(&index).unwrap();
+    assert_eq!(json, "\"AQI\"");
+    assert_eq!(
+        serde_json::from_str::<PermutationIndex>(&json).unwrap(),
+        index
+    );
+    assert!(serde_json::from_str::<PermutationIndex>("\"!\"").is_err());
+}
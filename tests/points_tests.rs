@@ -0,0 +1,118 @@
+use crusty_cards::{
+    BlackjackPoints, Card, CardPoints, CribbagePoints, CustomPoints, Deck, HeartsPoints, Rank,
+    Skat32, SkatPoints, Standard52, Standard54, Suit,
+};
+
+mod common;
+
+use common::cards;
+
+#[test]
+fn test_skat_points() {
+    assert_eq!(SkatPoints.total(&cards("AS TS KS QS JS 9S 8S 7S")), 30);
+    assert_eq!(Deck::from_factory(Skat32).points(&SkatPoints), 120);
+}
+
+#[test]
+fn test_hearts_points() {
+    let deck = Deck::from_factory(Standard54);
+    assert_eq!(deck.points(&HeartsPoints::new()), 26);
+    let variant = HeartsPoints::new().with_jack_of_diamonds(true);
+    assert!(variant.jack_of_diamonds());
+    assert_eq!(deck.points(&variant), 16);
+    assert_eq!(HeartsPoints::new().total(&cards("QS 2H JD")), 14);
+}
+
+#[test]
+fn test_blackjack_points() {
+    assert_eq!(BlackjackPoints.total(&cards("KH 7C")), 17);
+    assert_eq!(
+        BlackjackPoints.points(&Card::new(Suit::Clubs, Rank::Ace)),
+        11
+    );
+    assert_eq!(Deck::from_factory(Standard52).points(&BlackjackPoints), 380);
+
+    let soft = cards("AS 6H");
+    assert_eq!(BlackjackPoints.hand_total(&soft), 17);
+    assert!(BlackjackPoints.is_soft(&soft));
+    let hard = cards("AS 6H KD");
+    assert_eq!(BlackjackPoints.hand_total(&hard), 17);
+    assert!(!BlackjackPoints.is_soft(&hard));
+    assert_eq!(BlackjackPoints.hand_total(&cards("AS AH AD AC")), 14);
+    assert_eq!(BlackjackPoints.hand_total(&cards("KS QH 5D")), 25);
+}
+
+#[test]
+fn test_cribbage_points() {
+    assert_eq!(CribbagePoints.total(&cards("5H JS")), 15);
+    assert_eq!(CribbagePoints.total(&cards("AH 2C")), 3);
+    assert_eq!(Deck::from_factory(Standard52).points(&CribbagePoints), 340);
+}
+
+#[test]
+fn test_trait_objects() {
+    let schemes: Vec<Box<dyn CardPoints>> = vec![Box::new(SkatPoints), Box::new(CribbagePoints)];
+    let hand = cards("AH KH");
+    let totals: Vec<i32> = schemes.iter().map(|s| s.total(&hand)).collect();
+    assert_eq!(totals, [15, 11]);
+    let deck: Deck = hand.into_iter().collect();
+    assert_eq!(deck.points(schemes[0].as_ref()), 15);
+    assert_eq!(deck.points(&&SkatPoints), 15);
+}
+
+#[test]
+fn test_custom_points_builder() {
+    let queen = Card::new(Suit::Spades, Rank::Queen);
+    let scheme = CustomPoints::new()
+        .with_rank_points(Rank::Queen, 3)
+        .with_suit_points(Suit::Spades, 1)
+        .with_card_points(Card::new(Suit::Hearts, Rank::Queen), 0)
+        .with_rank_points(Rank::Queen, 5);
+    assert_eq!(scheme.rank_points(Rank::Queen), 5);
+    assert_eq!(scheme.points(&queen), 6);
+    assert_eq!(scheme.points(&Card::new(Suit::Hearts, Rank::Queen)), 0);
+    assert_eq!(scheme.points(&Card::new(Suit::Clubs, Rank::Two)), 0);
+    assert_eq!(scheme.card_points(&queen), None);
+}
+
+#[test]
+fn test_custom_points_config() {
+    let yaml = "\
+suits: { Hearts: 1 }
+cards:
+  - { suit: Spades, rank: Queen, points: 13 }
+  - { suit: Diamonds, rank: Jack, points: -10 }
+";
+    let scheme = CustomPoints::from_yaml(yaml).unwrap();
+    let deck = Deck::from_factory(Standard52);
+    assert_eq!(
+        deck.points(&scheme),
+        deck.points(&HeartsPoints::new().with_jack_of_diamonds(true))
+    );
+    assert_eq!(
+        CustomPoints::from_yaml(&scheme.to_yaml().unwrap()).unwrap(),
+        scheme
+    );
+
+    let json = r#"{"ranks":{"Ace":11,"Ten":10,"King":4,"Queen":3,"Jack":2}}"#;
+    let scheme = CustomPoints::from_json(json).unwrap();
+    assert_eq!(Deck::from_factory(Skat32).points(&scheme), 120);
+    assert_eq!(scheme.to_json().unwrap(), json);
+}
+
+#[test]
+fn test_custom_points_invalid_config() {
+    let err = CustomPoints::from_json(r#"{"ranks": {"Ace": 1, "Ace": 2}}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Ace appears more than once in ranks"));
+    let err = CustomPoints::from_yaml(
+        "cards:\n  - { suit: Spades, rank: Queen, points: 1 }\n  - { suit: Spades, rank: Queen, points: 2 }\n",
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Q♠ appears more than once in cards"));
+    assert!(CustomPoints::from_json(r#"{"rank": {}}"#).is_err());
+    assert!(CustomPoints::from_json(r#"{"ranks": {"Eleven": 1}}"#).is_err());
+}
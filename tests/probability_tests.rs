@@ -0,0 +1,124 @@
+use crusty_cards::{Card, Deck, DrawOdds, Rank, Standard52, Suit};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-12
+}
+
+fn is_king(card: &Card) -> bool {
+    card.rank() == Rank::King
+}
+
+fn is_heart(card: &Card) -> bool {
+    card.suit() == Suit::Hearts
+}
+
+#[test]
+fn test_next_card() {
+    let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    assert!(close(odds.next_card(Card::is_ace), 4.0 / 52.0));
+    assert!(close(
+        odds.next_card(|c| is_heart(c) || c.is_face_card()),
+        22.0 / 52.0
+    ));
+    assert_eq!(DrawOdds::default().next_card(Card::is_ace), 0.0);
+}
+
+#[test]
+fn test_exactly_matches_hypergeometric() {
+    let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    // C(4,1) * C(48,4) / C(52,5)
+    assert!(close(
+        odds.exactly(5, 1, Card::is_ace),
+        4.0 * 194580.0 / 2598960.0
+    ));
+    assert_eq!(odds.exactly(5, 5, Card::is_ace), 0.0);
+    assert_eq!(odds.exactly(2, 3, Card::is_ace), 0.0);
+    assert!(close(odds.exactly(0, 0, Card::is_ace), 1.0));
+}
+
+#[test]
+fn test_at_least_and_at_most_sum_to_one() {
+    let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    for min in 1..5 {
+        let at_least = odds.at_least(7, min, is_heart);
+        let at_most = odds.at_most(7, min - 1, is_heart);
+        assert!((at_least + at_most - 1.0).abs() < 1e-9);
+    }
+    assert!((odds.at_least(5, 1, Card::is_ace) - 0.3411580).abs() < 1e-7);
+    assert!(close(odds.at_least(5, 0, Card::is_ace), 1.0));
+    assert!(close(odds.at_most(5, 10, Card::is_ace), 1.0));
+}
+
+#[test]
+fn test_expected() {
+    let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    assert!(close(
+        odds.expected(10, |c| c.rank() == Rank::Ten),
+        10.0 * 4.0 / 52.0
+    ));
+    assert!(close(odds.expected(100, Card::is_ace), 4.0));
+    assert_eq!(DrawOdds::default().expected(5, Card::is_ace), 0.0);
+}
+
+#[test]
+fn test_draws_capped_at_remaining() {
+    let deck: Deck = "A♠ K♠ Q♠".parse().unwrap();
+    let odds = DrawOdds::new(&deck);
+    assert_eq!(odds.remaining(), 3);
+    assert!(close(odds.at_least(10, 1, Card::is_ace), 1.0));
+    assert!(close(odds.exactly(10, 1, Card::is_ace), 1.0));
+    assert!(close(odds.expected(10, Card::is_ace), 1.0));
+    assert!(close(
+        odds.all_of(10, &[(&Card::is_ace, 1), (&is_king, 1)]),
+        1.0
+    ));
+}
+
+#[test]
+fn test_follows_current_composition() {
+    let mut deck = Deck::from_factory(Standard52);
+    deck.sort_by(|a, b| b.is_ace().cmp(&a.is_ace()));
+    deck.deal_n(4).unwrap();
+    let odds = DrawOdds::new(&deck);
+    assert_eq!(odds.count(Card::is_ace), 0);
+    assert_eq!(odds.at_least(5, 1, Card::is_ace), 0.0);
+}
+
+#[test]
+fn test_from_cards() {
+    let cards = [
+        Card::new(Suit::Hearts, Rank::Ace),
+        Card::new(Suit::Clubs, Rank::Two),
+    ];
+    let odds = DrawOdds::from_cards(&cards);
+    assert_eq!(odds.next_card(Card::is_ace), 0.5);
+}
+
+#[test]
+fn test_all_of_multivariate() {
+    let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    assert!(close(
+        odds.all_of(2, &[(&Card::is_ace, 1), (&is_king, 1)]),
+        16.0 / 1326.0
+    ));
+
+    // A single requirement matches the univariate answer
+    assert!(close(
+        odds.all_of(5, &[(&Card::is_ace, 1)]),
+        odds.at_least(5, 1, Card::is_ace)
+    ));
+
+    // No requirements is certain
+    assert!(close(odds.all_of(5, &[]), 1.0));
+
+    // Impossible requirements
+    assert_eq!(odds.all_of(2, &[(&Card::is_ace, 2), (&is_king, 1)]), 0.0);
+}
+
+#[test]
+fn test_all_of_first_match_wins() {
+    let odds = DrawOdds::new(&Deck::from_factory(Standard52));
+    // Ace of hearts counts as an ace, not as a heart
+    let p = odds.all_of(1, &[(&Card::is_ace, 0), (&is_heart, 1)]);
+    assert!(close(p, 12.0 / 52.0));
+}
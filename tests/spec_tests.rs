@@ -0,0 +1,161 @@
+use crusty_cards::{Card, Deck, DeckFactory, DeckSpec, Rank, Standard52, Standard54, Suit};
+use std::collections::VecDeque;
+
+fn count(deck: &Deck, card: Card) -> usize {
+    deck.iter().filter(|c| **c == card).count()
+}
+
+#[test]
+fn test_factories_and_multipliers() {
+    assert_eq!(
+        Deck::from_spec("standard52").unwrap(),
+        Deck::from_factory(Standard52)
+    );
+    assert_eq!(
+        Deck::from_spec("STANDARD54").unwrap(),
+        Deck::from_factory(Standard54)
+    );
+    assert_eq!(
+        Deck::from_spec("standard52 * 6").unwrap(),
+        Deck::from_factory(Standard52) * 6
+    );
+    assert_eq!(Deck::from_spec("2x standard52").unwrap().len(), 104);
+    assert_eq!(Deck::from_spec("2 x standard52").unwrap().len(), 104);
+    assert_eq!(Deck::from_spec("2 standard52 * 3").unwrap().len(), 312);
+    assert!(Deck::from_spec("0x standard52").unwrap().is_empty());
+}
+
+#[test]
+fn test_rank_ranges_and_suits() {
+    let deck = Deck::from_spec("ranks 9..A of all suits * 2").unwrap();
+    assert_eq!(deck.len(), 48);
+    assert_eq!(count(&deck, Card::new(Suit::Hearts, Rank::Nine)), 2);
+    assert_eq!(count(&deck, Card::new(Suit::Hearts, Rank::Eight)), 0);
+
+    let deck = Deck::from_spec("ranks 2..4").unwrap();
+    assert_eq!(deck.to_string(), "2♥ 2♦ 2♣ 2♠ 3♥ 3♦ 3♣ 3♠ 4♥ 4♦ 4♣ 4♠");
+
+    assert_eq!(Deck::from_spec("ranks A, K, 10 of red").unwrap().len(), 6);
+    assert_eq!(
+        Deck::from_spec("ranks T..K of black suits").unwrap().len(),
+        8
+    );
+    let deck = Deck::from_spec("ranks q of hearts, ♠").unwrap();
+    assert_eq!(deck.to_string(), "Q♥ Q♠");
+}
+
+#[test]
+fn test_addition_subtraction_and_grouping() {
+    assert_eq!(
+        Deck::from_spec("standard52 - ranks 2..6").unwrap().len(),
+        32
+    );
+
+    let deck = Deck::from_spec("standard52 + joker red + joker black").unwrap();
+    assert_eq!(deck.len(), 54);
+    assert!(Deck::from_factory(Standard54)
+        .iter()
+        .all(|c| deck.contains(c)));
+
+    let deck = Deck::from_spec("2x standard52 + 4 jokers - 2C").unwrap();
+    assert_eq!(deck.len(), 106);
+    assert_eq!(count(&deck, Card::new(Suit::Clubs, Rank::Two)), 0);
+    assert_eq!(count(&deck, Card::new(Suit::Hearts, Rank::Joker)), 2);
+    assert_eq!(count(&deck, Card::new(Suit::Spades, Rank::Joker)), 2);
+
+    let deck = Deck::from_spec("(standard52 - ranks 2..8) * 2 + A♠").unwrap();
+    assert_eq!(deck.len(), 49);
+    assert_eq!(count(&deck, Card::new(Suit::Spades, Rank::Ace)), 3);
+
+    assert_eq!(Deck::from_spec("joker").unwrap().to_string(), "U♥");
+    assert_eq!(Deck::from_spec("3 jokers black").unwrap().len(), 3);
+}
+
+#[test]
+fn test_custom_factory() {
+    struct Pinochle;
+
+    impl DeckFactory for Pinochle {
+        fn generate(&self) -> VecDeque<Card> {
+            Deck::from_spec("2x ranks 9..A")
+                .unwrap()
+                .into_iter()
+                .collect()
+        }
+    }
+
+    let spec = DeckSpec::new().with_factory("Pinochle", Pinochle).unwrap();
+    assert_eq!(spec.build("pinochle").unwrap().len(), 48);
+    assert_eq!(
+        spec.factory_names().collect::<Vec<_>>(),
+        vec![
+            "standard52",
+            "standard54",
+            "euchre24",
+            "skat32",
+            "schafkopf24",
+            "doppelkopf48",
+            "pinochle"
+        ]
+    );
+    assert!(Deck::from_spec("pinochle").is_err());
+}
+
+#[test]
+fn test_factory_names_are_validated() {
+    let register = |name: &str| DeckSpec::new().with_factory(name, Standard52).map(|_| ());
+    assert_eq!(
+        register("my deck").unwrap_err(),
+        "Invalid factory name 'my deck': names must be a single word"
+    );
+    assert!(register("").is_err());
+    assert!(register("big+deck").is_err());
+    assert_eq!(
+        register("2c").unwrap_err(),
+        "Invalid factory name '2c': the name is a card"
+    );
+    assert!(register("A♠").is_err());
+    assert_eq!(
+        register("Jokers").unwrap_err(),
+        "Invalid factory name 'Jokers': the word is reserved"
+    );
+    assert!(register("6").is_err());
+    assert!(register("2x").is_err());
+    assert!(register("x").is_err());
+    assert!(register("standard52").is_ok());
+    assert!(register("deck_2").is_ok());
+}
+
+#[test]
+fn test_spec_errors() {
+    assert_eq!(
+        Deck::from_spec("standard53").unwrap_err(),
+        "Unknown deck term 'standard53'"
+    );
+    assert_eq!(
+        Deck::from_spec("ranks A..2").unwrap_err(),
+        "Invalid rank range 'A..2'"
+    );
+    assert_eq!(
+        Deck::from_spec("ranks 2..U").unwrap_err(),
+        "Unknown rank 'U'"
+    );
+    assert_eq!(
+        Deck::from_spec("ranks 2 of purple").unwrap_err(),
+        "Unknown suit 'purple'"
+    );
+    assert_eq!(
+        Deck::from_spec("standard52 * two").unwrap_err(),
+        "Expected a count after '*' but found 'two'"
+    );
+    assert_eq!(
+        Deck::from_spec("standard52 +").unwrap_err(),
+        "Unexpected end of deck spec"
+    );
+    assert_eq!(Deck::from_spec("(standard52").unwrap_err(), "Expected ')'");
+    assert_eq!(
+        Deck::from_spec("standard52 standard54").unwrap_err(),
+        "Unexpected 'standard54'"
+    );
+    assert!(Deck::from_spec("").is_err());
+}
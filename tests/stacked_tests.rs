@@ -0,0 +1,146 @@
+use crusty_cards::{
+    Card, DealPattern, DealStep, Deck, DeckFactory, StackedDeckBuilder, Standard52,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+mod common;
+
+use common::cards;
+
+#[test]
+fn test_stacked_hole_cards_and_board() {
+    let pattern = DealPattern::round_robin(2);
+    let mut deck = StackedDeckBuilder::new(Standard52)
+        .players(4)
+        .pattern(pattern.clone())
+        .seat(2, "AS AH")
+        .seat(0, "7D")
+        .board("KD 7C 2H")
+        .fill_random()
+        .build()
+        .unwrap();
+    assert!(deck.same_cards_as(&Deck::from_factory(Standard52)));
+
+    let deal = deck.deal_to(4, &pattern).unwrap();
+    assert_eq!(deal.hand(2).unwrap(), cards("AS AH").as_slice());
+    assert_eq!(deal.hand(0).unwrap()[0], cards("7D")[0]);
+    assert_eq!(deck.deal_n(3).unwrap(), cards("KD 7C 2H"));
+}
+
+#[test]
+fn test_stacked_respects_dealer_and_packets() {
+    let pattern = DealPattern::euchre().dealer(1);
+    let mut deck = StackedDeckBuilder::new(Standard52)
+        .players(4)
+        .pattern(pattern.clone())
+        .seat(1, "JH JD AH KH QH")
+        .seat(3, "9S")
+        .build()
+        .unwrap();
+    let deal = deck.deal_to(4, &pattern).unwrap();
+    assert_eq!(deal.hand(1).unwrap(), cards("JH JD AH KH QH").as_slice());
+    assert_eq!(deal.hand(3).unwrap()[0], cards("9S")[0]);
+}
+
+#[test]
+fn test_stacked_kitty() {
+    let pattern = DealPattern::skat();
+    let mut deck = StackedDeckBuilder::new(Standard52)
+        .players(3)
+        .pattern(pattern.clone())
+        .kitty("JC JS")
+        .build()
+        .unwrap();
+    let deal = deck.deal_to(3, &pattern).unwrap();
+    assert_eq!(deal.kitty(), cards("JC JS").as_slice());
+}
+
+#[test]
+fn test_stacked_fill_in_order() {
+    let deck = StackedDeckBuilder::new(Standard52)
+        .players(2)
+        .pattern(DealPattern::round_robin(1))
+        .seat(1, "AS")
+        .fill_random()
+        .fill_in_order()
+        .build()
+        .unwrap();
+    let mut expected: Vec<Card> = Standard52.generate().into_iter().collect();
+    let ace = cards("AS")[0];
+    expected.retain(|c| *c != ace);
+    expected.insert(1, ace);
+    assert_eq!(deck.into_iter().collect::<Vec<Card>>(), expected);
+}
+
+#[test]
+fn test_stacked_seeded_fill_is_reproducible() {
+    let builder = StackedDeckBuilder::new(Standard52)
+        .players(2)
+        .pattern(DealPattern::round_robin(2))
+        .seat(0, "AS AD")
+        .fill_random();
+    let a = builder
+        .build_with_rng(&mut StdRng::seed_from_u64(7))
+        .unwrap();
+    let b = builder
+        .build_with_rng(&mut StdRng::seed_from_u64(7))
+        .unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_stacked_errors() {
+    let too_many = StackedDeckBuilder::new(Standard52)
+        .players(2)
+        .pattern(DealPattern::round_robin(1))
+        .seat(0, "AS AH")
+        .build();
+    assert_eq!(
+        too_many.unwrap_err(),
+        "Seat 0 is dealt 1 cards but 2 were requested"
+    );
+
+    let no_seat = StackedDeckBuilder::new(Standard52)
+        .players(2)
+        .pattern(DealPattern::round_robin(1))
+        .seat(5, "AS")
+        .build();
+    assert!(no_seat.is_err());
+
+    let kitty = StackedDeckBuilder::new(Standard52)
+        .players(2)
+        .pattern(DealPattern::new(vec![DealStep::Kitty(1)]))
+        .kitty("AS AH")
+        .build();
+    assert_eq!(
+        kitty.unwrap_err(),
+        "The kitty is dealt 1 cards but 2 were requested"
+    );
+
+    let duplicate = StackedDeckBuilder::new(Standard52)
+        .players(2)
+        .pattern(DealPattern::round_robin(2))
+        .seat(0, "AS")
+        .seat(1, "AS")
+        .build();
+    assert_eq!(
+        duplicate.unwrap_err(),
+        "Card A♠ is not available in this deck"
+    );
+
+    let joker = StackedDeckBuilder::new(Standard52).board("US").build();
+    assert!(joker.is_err());
+
+    let bad_parse = StackedDeckBuilder::new(Standard52).seat(0, "ZZ").build();
+    assert!(bad_parse.unwrap_err().contains("ZZ"));
+
+    let too_big = StackedDeckBuilder::new(Standard52)
+        .players(5)
+        .pattern(DealPattern::round_robin(11))
+        .build();
+    assert_eq!(
+        too_big.unwrap_err(),
+        "Factory has 52 cards but the deal and board need 55"
+    );
+}
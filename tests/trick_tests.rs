@@ -0,0 +1,180 @@
+use crusty_cards::{
+    AceLowComparator, EuchreComparator, SkatComparator, SkatGame, Suit, Trick, TrickRules,
+    TrumpComparator,
+};
+
+mod common;
+
+use common::{card, cards};
+
+fn trick(trump: Option<Suit>, plays: &[(usize, &str)]) -> Trick {
+    let mut trick = Trick::new();
+    if let Some(trump) = trump {
+        trick = trick.with_trump(trump);
+    }
+    for &(seat, c) in plays {
+        trick.play(seat, card(c)).unwrap();
+    }
+    trick
+}
+
+#[test]
+fn test_empty_trick() {
+    let trick = Trick::new();
+    assert!(trick.is_empty());
+    assert_eq!(trick.led_suit(), None);
+    assert_eq!(trick.leader(), None);
+    assert_eq!(trick.winner(), None);
+    assert_eq!(trick.winning_card(), None);
+    assert_eq!(trick.winning_card_by(&AceLowComparator), None);
+}
+
+#[test]
+fn test_led_suit_beats_higher_off_suit() {
+    // Plain rank comparison would pick the ace of clubs.
+    let trick = trick(None, &[(2, "9H"), (3, "AC"), (0, "TH"), (1, "KD")]);
+    assert_eq!(trick.leader(), Some(2));
+    assert_eq!(trick.led_suit(), Some(Suit::Hearts));
+    assert_eq!(trick.winner(), Some(0));
+    assert_eq!(trick.winning_card(), Some(card("TH")));
+    assert_eq!(trick.len(), 4);
+    assert_eq!(trick.card_of(3), Some(card("AC")));
+    assert_eq!(trick.to_string(), "2:9♥ 3:A♣ 0:T♥ 1:K♦");
+}
+
+#[test]
+fn test_trump_wins() {
+    let trick = trick(
+        Some(Suit::Spades),
+        &[(0, "AH"), (1, "2S"), (2, "KH"), (3, "3S")],
+    );
+    assert_eq!(trick.winner(), Some(3));
+
+    let trick = self::trick(Some(Suit::Spades), &[(0, "AH"), (1, "KH")]);
+    assert_eq!(trick.winner(), Some(0));
+
+    // Leading trump is just following suit.
+    let trick = self::trick(Some(Suit::Clubs), &[(0, "2C"), (1, "AH")]);
+    assert_eq!(trick.winner(), Some(0));
+}
+
+#[test]
+fn test_first_of_equal_cards_wins() {
+    let trick = trick(None, &[(0, "QS"), (1, "QS")]);
+    assert_eq!(trick.winner(), Some(0));
+}
+
+#[test]
+fn test_winner_by_comparator() {
+    let trick = trick(None, &[(0, "AD"), (1, "2D")]);
+    assert_eq!(trick.winner(), Some(0));
+    assert_eq!(trick.winner_by(&AceLowComparator), Some(1));
+}
+
+#[test]
+fn test_seat_cannot_play_twice() {
+    let mut trick = Trick::new();
+    trick.play(1, card("2H")).unwrap();
+    assert_eq!(
+        trick.play(1, card("3H")).unwrap_err(),
+        "Seat 1 has already played to this trick"
+    );
+    assert_eq!(trick.len(), 1);
+}
+
+#[test]
+fn test_legal_plays_follow_suit() {
+    let rules = TrickRules::default();
+    let hand = cards("2H 5C AH 9S");
+    assert_eq!(rules.legal_plays(&hand, &Trick::new()), hand);
+
+    let led_hearts = trick(Some(Suit::Spades), &[(0, "KH")]);
+    assert_eq!(rules.legal_plays(&hand, &led_hearts), cards("2H AH"));
+    assert!(rules.is_legal(&card("AH"), &hand, &led_hearts));
+    assert!(!rules.is_legal(&card("9S"), &hand, &led_hearts));
+    assert!(!rules.is_legal(&card("3H"), &hand, &led_hearts));
+
+    let led_diamonds = trick(Some(Suit::Spades), &[(0, "KD")]);
+    assert_eq!(rules.legal_plays(&hand, &led_diamonds), hand);
+}
+
+#[test]
+fn test_legal_plays_must_trump() {
+    let rules = TrickRules::new().with_must_trump(true);
+    assert!(rules.must_follow());
+    assert!(rules.must_trump());
+    let hand = cards("2H 5C 9S JS");
+
+    let led_diamonds = trick(Some(Suit::Spades), &[(0, "KD")]);
+    assert_eq!(rules.legal_plays(&hand, &led_diamonds), cards("9S JS"));
+
+    let led_hearts = trick(Some(Suit::Spades), &[(0, "KH")]);
+    assert_eq!(rules.legal_plays(&hand, &led_hearts), cards("2H"));
+
+    let no_trump = trick(None, &[(0, "KD")]);
+    assert_eq!(rules.legal_plays(&hand, &no_trump), hand);
+}
+
+#[test]
+fn test_legal_plays_without_follow() {
+    let rules = TrickRules::new().with_must_follow(false);
+    let hand = cards("2H 5C");
+    assert_eq!(rules.legal_plays(&hand, &trick(None, &[(0, "KH")])), hand);
+
+    let rules = rules.with_must_trump(true);
+    let led = trick(Some(Suit::Clubs), &[(0, "KH")]);
+    assert_eq!(rules.legal_plays(&hand, &led), cards("5C"));
+}
+
+#[test]
+fn test_euchre_tricks_use_effective_suit() {
+    let euchre = EuchreComparator::new(Suit::Hearts);
+
+    // The left bower led is a trump lead, and beats the ace of trump.
+    let led_left = trick(Some(Suit::Hearts), &[(0, "JD"), (1, "AH"), (2, "AD")]);
+    assert_eq!(led_left.led_suit(), Some(Suit::Diamonds));
+    assert_eq!(led_left.led_suit_by(&euchre), Some(Suit::Hearts));
+    assert_eq!(led_left.winner_by(&euchre), Some(0));
+
+    // Diamonds led: the left bower is not a diamond but trumps the trick.
+    let led_diamonds = trick(Some(Suit::Hearts), &[(0, "AD"), (1, "JD"), (2, "KD")]);
+    assert_eq!(led_diamonds.winner(), Some(0));
+    assert_eq!(led_diamonds.winner_by(&euchre), Some(1));
+    assert_eq!(led_diamonds.winning_card(), Some(card("AD")));
+    assert_eq!(led_diamonds.winning_card_by(&euchre), Some(card("JD")));
+
+    let rules = TrickRules::new();
+    let hand = cards("JD 9C");
+    let led = trick(Some(Suit::Hearts), &[(0, "AD")]);
+    assert_eq!(rules.legal_plays(&hand, &led), cards("JD"));
+    assert_eq!(rules.legal_plays_by(&hand, &led, &euchre), hand);
+    assert!(rules.is_legal_by(&card("9C"), &hand, &led, &euchre));
+}
+
+#[test]
+fn test_comparator_trumps_without_trick_trump() {
+    let grand = SkatComparator::new(SkatGame::Grand);
+
+    // A jack trumps a clubs lead in Grand.
+    let trick = trick(None, &[(0, "AC"), (1, "JD"), (2, "TC")]);
+    assert_eq!(trick.winner(), Some(0));
+    assert_eq!(trick.winner_by(&grand), Some(1));
+    assert_eq!(trick.winning_card_by(&grand), Some(card("JD")));
+
+    // A jack lead must be followed by a jack, not a club.
+    let rules = TrickRules::new();
+    let hand = cards("JC 7C");
+    let led_jack = self::trick(None, &[(0, "JS")]);
+    assert_eq!(rules.legal_plays_by(&hand, &led_jack, &grand), cards("JC"));
+    let led_club = self::trick(None, &[(0, "AC")]);
+    assert_eq!(rules.legal_plays_by(&hand, &led_club, &grand), cards("7C"));
+
+    let trump = TrumpComparator::new(Suit::Spades);
+    let trick = self::trick(None, &[(0, "AH"), (1, "2S")]);
+    assert_eq!(trick.winner_by(&trump), Some(1));
+    let rules = rules.with_must_trump(true);
+    assert_eq!(
+        rules.legal_plays_by(&cards("2C 3S"), &self::trick(None, &[(0, "AH")]), &trump),
+        cards("3S")
+    );
+}
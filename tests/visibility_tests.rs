@@ -0,0 +1,143 @@
+use crusty_cards::{
+    Card, CardView, Deck, Rank, RedactedDeck, Suit, TrackedCard, TrackedDeck, Viewer, Visibility,
+};
+
+fn ace() -> Card {
+    Card::new(Suit::Spades, Rank::Ace)
+}
+
+fn king() -> Card {
+    Card::new(Suit::Hearts, Rank::King)
+}
+
+#[test]
+fn test_visibility_rules() {
+    let up = Visibility::FaceUp;
+    let down = Visibility::FaceDown;
+    let known = Visibility::known_to([1, 3]);
+
+    assert!(up.is_visible_to(Viewer::Seat(0)));
+    assert!(up.is_visible_to(Viewer::Spectator));
+    assert!(!down.is_visible_to(Viewer::Seat(0)));
+    assert!(!down.is_visible_to(Viewer::Spectator));
+    assert!(down.is_visible_to(Viewer::Omniscient));
+    assert!(known.is_visible_to(Viewer::Seat(1)));
+    assert!(known.is_visible_to(Viewer::Seat(3)));
+    assert!(!known.is_visible_to(Viewer::Seat(2)));
+    assert!(!known.is_visible_to(Viewer::Spectator));
+    assert!(known.is_visible_to(Viewer::Omniscient));
+}
+
+#[test]
+fn test_tracked_card_reveal_to() {
+    let mut tracked = TrackedCard::new(ace(), Visibility::FaceDown);
+    assert_eq!(tracked.view_for(Viewer::Seat(0)), CardView::Back);
+
+    tracked.reveal_to(0);
+    assert_eq!(tracked.visibility(), &Visibility::known_to([0]));
+    tracked.reveal_to(2);
+    assert_eq!(tracked.visibility(), &Visibility::known_to([0, 2]));
+    assert_eq!(tracked.view_for(Viewer::Seat(2)), CardView::Face(ace()));
+    assert_eq!(tracked.view_for(Viewer::Seat(1)), CardView::Back);
+
+    tracked.set_visibility(Visibility::FaceUp);
+    tracked.reveal_to(5);
+    assert_eq!(tracked.visibility(), &Visibility::FaceUp);
+    assert_eq!(tracked.card(), ace());
+}
+
+#[test]
+fn test_card_view_helpers() {
+    assert_eq!(CardView::Face(ace()).card(), Some(ace()));
+    assert_eq!(CardView::Back.card(), None);
+    assert!(CardView::Back.is_back());
+    assert!(!CardView::Face(ace()).is_back());
+    assert_eq!(CardView::Face(king()).to_string(), "K♥");
+    assert_eq!(CardView::Back.to_string(), "??");
+}
+
+#[test]
+fn test_tracked_deck_views() {
+    let mut table = TrackedDeck::default();
+    table.add_card_bottom(ace(), Visibility::known_to([0]));
+    table.add_card_bottom(king(), Visibility::known_to([1]));
+    table.add_card(Card::new(Suit::Clubs, Rank::Two), Visibility::FaceUp);
+
+    assert_eq!(table.len(), 3);
+    assert_eq!(table.view_for(Viewer::Seat(0)).to_string(), "2♣ A♠ ??");
+    assert_eq!(table.view_for(Viewer::Seat(1)).to_string(), "2♣ ?? K♥");
+    assert_eq!(table.view_for(Viewer::Spectator).hidden_count(), 2);
+    assert_eq!(table.view_for(Viewer::Omniscient).hidden_count(), 0);
+    assert_eq!(table.to_deck().to_string(), "2♣ A♠ K♥");
+}
+
+#[test]
+fn test_tracked_deck_reveal() {
+    let deck: Deck = "A♠ K♥ Q♦".parse().unwrap();
+    let mut tracked = TrackedDeck::from_deck(deck, Visibility::FaceDown);
+    assert!(tracked.reveal(1));
+    assert!(!tracked.reveal(3));
+    assert!(tracked.reveal_to(2, 4));
+    assert!(!tracked.reveal_to(3, 4));
+    assert!(!tracked.set_visibility(3, Visibility::FaceUp));
+
+    assert_eq!(tracked.view_for(Viewer::Seat(4)).to_string(), "?? K♥ Q♦");
+    assert_eq!(tracked.view_for(Viewer::Seat(0)).to_string(), "?? K♥ ??");
+
+    tracked.reveal_all();
+    assert_eq!(tracked.view_for(Viewer::Spectator).hidden_count(), 0);
+}
+
+#[test]
+fn test_tracked_deck_deal_and_remove() {
+    let deck: Deck = "A♠ K♥ Q♦".parse().unwrap();
+    let mut tracked = TrackedDeck::from_deck(deck, Visibility::FaceUp);
+    assert_eq!(tracked.get(0).map(TrackedCard::card), Some(ace()));
+    assert_eq!(tracked.deal().map(|t| t.card()), Some(ace()));
+    assert_eq!(
+        tracked.remove_at(1).map(|t| t.card()).unwrap().rank(),
+        Rank::Queen
+    );
+    assert_eq!(tracked.iter().count(), 1);
+    assert!(tracked.remove_at(5).is_none());
+    tracked.deal();
+    assert!(tracked.is_empty());
+    assert!(tracked.deal().is_none());
+}
+
+#[test]
+fn test_redacted_deck_never_leaks_hidden_cards() {
+    let deck: Deck = "A♠ K♥".parse().unwrap();
+    let tracked = TrackedDeck::from_deck(deck, Visibility::known_to([0]));
+    let json = tracked.view_for(Viewer::Seat(1)).to_json().unwrap();
+    assert!(!json.contains("Spades"));
+    assert!(!json.contains("Hearts"));
+    assert_eq!(json, r#"{"cards":["Back","Back"]}"#);
+}
+
+#[test]
+fn test_redacted_deck_json_round_trip() {
+    let deck: Deck = "A♠ K♥".parse().unwrap();
+    let mut tracked = TrackedDeck::from_deck(deck, Visibility::FaceDown);
+    tracked.reveal(0);
+    let view = tracked.view_for(Viewer::Seat(0));
+    let json = view.to_json().unwrap();
+    assert_eq!(
+        json,
+        r#"{"cards":[{"Face":{"suit":"Spades","rank":"Ace"}},"Back"]}"#
+    );
+    let restored = RedactedDeck::from_json(&json).unwrap();
+    assert_eq!(restored, view);
+    assert_eq!(restored.len(), 2);
+    assert!(!restored.is_empty());
+    assert_eq!(restored.cards()[0], CardView::Face(ace()));
+}
+
+#[test]
+fn test_tracked_deck_serialization() {
+    let deck: Deck = "A♠ K♥".parse().unwrap();
+    let tracked = TrackedDeck::from_deck(deck, Visibility::known_to([0, 1]));
+    let json = serde_json::to_string(&tracked).unwrap();
+    let restored: TrackedDeck = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, tracked);
+}